@@ -51,7 +51,9 @@ impl File {
                 .map_err(|err| FileConversionError::RequiresPython(err.line().clone(), err))?,
             size: file.size,
             upload_time_utc_ms: file.upload_time.map(|dt| dt.timestamp_millis()),
-            url: if file.url.contains("://") {
+            url: if let Some(path) = local_path(&file.url, base) {
+                FileLocation::Path(path)
+            } else if file.url.contains("://") {
                 FileLocation::AbsoluteUrl(file.url)
             } else {
                 FileLocation::RelativeUrl(base.to_string(), file.url)
@@ -61,7 +63,23 @@ impl File {
     }
 }
 
-/// While a registry file is generally a remote URL, it can also be a file if it comes from a directory flat indexes.
+/// If the file is served by a local (`file://`) index, return its path on disk.
+///
+/// Files in a local index are read from disk directly, like those in a local `--find-links`
+/// directory, rather than being fetched over HTTP.
+fn local_path(url: &str, base: &Url) -> Option<PathBuf> {
+    if base.scheme() != "file" {
+        return None;
+    }
+    let url = base.join(url).ok()?;
+    if url.scheme() != "file" {
+        return None;
+    }
+    url.to_file_path().ok()
+}
+
+/// While a registry file is generally a remote URL, it can also be a file if it comes from a directory flat index,
+/// or from a local (`file://`) index.
 #[derive(
     Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize,
 )]
@@ -9,8 +9,9 @@ use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
-use pep508_rs::{expand_env_vars, split_scheme, strip_host, Scheme, VerbatimUrl};
+use pep508_rs::{expand_env_vars, redact_url, split_scheme, strip_host, Scheme, VerbatimUrl};
 use uv_fs::normalize_url_path;
+use uv_normalize::PackageName;
 
 use crate::Verbatim;
 
@@ -37,11 +38,9 @@ impl IndexUrl {
 }
 
 impl Display for IndexUrl {
+    /// Display the index URL, with any credentials redacted.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Pypi(url) => Display::fmt(url, f),
-            Self::Url(url) => Display::fmt(url, f),
-        }
+        Display::fmt(&redact_url(self.url()), f)
     }
 }
 
@@ -98,6 +97,111 @@ impl Deref for IndexUrl {
     }
 }
 
+/// A package index with a user-provided name (e.g., `internal=https://example.com/simple`).
+///
+/// Named indexes are only consulted for the packages that are assigned to them via an
+/// [`IndexAssignment`].
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct NamedIndex {
+    pub name: String,
+    pub url: IndexUrl,
+}
+
+impl FromStr for NamedIndex {
+    type Err = IndexAssignmentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((name, url)) = s.split_once('=') else {
+            return Err(IndexAssignmentError::MissingSeparator(s.to_string()));
+        };
+        let name = name.trim();
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            return Err(IndexAssignmentError::InvalidIndexName(name.to_string()));
+        }
+        let url = IndexUrl::from_str(url.trim())
+            .map_err(|err| IndexAssignmentError::InvalidUrl(url.to_string(), err))?;
+        Ok(Self {
+            name: name.to_string(),
+            url,
+        })
+    }
+}
+
+impl Display for NamedIndex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.name, self.url)
+    }
+}
+
+/// An assignment of one or more packages to a [`NamedIndex`] (e.g., `acme-core=internal` or
+/// `acme-*=internal`).
+///
+/// The package may be given as a glob pattern, which is matched against normalized package names.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct IndexAssignment {
+    pattern: glob::Pattern,
+    index: String,
+}
+
+impl IndexAssignment {
+    /// Returns `true` if the assignment applies to the given package.
+    pub fn matches(&self, package_name: &PackageName) -> bool {
+        self.pattern.matches(package_name.as_ref())
+    }
+
+    /// The name of the index to which the packages are assigned.
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+}
+
+impl FromStr for IndexAssignment {
+    type Err = IndexAssignmentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package, index)) = s.split_once('=') else {
+            return Err(IndexAssignmentError::MissingSeparator(s.to_string()));
+        };
+
+        // Normalize the pattern in the same way as package names, such that, e.g., `Acme_*`
+        // matches `acme-core`.
+        let normalized = package.trim().to_lowercase().replace(['_', '.'], "-");
+        let pattern = glob::Pattern::new(&normalized)
+            .map_err(|err| IndexAssignmentError::InvalidPattern(package.to_string(), err))?;
+
+        Ok(Self {
+            pattern,
+            index: index.trim().to_string(),
+        })
+    }
+}
+
+impl Display for IndexAssignment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.pattern, self.index)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum IndexAssignmentError {
+    #[error("Expected a value of the form `<name>=<value>`, but found: `{0}`")]
+    MissingSeparator(String),
+    #[error("Index names may only contain letters, numbers, `-`, `_`, and `.`, but found: `{0}`")]
+    InvalidIndexName(String),
+    #[error("Invalid index URL: `{0}`")]
+    InvalidUrl(String, #[source] url::ParseError),
+    #[error("Invalid package pattern: `{0}`")]
+    InvalidPattern(String, #[source] glob::PatternError),
+    #[error("Packages matching `{0}` are assigned to the index `{1}`, but no index with that name was provided (e.g., `--index {1}=<url>`)")]
+    UnknownIndex(String, String),
+    #[error("The index name `{0}` was provided multiple times")]
+    DuplicateIndex(String),
+}
+
 /// A directory with distributions or a URL to an HTML file with a flat listing of distributions.
 ///
 /// Also known as `--find-links`.
@@ -181,6 +285,8 @@ pub struct IndexLocations {
     index: Option<IndexUrl>,
     extra_index: Vec<IndexUrl>,
     flat_index: Vec<FlatIndexLocation>,
+    named_index: Vec<NamedIndex>,
+    index_assignments: Vec<IndexAssignment>,
     no_index: bool,
 }
 
@@ -191,6 +297,8 @@ impl Default for IndexLocations {
             index: Some(DEFAULT_INDEX_URL.clone()),
             extra_index: Vec::new(),
             flat_index: Vec::new(),
+            named_index: Vec::new(),
+            index_assignments: Vec::new(),
             no_index: false,
         }
     }
@@ -208,10 +316,50 @@ impl IndexLocations {
             index,
             extra_index,
             flat_index,
+            named_index: Vec::new(),
+            index_assignments: Vec::new(),
             no_index,
         }
     }
 
+    /// Add a set of named indexes, along with the assignments of packages to those indexes.
+    ///
+    /// A package that matches an assignment is only ever fetched from the assigned index, even if
+    /// it's available on other indexes. If multiple assignments match a package, the first one
+    /// wins.
+    ///
+    /// Returns an error if an assignment refers to an index that wasn't provided.
+    pub fn with_index_assignments(
+        self,
+        named_index: Vec<NamedIndex>,
+        index_assignments: Vec<IndexAssignment>,
+    ) -> Result<Self, IndexAssignmentError> {
+        for (i, index) in named_index.iter().enumerate() {
+            if named_index[..i]
+                .iter()
+                .any(|other| other.name == index.name)
+            {
+                return Err(IndexAssignmentError::DuplicateIndex(index.name.clone()));
+            }
+        }
+        for assignment in &index_assignments {
+            if !named_index
+                .iter()
+                .any(|index| index.name == assignment.index)
+            {
+                return Err(IndexAssignmentError::UnknownIndex(
+                    assignment.pattern.to_string(),
+                    assignment.index.clone(),
+                ));
+            }
+        }
+        Ok(Self {
+            named_index,
+            index_assignments,
+            ..self
+        })
+    }
+
     /// Combine a set of index locations.
     ///
     /// If either the current or the other index locations have `no_index` set, the result will
@@ -230,6 +378,8 @@ impl IndexLocations {
             index: self.index.or(index),
             extra_index: self.extra_index.into_iter().chain(extra_index).collect(),
             flat_index: self.flat_index.into_iter().chain(flat_index).collect(),
+            named_index: self.named_index,
+            index_assignments: self.index_assignments,
             no_index: self.no_index || no_index,
         }
     }
@@ -266,6 +416,17 @@ impl<'a> IndexLocations {
         self.index().into_iter().chain(self.extra_index())
     }
 
+    /// Return an iterator over the [`NamedIndex`] entries.
+    ///
+    /// If `--no-index` is set, return an empty iterator.
+    pub fn named_index(&'a self) -> impl Iterator<Item = &'a NamedIndex> + 'a {
+        if self.no_index {
+            Either::Left(std::iter::empty())
+        } else {
+            Either::Right(self.named_index.iter())
+        }
+    }
+
     /// Return an iterator over the [`FlatIndexLocation`] entries.
     pub fn flat_index(&'a self) -> impl Iterator<Item = &'a FlatIndexLocation> + 'a {
         self.flat_index.iter()
@@ -276,6 +437,8 @@ impl<'a> IndexLocations {
         IndexUrls {
             index: self.index.clone(),
             extra_index: self.extra_index.clone(),
+            named_index: self.named_index.clone(),
+            index_assignments: self.index_assignments.clone(),
             no_index: self.no_index,
         }
     }
@@ -283,6 +446,7 @@ impl<'a> IndexLocations {
     /// Return an iterator over all [`Url`] entries.
     pub fn urls(&'a self) -> impl Iterator<Item = &'a Url> + 'a {
         self.indexes()
+            .chain(self.named_index().map(|index| &index.url))
             .map(IndexUrl::url)
             .chain(self.flat_index.iter().filter_map(|index| match index {
                 FlatIndexLocation::Path(_) => None,
@@ -298,6 +462,8 @@ impl<'a> IndexLocations {
 pub struct IndexUrls {
     index: Option<IndexUrl>,
     extra_index: Vec<IndexUrl>,
+    named_index: Vec<NamedIndex>,
+    index_assignments: Vec<IndexAssignment>,
     no_index: bool,
 }
 
@@ -307,6 +473,8 @@ impl Default for IndexUrls {
         Self {
             index: Some(DEFAULT_INDEX_URL.clone()),
             extra_index: Vec::new(),
+            named_index: Vec::new(),
+            index_assignments: Vec::new(),
             no_index: false,
         }
     }
@@ -345,6 +513,22 @@ impl<'a> IndexUrls {
     pub fn indexes(&'a self) -> impl Iterator<Item = &'a IndexUrl> + 'a {
         self.extra_index().chain(self.index())
     }
+
+    /// Return the [`NamedIndex`] to which the given package is assigned, if any.
+    ///
+    /// If `no_index` was enabled, then this always returns `None`.
+    pub fn assigned_index(&'a self, package_name: &PackageName) -> Option<&'a NamedIndex> {
+        if self.no_index {
+            return None;
+        }
+        let assignment = self
+            .index_assignments
+            .iter()
+            .find(|assignment| assignment.matches(package_name))?;
+        self.named_index
+            .iter()
+            .find(|index| index.name == assignment.index)
+    }
 }
 
 impl From<IndexLocations> for IndexUrls {
@@ -352,6 +536,8 @@ impl From<IndexLocations> for IndexUrls {
         Self {
             index: locations.index,
             extra_index: locations.extra_index,
+            named_index: locations.named_index,
+            index_assignments: locations.index_assignments,
             no_index: locations.no_index,
         }
     }
@@ -384,4 +570,45 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn assigned_index() {
+        let named_index = vec![
+            NamedIndex::from_str("internal=https://example.com/simple").unwrap(),
+            NamedIndex::from_str("torch = https://download.pytorch.org/whl/cpu").unwrap(),
+        ];
+        let index_assignments = vec![
+            IndexAssignment::from_str("Acme_*=internal").unwrap(),
+            IndexAssignment::from_str("torch=torch").unwrap(),
+        ];
+        let index_urls = IndexLocations::default()
+            .with_index_assignments(named_index, index_assignments)
+            .unwrap()
+            .index_urls();
+
+        let assigned = |name: &str| {
+            index_urls
+                .assigned_index(&PackageName::from_str(name).unwrap())
+                .map(|index| index.name.as_str())
+        };
+        assert_eq!(assigned("acme-core"), Some("internal"));
+        assert_eq!(assigned("acme.web"), Some("internal"));
+        assert_eq!(assigned("torch"), Some("torch"));
+        assert_eq!(assigned("torchvision"), None);
+        assert_eq!(assigned("anyio"), None);
+    }
+
+    #[test]
+    fn unknown_assigned_index() {
+        let err = IndexLocations::default()
+            .with_index_assignments(
+                vec![],
+                vec![IndexAssignment::from_str("acme-core=internal").unwrap()],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Packages matching `acme-core` are assigned to the index `internal`, but no index with that name was provided (e.g., `--index internal=<url>`)"
+        );
+    }
 }
@@ -46,7 +46,9 @@ use uv_fs::normalize_url_path;
 // Parity with the crates.io version of pep508_rs
 use crate::verbatim_url::VerbatimUrlError;
 pub use uv_normalize::{ExtraName, InvalidNameError, PackageName};
-pub use verbatim_url::{expand_env_vars, split_scheme, strip_host, Scheme, VerbatimUrl};
+pub use verbatim_url::{
+    expand_env_vars, redact_url, split_scheme, strip_host, Scheme, VerbatimUrl,
+};
 
 mod marker;
 mod verbatim_url;
@@ -87,6 +87,8 @@ enum RequirementsTxtStatement {
     NoBinary(NoBinary),
     /// `only-binary`
     OnlyBinary(NoBuild),
+    /// `--trusted-host`
+    TrustedHost(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -337,6 +339,8 @@ pub struct RequirementsTxt {
     pub no_binary: NoBinary,
     /// Whether to allow only wheels, specified with `--only-binary`.
     pub only_binary: NoBuild,
+    /// The hosts to allow insecure connections to, specified with `--trusted-host`.
+    pub trusted_hosts: Vec<String>,
 }
 
 impl RequirementsTxt {
@@ -533,6 +537,9 @@ impl RequirementsTxt {
                 RequirementsTxtStatement::OnlyBinary(only_binary) => {
                     data.only_binary.extend(only_binary);
                 }
+                RequirementsTxtStatement::TrustedHost(host) => {
+                    data.trusted_hosts.push(host);
+                }
             }
         }
         Ok(data)
@@ -550,6 +557,7 @@ impl RequirementsTxt {
             no_index,
             no_binary,
             only_binary,
+            trusted_hosts,
         } = other;
         self.requirements.extend(requirements);
         self.constraints.extend(constraints);
@@ -562,6 +570,7 @@ impl RequirementsTxt {
         self.no_index = self.no_index || no_index;
         self.no_binary.extend(no_binary);
         self.only_binary.extend(only_binary);
+        self.trusted_hosts.extend(trusted_hosts);
     }
 }
 
@@ -665,6 +674,9 @@ fn parse_entry(
             }
         })?;
         RequirementsTxtStatement::OnlyBinary(NoBuild::from_arg(specifier))
+    } else if s.eat_if("--trusted-host") {
+        let given = parse_value(content, s, |c: char| !['\n', '\r', '#'].contains(&c))?;
+        RequirementsTxtStatement::TrustedHost(expand_env_vars(given).trim().to_string())
     } else if s.at(char::is_ascii_alphanumeric) || s.at(|char| matches!(char, '.' | '/' | '$')) {
         let (requirement, hashes) = parse_requirement_and_hashes(s, content, working_dir)?;
         RequirementsTxtStatement::RequirementEntry(RequirementEntry {
@@ -1754,6 +1766,7 @@ mod test {
             no_index: false,
             no_binary: None,
             only_binary: None,
+            trusted_hosts: [],
         }
         "###);
 
@@ -1815,6 +1828,7 @@ mod test {
                 ],
             ),
             only_binary: None,
+            trusted_hosts: [],
         }
         "###);
 
@@ -1882,6 +1896,7 @@ mod test {
             no_index: true,
             no_binary: None,
             only_binary: None,
+            trusted_hosts: [],
         }
         "###);
 
@@ -1931,6 +1946,39 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn nested_trusted_host() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            --trusted-host mirror.internal
+            -r child.txt
+        "})?;
+
+        let child = temp_dir.child("child.txt");
+        child.write_str(indoc! {"
+            --trusted-host=mirror.internal:8080  # A legacy mirror.
+        "})?;
+
+        let requirements = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            requirements.trusted_hosts,
+            vec![
+                "mirror.internal".to_string(),
+                "mirror.internal:8080".to_string()
+            ]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn editable_extra() {
         assert_eq!(
@@ -1,4 +1,5 @@
-use std::process::Command;
+use std::io::Write;
+use std::process::{Command, Stdio};
 
 use thiserror::Error;
 use tracing::debug;
@@ -31,6 +32,8 @@ pub enum Error {
     CliFailure(#[from] std::io::Error),
     #[error(transparent)]
     ParseFailed(#[from] std::string::FromUtf8Error),
+    #[error("`keyring {0}` failed: {1}")]
+    CommandFailed(&'static str, String),
 }
 
 /// Get credentials from keyring for given url
@@ -59,9 +62,22 @@ pub fn get_keyring_subprocess_auth(url: &Url) -> Result<Option<Credential>, Erro
         url.to_string(),
         username
     );
-    let output = match Command::new("keyring")
+    let password = keyring_get(url.as_str(), username)?;
+
+    Ok(password.map(|password| {
+        Credential::Basic(BasicAuthData {
+            username: username.to_string(),
+            password: Some(password),
+        })
+    }))
+}
+
+/// Get the password for the given service (e.g., an index URL) and username from the `keyring`
+/// CLI.
+pub(crate) fn keyring_get(service: &str, username: &str) -> Result<Option<String>, Error> {
+    match Command::new("keyring")
         .arg("get")
-        .arg(url.to_string())
+        .arg(service)
         .arg(username)
         .output()
     {
@@ -73,16 +89,52 @@ pub fn get_keyring_subprocess_auth(url: &Url) -> Result<Option<Credential>, Erro
         )),
         Ok(_) => Ok(None),
         Err(e) => Err(Error::CliFailure(e)),
-    };
+    }
+}
 
-    output.map(|password| {
-        password.map(|password| {
-            Credential::Basic(BasicAuthData {
-                username: username.to_string(),
-                password: Some(password),
-            })
-        })
-    })
+/// Store the password for the given service (e.g., an index URL) and username with the `keyring`
+/// CLI, which reads the password from stdin.
+pub fn keyring_set(service: &str, username: &str, password: &str) -> Result<(), Error> {
+    debug!("Running `keyring set` for `{service}` with username `{username}`");
+    let mut child = Command::new("keyring")
+        .arg("set")
+        .arg(service)
+        .arg(username)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        writeln!(stdin, "{password}")?;
+    }
+    let output = child.wait_with_output()?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(Error::CommandFailed(
+            "set",
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ))
+    }
+}
+
+/// Remove the password for the given service (e.g., an index URL) and username with the `keyring`
+/// CLI.
+pub fn keyring_delete(service: &str, username: &str) -> Result<(), Error> {
+    debug!("Running `keyring del` for `{service}` with username `{username}`");
+    let output = Command::new("keyring")
+        .arg("del")
+        .arg(service)
+        .arg(username)
+        .output()?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(Error::CommandFailed(
+            "del",
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ))
+    }
 }
 
 #[cfg(test)]
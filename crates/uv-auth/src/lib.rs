@@ -1,11 +1,16 @@
+mod credentials;
 mod keyring;
 mod middleware;
 mod store;
 
-pub use keyring::KeyringProvider;
+pub use credentials::{
+    credentials_file, proxy_credential, CredentialsError, CredentialsStore, Login,
+    StoredCredentials, StoredIndex,
+};
+pub use keyring::{keyring_delete, keyring_set, Error as KeyringError, KeyringProvider};
 pub use middleware::AuthMiddleware;
 use once_cell::sync::Lazy;
-pub use store::AuthenticationStore;
+pub use store::{AuthenticationStore, BasicAuthData, Credential};
 
 use url::Url;
 
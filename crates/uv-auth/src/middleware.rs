@@ -54,14 +54,20 @@ impl Middleware for AuthMiddleware {
         }
 
         // Try auth strategies in order of precedence:
-        if let Some(stored_auth) = GLOBAL_AUTH_STORE.get(&url) {
+        if let Some(auth) = GLOBAL_AUTH_STORE.get_index_credential(&url).await {
+            // If the URL belongs to an index with configured credentials, use them. These are
+            // matched by URL prefix, rather than by host, as multiple indexes on the same host
+            // (e.g., GitLab projects) may require different credentials.
+            debug!("Adding index credentials to: {url}");
+            req.headers_mut()
+                .insert(reqwest::header::AUTHORIZATION, authorization(&auth));
+            return next.run(req, _extensions).await;
+        } else if let Some(stored_auth) = GLOBAL_AUTH_STORE.get(&url) {
             // If we've already seen this URL, we can use the stored credentials
             if let Some(auth) = stored_auth {
                 debug!("Adding authentication to already-seen URL: {url}");
-                req.headers_mut().insert(
-                    reqwest::header::AUTHORIZATION,
-                    basic_auth(auth.username(), auth.password()),
-                );
+                req.headers_mut()
+                    .insert(reqwest::header::AUTHORIZATION, authorization(&auth));
             } else {
                 debug!("No credentials found for already-seen URL: {url}");
             }
@@ -71,19 +77,15 @@ impl Middleware for AuthMiddleware {
                 .and_then(|host| nrc.hosts.get(host).or_else(|| nrc.hosts.get("default")))
         }) {
             let auth = Credential::from(auth.to_owned());
-            req.headers_mut().insert(
-                reqwest::header::AUTHORIZATION,
-                basic_auth(auth.username(), auth.password()),
-            );
+            req.headers_mut()
+                .insert(reqwest::header::AUTHORIZATION, authorization(&auth));
             GLOBAL_AUTH_STORE.set(&url, Some(auth));
         } else if matches!(self.keyring_provider, KeyringProvider::Subprocess) {
             // If we have keyring support enabled, we check there as well
             match get_keyring_subprocess_auth(&url) {
                 Ok(Some(auth)) => {
-                    req.headers_mut().insert(
-                        reqwest::header::AUTHORIZATION,
-                        basic_auth(auth.username(), auth.password()),
-                    );
+                    req.headers_mut()
+                        .insert(reqwest::header::AUTHORIZATION, authorization(&auth));
                     GLOBAL_AUTH_STORE.set(&url, Some(auth));
                 }
                 Ok(None) => {
@@ -105,6 +107,27 @@ impl Middleware for AuthMiddleware {
     }
 }
 
+/// Create a `HeaderValue` for the given credentials.
+fn authorization(auth: &Credential) -> HeaderValue {
+    match auth {
+        Credential::Bearer(token) => bearer_auth(token),
+        Credential::Basic(_) | Credential::UrlEncoded(_) => {
+            basic_auth(auth.username().unwrap_or_default(), auth.password())
+        }
+    }
+}
+
+/// Create a `HeaderValue` for bearer token authentication.
+fn bearer_auth(token: &str) -> HeaderValue {
+    let mut header =
+        HeaderValue::from_str(&format!("Bearer {}", token.trim())).unwrap_or_else(|_| {
+            warn!("Ignoring bearer token with invalid characters");
+            HeaderValue::from_static("Bearer")
+        });
+    header.set_sensitive(true);
+    header
+}
+
 /// Create a `HeaderValue` for basic authentication.
 ///
 /// Source: <https://github.com/seanmonstar/reqwest/blob/2c11ef000b151c2eebeed2c18a7b81042220c6b0/src/util.rs#L3>
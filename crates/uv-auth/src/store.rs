@@ -1,28 +1,34 @@
-use std::{collections::HashMap, sync::Mutex};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 use netrc::Authenticator;
 use tracing::warn;
 use url::Url;
 
+use crate::credentials::{credentials_file, CredentialsError, CredentialsFile, IndexCredentials};
 use crate::NetLoc;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Credential {
     Basic(BasicAuthData),
     UrlEncoded(UrlAuthData),
+    /// A bearer token, e.g., for GitLab, Google Artifact Registry or AWS CodeArtifact.
+    Bearer(String),
 }
 
 impl Credential {
-    pub fn username(&self) -> &str {
+    pub fn username(&self) -> Option<&str> {
         match self {
-            Credential::Basic(auth) => &auth.username,
-            Credential::UrlEncoded(auth) => &auth.username,
+            Credential::Basic(auth) => Some(&auth.username),
+            Credential::UrlEncoded(auth) => Some(&auth.username),
+            Credential::Bearer(_) => None,
         }
     }
     pub fn password(&self) -> Option<&str> {
         match self {
             Credential::Basic(auth) => auth.password.as_deref(),
             Credential::UrlEncoded(auth) => auth.password.as_deref(),
+            Credential::Bearer(_) => None,
         }
     }
 }
@@ -64,6 +70,8 @@ pub struct BasicAuthData {
 
 pub struct AuthenticationStore {
     credentials: Mutex<HashMap<NetLoc, Option<Credential>>>,
+    /// The credentials configured for each index, read on first use if not configured upfront.
+    indexes: Mutex<Option<Vec<Arc<IndexCredentials>>>>,
 }
 
 impl Default for AuthenticationStore {
@@ -76,9 +84,65 @@ impl AuthenticationStore {
     pub fn new() -> Self {
         Self {
             credentials: Mutex::new(HashMap::new()),
+            indexes: Mutex::new(None),
         }
     }
 
+    /// Read the credentials configured for the given named indexes (as `(name, url)` pairs), and
+    /// for any indexes identified by URL in the credentials file.
+    ///
+    /// Credentials are read from the `UV_INDEX_<NAME>_USERNAME`, `UV_INDEX_<NAME>_PASSWORD`,
+    /// `UV_INDEX_<NAME>_TOKEN` and `UV_INDEX_<NAME>_CREDENTIAL_HELPER` environment variables, and
+    /// from the credentials file.
+    pub fn configure_indexes<'a>(
+        &self,
+        indexes: impl IntoIterator<Item = (&'a str, &'a Url)>,
+    ) -> Result<(), CredentialsError> {
+        let file = match credentials_file() {
+            Some(path) => CredentialsFile::read(&path)?,
+            None => CredentialsFile::default(),
+        };
+        let indexes = IndexCredentials::read(indexes, &file)?;
+        *self.indexes.lock().unwrap() = Some(indexes.into_iter().map(Arc::new).collect());
+        Ok(())
+    }
+
+    /// Return the credentials configured for the index that serves the given URL, if any.
+    ///
+    /// If multiple indexes match, the most specific (i.e., longest) URL prefix wins.
+    ///
+    /// If the indexes weren't configured upfront, the credentials file is read on first use, such
+    /// that any credentials stored via `uv auth login` apply to all requests.
+    ///
+    /// The lock on the indexes is released before any credential helper is invoked.
+    pub async fn get_index_credential(&self, url: &Url) -> Option<Credential> {
+        let index = {
+            let mut indexes = self.indexes.lock().unwrap();
+            let indexes = indexes.get_or_insert_with(|| {
+                let file = match credentials_file().map(|path| CredentialsFile::read(&path)) {
+                    Some(Ok(file)) => file,
+                    Some(Err(err)) => {
+                        warn!("Failed to read credentials file: {err}");
+                        return Vec::new();
+                    }
+                    None => return Vec::new(),
+                };
+                IndexCredentials::read([], &file)
+                    .map(|indexes| indexes.into_iter().map(Arc::new).collect())
+                    .unwrap_or_else(|err| {
+                        warn!("Failed to read credentials file: {err}");
+                        Vec::new()
+                    })
+            });
+            indexes
+                .iter()
+                .filter(|index| index.matches(url))
+                .max_by_key(|index| index.specificity())
+                .cloned()?
+        };
+        index.credential(url).await
+    }
+
     pub fn get(&self, url: &Url) -> Option<Option<Credential>> {
         let netloc = NetLoc::from(url);
         let credentials = self.credentials.lock().unwrap();
@@ -22,7 +22,7 @@ use serde::{de, Deserialize, Deserializer};
 use tempfile::{tempdir_in, TempDir};
 use thiserror::Error;
 use tokio::process::Command;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Semaphore};
 use tracing::{debug, info_span, instrument, Instrument};
 
 use distribution_types::Resolution;
@@ -372,6 +372,10 @@ pub struct SourceBuild {
     modified_path: OsString,
     /// Environment variables to be passed in during metadata or wheel building
     environment_variables: FxHashMap<OsString, OsString>,
+    /// The resolved requirements installed into the build environment, if the build is isolated.
+    build_requirements: Option<Resolution>,
+    /// Limits the number of build backend invocations that run concurrently across all builds.
+    concurrent_builds: Arc<Semaphore>,
 }
 
 impl SourceBuild {
@@ -392,6 +396,7 @@ impl SourceBuild {
         build_isolation: BuildIsolation<'_>,
         build_kind: BuildKind,
         mut environment_variables: FxHashMap<OsString, OsString>,
+        concurrent_builds: Arc<Semaphore>,
     ) -> Result<Self, Error> {
         let temp_dir = tempdir_in(build_context.cache().root())?;
 
@@ -422,7 +427,7 @@ impl SourceBuild {
 
         // Setup the build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
-        if build_isolation.is_isolated() {
+        let build_requirements = if build_isolation.is_isolated() {
             let resolved_requirements = Self::get_resolved_requirements(
                 build_context,
                 source_build_context,
@@ -437,7 +442,11 @@ impl SourceBuild {
                 .map_err(|err| {
                     Error::RequirementsInstall("build-system.requires (install)", err)
                 })?;
-        }
+
+            Some(resolved_requirements)
+        } else {
+            None
+        };
 
         // Figure out what the modified path should be
         // Remove the PATH variable from the environment variables if it's there
@@ -484,6 +493,7 @@ impl SourceBuild {
                     &environment_variables,
                     &modified_path,
                     &temp_dir,
+                    &concurrent_builds,
                 )
                 .await?;
             }
@@ -501,9 +511,17 @@ impl SourceBuild {
             package_id,
             environment_variables,
             modified_path,
+            build_requirements,
+            concurrent_builds,
         })
     }
 
+    /// Return the resolved requirements that were installed into the build environment, or
+    /// `None` if build isolation is disabled.
+    pub fn build_requirements(&self) -> Option<&Resolution> {
+        self.build_requirements.as_ref()
+    }
+
     async fn get_resolved_requirements(
         build_context: &impl BuildContext,
         source_build_context: SourceBuildContext,
@@ -689,6 +707,7 @@ impl SourceBuild {
             &self.source_tree,
             &self.environment_variables,
             &self.modified_path,
+            &self.concurrent_builds,
         )
         .instrument(span)
         .await?;
@@ -740,6 +759,11 @@ impl SourceBuild {
                 script="setup.py bdist_wheel",
                 python_version = %self.venv.interpreter().python_version()
             );
+            let _permit = self
+                .concurrent_builds
+                .acquire()
+                .await
+                .expect("the build semaphore is never closed");
             let output = Command::new(python_interpreter)
                 .args(["setup.py", "bdist_wheel"])
                 .current_dir(self.source_tree.simplified())
@@ -822,6 +846,7 @@ impl SourceBuild {
             &self.source_tree,
             &self.environment_variables,
             &self.modified_path,
+            &self.concurrent_builds,
         )
         .instrument(span)
         .await?;
@@ -859,6 +884,10 @@ impl SourceBuildTrait for SourceBuild {
     async fn wheel<'a>(&'a self, wheel_dir: &'a Path) -> anyhow::Result<String> {
         Ok(self.build(wheel_dir).await?)
     }
+
+    fn build_requirements(&self) -> Option<&Resolution> {
+        self.build_requirements.as_ref()
+    }
 }
 
 fn escape_path_for_python(path: &Path) -> String {
@@ -880,6 +909,7 @@ async fn create_pep517_build_environment(
     environment_variables: &FxHashMap<OsString, OsString>,
     modified_path: &OsString,
     temp_dir: &TempDir,
+    concurrent_builds: &Semaphore,
 ) -> Result<(), Error> {
     // Write the hook output to a file so that we can read it back reliably.
     let outfile = temp_dir
@@ -921,6 +951,7 @@ async fn create_pep517_build_environment(
         source_tree,
         environment_variables,
         modified_path,
+        concurrent_builds,
     )
     .instrument(span)
     .await?;
@@ -984,13 +1015,22 @@ async fn create_pep517_build_environment(
 }
 
 /// It is the caller's responsibility to create an informative span.
+///
+/// The script only runs once a permit is available from `concurrent_builds`. The permit is held
+/// for the duration of the script alone (and not, e.g., while installing build requirements, which
+/// may themselves require builds), such that nested builds can't deadlock.
 async fn run_python_script(
     venv: &PythonEnvironment,
     script: &str,
     source_tree: &Path,
     environment_variables: &FxHashMap<OsString, OsString>,
     modified_path: &OsString,
+    concurrent_builds: &Semaphore,
 ) -> Result<Output, Error> {
+    let _permit = concurrent_builds
+        .acquire()
+        .await
+        .expect("the build semaphore is never closed");
     Command::new(venv.python_executable())
         .args(["-c", script])
         .current_dir(source_tree.simplified())
@@ -1,13 +1,13 @@
 use pep508_rs::MarkerEnvironment;
 use platform_tags::Platform;
+use reqwest::redirect::Policy;
 use reqwest::{Client, ClientBuilder};
 use reqwest_middleware::ClientWithMiddleware;
-use reqwest_retry::policies::ExponentialBackoff;
-use reqwest_retry::RetryTransientMiddleware;
 use std::env;
 use std::fmt::Debug;
 use std::ops::Deref;
 use std::path::Path;
+use std::time::Duration;
 use tracing::debug;
 use uv_auth::{AuthMiddleware, KeyringProvider};
 use uv_fs::Simplified;
@@ -15,15 +15,25 @@ use uv_version::version;
 use uv_warnings::warn_user_once;
 
 use crate::linehaul::LineHaul;
-use crate::middleware::OfflineMiddleware;
-use crate::tls::Roots;
-use crate::{tls, Connectivity};
+use crate::middleware::{HostTlsMiddleware, HostTlsSettings, OfflineMiddleware};
+use crate::proxy::{ProxyConfig, ProxyMiddleware};
+use crate::retry::{RetryMiddleware, RetryPolicy};
+use crate::tls::{ClientCertificate, HostCaBundle, Roots, TlsOptions};
+use crate::user_agent::UserAgentMiddleware;
+use crate::{tls, Connectivity, TrustedHost, UserAgentSuffix};
 
 /// A builder for an [`BaseClient`].
 #[derive(Debug, Clone)]
 pub struct BaseClientBuilder<'a> {
     keyring_provider: KeyringProvider,
     native_tls: bool,
+    client_cert: Option<ClientCertificate>,
+    ca_bundles: Vec<HostCaBundle>,
+    insecure_hosts: Vec<TrustedHost>,
+    proxy: Option<ProxyConfig>,
+    linehaul: bool,
+    user_agent_suffixes: Vec<UserAgentSuffix>,
+    stale_if_error: Option<Duration>,
     retries: u32,
     connectivity: Connectivity,
     client: Option<Client>,
@@ -42,6 +52,13 @@ impl BaseClientBuilder<'_> {
         Self {
             keyring_provider: KeyringProvider::default(),
             native_tls: false,
+            client_cert: None,
+            ca_bundles: Vec::new(),
+            insecure_hosts: Vec::new(),
+            proxy: None,
+            linehaul: true,
+            user_agent_suffixes: Vec::new(),
+            stale_if_error: None,
             connectivity: Connectivity::Online,
             retries: 3,
             client: None,
@@ -76,6 +93,60 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Present the given client certificate to its hosts, for mutual TLS authentication.
+    #[must_use]
+    pub fn client_cert(mut self, client_cert: Option<ClientCertificate>) -> Self {
+        self.client_cert = client_cert;
+        self
+    }
+
+    /// Trust the given CA certificates, in addition to the default roots, for their hosts.
+    #[must_use]
+    pub fn ca_bundles(mut self, ca_bundles: Vec<HostCaBundle>) -> Self {
+        self.ca_bundles = ca_bundles;
+        self
+    }
+
+    /// Allow insecure connections to the given hosts, skipping certificate verification.
+    #[must_use]
+    pub fn allow_insecure_hosts(mut self, insecure_hosts: Vec<TrustedHost>) -> Self {
+        self.insecure_hosts = insecure_hosts;
+        self
+    }
+
+    /// Route requests through the given proxies, rather than those configured in the environment.
+    #[must_use]
+    pub fn proxy(mut self, proxy: Option<ProxyConfig>) -> Self {
+        self.proxy = proxy;
+        self
+    }
+
+    /// Whether to include the linehaul metadata (i.e., details of the platform and Python
+    /// interpreter) in the `User-Agent`, in addition to the uv version.
+    #[must_use]
+    pub fn linehaul(mut self, linehaul: bool) -> Self {
+        self.linehaul = linehaul;
+        self
+    }
+
+    /// Append the given suffixes to the `User-Agent` of requests to their hosts.
+    #[must_use]
+    pub fn user_agent_suffixes(mut self, user_agent_suffixes: Vec<UserAgentSuffix>) -> Self {
+        self.user_agent_suffixes = user_agent_suffixes;
+        self
+    }
+
+    /// If revalidating a cached response fails, as the index is unreachable or responds with a
+    /// server error, use the cached response if it has been stale for at most the given duration
+    /// (i.e., `stale-if-error`). Disabled by default.
+    #[must_use]
+    pub fn stale_if_error(mut self, stale_if_error: Option<Duration>) -> Self {
+        self.stale_if_error = stale_if_error;
+        self
+    }
+
+    /// Use the given client, rather than building one. If any TLS settings apply to specific
+    /// hosts, the client must not follow redirects (see [`Policy::none`]).
     #[must_use]
     pub fn client(mut self, client: Client) -> Self {
         self.client = Some(client);
@@ -102,12 +173,16 @@ impl<'a> BaseClientBuilder<'a> {
         // Create user agent.
         let mut user_agent_string = format!("uv/{}", version());
 
-        // Add linehaul metadata.
-        if let Some(markers) = self.markers {
-            let linehaul = LineHaul::new(markers, self.platform);
-            if let Ok(output) = serde_json::to_string(&linehaul) {
-                user_agent_string += &format!(" {}", output);
+        // Add linehaul metadata, unless disabled.
+        if self.linehaul {
+            if let Some(markers) = self.markers {
+                let linehaul = LineHaul::new(markers, self.platform);
+                if let Ok(output) = serde_json::to_string(&linehaul) {
+                    user_agent_string += &format!(" {}", output);
+                }
             }
+        } else {
+            debug!("Omitting linehaul metadata from the `User-Agent`");
         }
 
         // Timeout options, matching https://doc.rust-lang.org/nightly/cargo/reference/config.html#httptimeout
@@ -127,50 +202,91 @@ impl<'a> BaseClientBuilder<'a> {
             .unwrap_or(default_timeout);
         debug!("Using registry request timeout of {}s", timeout);
 
-        // Initialize the base client.
-        let client = self.client.clone().unwrap_or_else(|| {
-            // Check for the presence of an `SSL_CERT_FILE`.
-            let ssl_cert_file_exists = env::var_os("SSL_CERT_FILE").is_some_and(|path| {
-                let path_exists = Path::new(&path).exists();
-                if !path_exists {
-                    warn_user_once!(
-                        "Ignoring invalid `SSL_CERT_FILE`. File does not exist: {}.",
-                        path.simplified_display()
-                    );
-                }
-                path_exists
-            });
-            // Load the TLS configuration.
-            let tls = tls::load(if self.native_tls || ssl_cert_file_exists {
-                Roots::Native
-            } else {
-                Roots::Webpki
+        // Retry options, with `UV_HTTP_RETRIES` taking precedence over the configured value.
+        let retries = env::var("UV_HTTP_RETRIES")
+            .ok()
+            .and_then(|value| {
+                value.parse::<u32>()
+                    .map_err(|_| {
+                        warn_user_once!("Ignoring invalid value from environment for UV_HTTP_RETRIES. Expected a non-negative integer, got \"{value}\".");
+                    })
+                    .ok()
             })
-            .expect("Failed to load TLS configuration.");
+            .unwrap_or(self.retries);
 
-            let client_core = ClientBuilder::new()
-                .user_agent(user_agent_string)
-                .pool_max_idle_per_host(20)
-                .timeout(std::time::Duration::from_secs(timeout))
-                .use_preconfigured_tls(tls);
+        // Resolve the proxies, along with any credentials from the credential store.
+        let proxy = self.proxy.clone().map(ProxyConfig::with_credentials);
+        if let Some(proxy) = &proxy {
+            proxy.log();
+        }
 
-            client_core.build().expect("Failed to build HTTP client.")
-        });
+        // Collect the TLS settings that only apply to specific hosts.
+        let host_tls = HostTlsSettings {
+            client_cert: self.client_cert.clone(),
+            ca_bundles: self.ca_bundles.clone(),
+            insecure_hosts: self.insecure_hosts.clone(),
+        };
+        for ca_bundle in &host_tls.ca_bundles {
+            debug!(
+                "Trusting additional CA certificates for: {}",
+                ca_bundle.host()
+            );
+        }
+        for host in &host_tls.insecure_hosts {
+            warn_user_once!(
+                "Certificate verification is disabled for `{host}`; connections to this host are insecure."
+            );
+        }
+
+        // Initialize the base client. If any TLS settings apply to specific hosts, redirects are
+        // followed by the middleware instead, such that each hop uses the settings for its host.
+        let factory = ClientFactory {
+            user_agent: user_agent_string.clone(),
+            timeout,
+            roots: self.roots(),
+            proxy: proxy.clone(),
+            follow_redirects: host_tls.is_empty(),
+        };
+        let client = self
+            .client
+            .clone()
+            .unwrap_or_else(|| factory.build(TlsOptions::default()));
 
         // Wrap in any relevant middleware.
         let client = match self.connectivity {
             Connectivity::Online => {
                 let client = reqwest_middleware::ClientBuilder::new(client.clone());
 
-                // Initialize the retry strategy.
-                let retry_policy =
-                    ExponentialBackoff::builder().build_with_max_retries(self.retries);
-                let retry_strategy = RetryTransientMiddleware::new_with_policy(retry_policy);
-                let client = client.with(retry_strategy);
+                // Append any custom suffixes to the `User-Agent` of requests to their hosts.
+                let client = if self.user_agent_suffixes.is_empty() {
+                    client
+                } else {
+                    client.with(UserAgentMiddleware::new(
+                        user_agent_string,
+                        self.user_agent_suffixes.clone(),
+                    ))
+                };
+
+                // Log the proxy used for each request.
+                let client = match proxy {
+                    Some(proxy) => client.with(ProxyMiddleware::new(proxy)),
+                    None => client,
+                };
+
+                // Initialize the retry strategy, which honors `Retry-After` on rate-limited and
+                // unavailable responses.
+                let client = client.with(RetryMiddleware::new(RetryPolicy::new(retries)));
 
                 // Initialize the authentication middleware to set headers.
                 let client = client.with(AuthMiddleware::new(self.keyring_provider));
 
+                // Route requests to hosts with custom TLS settings through their own clients.
+                let client = if host_tls.is_empty() {
+                    client
+                } else {
+                    client.with(HostTlsMiddleware::new(host_tls, factory))
+                };
+
                 client.build()
             }
             Connectivity::Offline => reqwest_middleware::ClientBuilder::new(client.clone())
@@ -182,10 +298,67 @@ impl<'a> BaseClientBuilder<'a> {
             connectivity: self.connectivity,
             client,
             timeout,
+            stale_if_error: self.stale_if_error,
+        }
+    }
+
+    /// Determine the root certificates to load.
+    fn roots(&self) -> Roots {
+        // Check for the presence of an `SSL_CERT_FILE`.
+        let ssl_cert_file_exists = env::var_os("SSL_CERT_FILE").is_some_and(|path| {
+            let path_exists = Path::new(&path).exists();
+            if !path_exists {
+                warn_user_once!(
+                    "Ignoring invalid `SSL_CERT_FILE`. File does not exist: {}.",
+                    path.simplified_display()
+                );
+            }
+            path_exists
+        });
+        if self.native_tls || ssl_cert_file_exists {
+            Roots::Native
+        } else {
+            Roots::Webpki
         }
     }
 }
 
+/// The settings shared by every underlying [`Client`], which differ only in their TLS options.
+#[derive(Debug, Clone)]
+pub(crate) struct ClientFactory {
+    user_agent: String,
+    timeout: u64,
+    roots: Roots,
+    proxy: Option<ProxyConfig>,
+    /// Whether the client should follow redirects itself.
+    follow_redirects: bool,
+}
+
+impl ClientFactory {
+    /// Build an underlying [`Client`] with the given TLS options.
+    pub(crate) fn build(&self, options: TlsOptions) -> Client {
+        // Load the TLS configuration.
+        let tls = tls::load(self.roots, options).expect("Failed to load TLS configuration.");
+
+        let mut client_core = ClientBuilder::new()
+            .user_agent(&self.user_agent)
+            .pool_max_idle_per_host(20)
+            .timeout(std::time::Duration::from_secs(self.timeout))
+            .use_preconfigured_tls(tls);
+
+        if !self.follow_redirects {
+            client_core = client_core.redirect(Policy::none());
+        }
+
+        // Setting a proxy disables any proxies from the environment.
+        if let Some(proxy) = &self.proxy {
+            client_core = client_core.proxy(proxy.to_reqwest());
+        }
+
+        client_core.build().expect("Failed to build HTTP client.")
+    }
+}
+
 /// A base client for HTTP requests
 #[derive(Debug, Clone)]
 pub struct BaseClient {
@@ -195,6 +368,8 @@ pub struct BaseClient {
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
     timeout: u64,
+    /// How long a stale cached response may be used in place of a failed request, if at all.
+    stale_if_error: Option<Duration>,
 }
 
 impl BaseClient {
@@ -212,6 +387,12 @@ impl BaseClient {
     pub fn connectivity(&self) -> Connectivity {
         self.connectivity
     }
+
+    /// How long a stale cached response may be used in place of a failed request, as per
+    /// `stale-if-error`, or `None` if stale responses are never used.
+    pub fn stale_if_error(&self) -> Option<Duration> {
+        self.stale_if_error
+    }
 }
 
 // To avoid excessively verbose call chains, as the [`BaseClient`] is often nested within other client types.
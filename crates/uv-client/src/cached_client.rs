@@ -1,3 +1,4 @@
+use std::time::SystemTime;
 use std::{borrow::Cow, future::Future, path::Path};
 
 use futures::FutureExt;
@@ -7,8 +8,10 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info_span, instrument, trace, warn, Instrument};
 
+use pep508_rs::redact_url;
 use uv_cache::{CacheEntry, Freshness};
 use uv_fs::write_atomic;
+use uv_warnings::warn_user_once;
 
 use crate::BaseClient;
 use crate::{
@@ -398,8 +401,13 @@ impl CachedClient {
             BeforeRequest::Stale(new_cache_policy_builder) => match cache_control {
                 CacheControl::None | CacheControl::MustRevalidate => {
                     debug!("Found stale response for: {}", req.url());
-                    self.send_cached_handle_stale(req, cached, new_cache_policy_builder)
-                        .await?
+                    self.send_cached_handle_stale(
+                        req,
+                        cache_control,
+                        cached,
+                        new_cache_policy_builder,
+                    )
+                    .await?
                 }
                 CacheControl::AllowStale => {
                     debug!("Found stale (but allowed) response for: {}", req.url());
@@ -424,16 +432,51 @@ impl CachedClient {
     async fn send_cached_handle_stale(
         &self,
         req: Request,
+        cache_control: CacheControl,
         cached: DataWithCachePolicy,
         new_cache_policy_builder: CachePolicyBuilder,
     ) -> Result<CachedResponse, Error> {
         let url = req.url().clone();
         debug!("Sending revalidation request for: {url}");
-        let response = self
+        let result = self
             .0
             .execute(req)
             .instrument(info_span!("revalidation_request", url = url.as_str()))
-            .await
+            .await;
+
+        // If the index is unreachable or failing, fall back to the stale response, as per
+        // `stale-if-error`. An explicit refresh (`MustRevalidate`) never falls back.
+        let reason = match &result {
+            Err(reqwest_middleware::Error::Reqwest(err))
+                if err.is_connect() || err.is_timeout() || err.is_request() =>
+            {
+                Some(err.to_string())
+            }
+            Ok(response) if response.status().is_server_error() => {
+                Some(format!("`{}`", response.status()))
+            }
+            _ => None,
+        };
+        if let (Some(reason), Some(stale_if_error)) = (reason, self.0.stale_if_error()) {
+            if matches!(cache_control, CacheControl::None)
+                && cached.cache_policy.allows_stale_if_error(stale_if_error)
+            {
+                let stale = cached.cache_policy.stale_amount(SystemTime::now());
+                debug!(
+                    "Failed to revalidate cached response for {} ({reason}); using a cached response that is {}s stale",
+                    redact_url(&url),
+                    stale.as_secs()
+                );
+                // Warn once per host, rather than once per request.
+                warn_user_once!(
+                    "Failed to revalidate cached responses from {}; using stale cached responses instead",
+                    url.host_str().unwrap_or(url.as_str())
+                );
+                return Ok(CachedResponse::FreshCache(cached));
+            }
+        }
+
+        let response = result
             .map_err(ErrorKind::from)?
             .error_for_status()
             .map_err(ErrorKind::from)?;
@@ -1,11 +1,14 @@
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
+use std::path::PathBuf;
 
 use async_http_range_reader::AsyncHttpRangeReaderError;
 use async_zip::error::ZipError;
 use url::Url;
 
 use distribution_filename::{WheelFilename, WheelFilenameError};
+use pep508_rs::redact_url;
+use uv_fs::Simplified;
 use uv_normalize::PackageName;
 
 use crate::html;
@@ -144,6 +147,12 @@ pub enum ErrorKind {
     #[error("Package `{0}` was not found in the registry.")]
     PackageNotFound(String),
 
+    /// The package was not found in the index to which it's assigned.
+    ///
+    /// Packages that are assigned to an index are never fetched from any other index.
+    #[error("Package `{0}` was not found in the `{1}` index.")]
+    PackageNotFoundInIndex(String, String),
+
     /// The metadata file could not be parsed.
     #[error("Couldn't parse metadata of {0} from {1}")]
     MetadataParseError(
@@ -170,10 +179,10 @@ pub enum ErrorKind {
     #[error(transparent)]
     ReqwestMiddlewareError(#[from] anyhow::Error),
 
-    #[error("Received some unexpected JSON from {url}")]
+    #[error("Received some unexpected JSON from {}", redact_url(.url))]
     BadJson { source: serde_json::Error, url: Url },
 
-    #[error("Received some unexpected HTML from {url}")]
+    #[error("Received some unexpected HTML from {}", redact_url(.url))]
     BadHtml { source: html::Error, url: Url },
 
     #[error(transparent)]
@@ -210,13 +219,13 @@ pub enum ErrorKind {
     #[error(transparent)]
     Persist(#[from] tempfile::PersistError),
 
-    #[error("Missing `Content-Type` header for {0}")]
+    #[error("Missing `Content-Type` header for {}", redact_url(.0))]
     MissingContentType(Url),
 
-    #[error("Invalid `Content-Type` header for {0}")]
+    #[error("Invalid `Content-Type` header for {}", redact_url(.0))]
     InvalidContentTypeHeader(Url, #[source] http::header::ToStrError),
 
-    #[error("Unsupported `Content-Type` \"{1}\" for {0}. Expected JSON or HTML.")]
+    #[error("Unsupported `Content-Type` \"{1}\" for {}. Expected JSON or HTML.", redact_url(.0))]
     UnsupportedMediaType(Url, String),
 
     #[error("Reading from cache archive failed: {0}")]
@@ -225,6 +234,13 @@ pub enum ErrorKind {
     #[error("Writing to cache archive failed: {0}")]
     ArchiveWrite(#[source] crate::rkyvutil::SerializerError),
 
+    /// The package was not found in a local (`file://`) index.
+    #[error("Package `{0}` was not found in the local index at: `{}`", .1.user_display())]
+    LocalPackageNotFound(String, PathBuf),
+
+    #[error("Local index URL is not a valid file path: {}", redact_url(.0))]
+    InvalidFileUrl(Url),
+
     #[error("Network connectivity is disabled, but the requested data wasn't found in the cache for: `{0}`")]
     Offline(String),
 }
@@ -239,7 +255,7 @@ impl From<reqwest_middleware::Error> for ErrorKind {
     fn from(error: reqwest_middleware::Error) -> Self {
         if let reqwest_middleware::Error::Middleware(ref underlying) = error {
             if let Some(err) = underlying.downcast_ref::<OfflineError>() {
-                return Self::Offline(err.url().to_string());
+                return Self::Offline(redact_url(err.url()).to_string());
             }
         }
 
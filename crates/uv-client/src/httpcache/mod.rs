@@ -838,6 +838,47 @@ impl ArchivedCachePolicy {
         true
     }
 
+    /// Returns true if the cached response may be served in place of a
+    /// response that failed (i.e., a connection error or a `5xx` status), as
+    /// per the `stale-if-error` extension in [RFC 5861 S4].
+    ///
+    /// The directive is provided by the client, as the number of seconds for
+    /// which a stale response remains usable, rather than by the origin
+    /// server. As with `max-stale`, a response with `must-revalidate` or
+    /// `no-cache` is never served stale.
+    ///
+    /// [RFC 5861 S4]: https://httpwg.org/specs/rfc5861.html#n-the-stale-if-error-cache-control-extension
+    pub fn allows_stale_if_error(&self, stale_if_error: Duration) -> bool {
+        if self.response.headers.cc.must_revalidate || self.response.headers.cc.no_cache {
+            tracing::trace!(
+                "cached request {} has a cached response that does not \
+                 permit staleness on error because the response has a \
+                 'must-revalidate' or 'no-cache' cache-control directive set",
+                self.request.uri,
+            );
+            return false;
+        }
+        let stale_amount = self.stale_amount(SystemTime::now());
+        if stale_amount <= stale_if_error {
+            tracing::trace!(
+                "cached request {} has a cached response that allows staleness \
+                 on error because the stale amount is {} seconds and \
+                 'stale-if-error' is {} seconds",
+                self.request.uri,
+                stale_amount.as_secs(),
+                stale_if_error.as_secs(),
+            );
+            return true;
+        }
+        false
+    }
+
+    /// Returns how long the cached response has been stale for, i.e., the
+    /// amount by which its age exceeds its freshness lifetime.
+    pub fn stale_amount(&self, now: SystemTime) -> Duration {
+        self.age(now).saturating_sub(self.freshness_lifetime())
+    }
+
     /// Returns true if we're allowed to serve a stale response, as per [RFC
     /// 9111 S4.2.4].
     ///
@@ -3,11 +3,15 @@ pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithC
 pub use error::{BetterReqwestError, Error, ErrorKind};
 pub use flat_index::{FlatDistributions, FlatIndex, FlatIndexClient, FlatIndexError};
 pub use linehaul::LineHaul;
+pub use proxy::{IndexProxy, ProxyBypass, ProxyConfig, ProxyError};
 pub use registry_client::{
     Connectivity, RegistryClient, RegistryClientBuilder, SimpleMetadata, SimpleMetadatum,
     VersionFiles,
 };
 pub use rkyvutil::OwnedArchive;
+pub use tls::{CertificateError, ClientCertificate, HostCaBundle};
+pub use trusted_host::{TrustedHost, TrustedHostError};
+pub use user_agent::{UserAgentSuffix, UserAgentSuffixError};
 
 mod base_client;
 mod cached_client;
@@ -17,7 +21,11 @@ mod html;
 mod httpcache;
 mod linehaul;
 mod middleware;
+mod proxy;
 mod registry_client;
 mod remote_metadata;
+mod retry;
 mod rkyvutil;
 mod tls;
+mod trusted_host;
+mod user_agent;
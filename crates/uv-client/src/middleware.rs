@@ -1,10 +1,23 @@
 use std::fmt::Debug;
+use std::sync::Mutex;
 
-use reqwest::{Request, Response};
+use reqwest::header::{
+    AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, COOKIE, LOCATION, PROXY_AUTHORIZATION,
+    TRANSFER_ENCODING, WWW_AUTHENTICATE,
+};
+use reqwest::{Client, Method, Request, Response, StatusCode};
 use reqwest_middleware::{Middleware, Next};
+use rustc_hash::FxHashMap;
 use task_local_extensions::Extensions;
+use tracing::trace;
 use url::Url;
 
+use pep508_rs::redact_url;
+
+use crate::base_client::ClientFactory;
+use crate::tls::{ClientCertificate, HostCaBundle, TlsOptions};
+use crate::TrustedHost;
+
 /// A custom error type for the offline middleware.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct OfflineError {
@@ -20,7 +33,7 @@ impl OfflineError {
 
 impl std::fmt::Display for OfflineError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Network connectivity is disabled, but the requested data wasn't found in the cache for: `{}`", self.url)
+        write!(f, "Network connectivity is disabled, but the requested data wasn't found in the cache for: `{}`", redact_url(&self.url))
     }
 }
 
@@ -45,3 +58,182 @@ impl Middleware for OfflineMiddleware {
         ))
     }
 }
+
+/// The TLS settings that apply to specific hosts, in lieu of the defaults.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HostTlsSettings {
+    /// The client certificate to present to its hosts, if any.
+    pub(crate) client_cert: Option<ClientCertificate>,
+    /// Additional CA certificates to trust for specific hosts.
+    pub(crate) ca_bundles: Vec<HostCaBundle>,
+    /// The hosts for which certificate verification is skipped.
+    pub(crate) insecure_hosts: Vec<TrustedHost>,
+}
+
+/// Identifies a combination of [`TlsOptions`], i.e., a client certificate, a CA bundle (by index),
+/// and whether the host is insecure.
+type TlsOptionsKey = (bool, Option<usize>, bool);
+
+impl HostTlsSettings {
+    /// Returns `true` if the settings don't apply to any host.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.client_cert.is_none() && self.ca_bundles.is_empty() && self.insecure_hosts.is_empty()
+    }
+
+    /// Returns the [`TlsOptions`] for the given URL, or `None` if the defaults apply.
+    fn options(&self, url: &Url) -> Option<(TlsOptionsKey, TlsOptions)> {
+        let client_cert = self
+            .client_cert
+            .as_ref()
+            .filter(|client_cert| client_cert.matches(url));
+        let ca_bundle = self
+            .ca_bundles
+            .iter()
+            .position(|ca_bundle| ca_bundle.matches(url));
+        let insecure = self.insecure_hosts.iter().any(|host| host.matches(url));
+        if client_cert.is_none() && ca_bundle.is_none() && !insecure {
+            return None;
+        }
+        Some((
+            (client_cert.is_some(), ca_bundle, insecure),
+            TlsOptions {
+                client_cert,
+                ca_bundle: ca_bundle.map(|index| &self.ca_bundles[index]),
+                insecure,
+            },
+        ))
+    }
+}
+
+/// The maximum number of redirects to follow, matching the default policy of `reqwest`.
+const MAX_REDIRECTS: usize = 10;
+
+/// A middleware that sends requests to hosts with custom TLS settings (e.g., a client
+/// certificate, a CA bundle, or skipped certificate verification) through a separate client, such
+/// that the settings never apply to other hosts.
+///
+/// Redirects are followed by the middleware itself, rather than the underlying clients, such that
+/// each hop is sent with the settings for its own host. As such, none of the underlying clients
+/// may follow redirects.
+///
+/// Should be registered last, such that all other middleware (e.g., authentication and retries)
+/// apply to requests sent through any client.
+pub(crate) struct HostTlsMiddleware {
+    settings: HostTlsSettings,
+    factory: ClientFactory,
+    /// The clients for each combination of TLS options, created on first use.
+    clients: Mutex<FxHashMap<TlsOptionsKey, Client>>,
+}
+
+impl HostTlsMiddleware {
+    pub(crate) fn new(settings: HostTlsSettings, factory: ClientFactory) -> Self {
+        Self {
+            settings,
+            factory,
+            clients: Mutex::default(),
+        }
+    }
+
+    /// Send a single request, through the client for its host.
+    async fn send(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let Some((key, options)) = self.settings.options(req.url()) else {
+            return next.run(req, extensions).await;
+        };
+        trace!(
+            "Using custom TLS settings for: {}",
+            req.url().host_str().unwrap_or_default()
+        );
+        let client = self
+            .clients
+            .lock()
+            .expect("client lock is poisoned")
+            .entry(key)
+            .or_insert_with(|| self.factory.build(options))
+            .clone();
+        Ok(client.execute(req).await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for HostTlsMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let mut req = req;
+        let mut redirects = 0;
+        loop {
+            // Retain a copy of the request, from which to follow a redirect.
+            let previous = req.try_clone();
+            let response = self.send(req, extensions, next.clone()).await?;
+            let Some(redirect) =
+                previous.and_then(|previous| redirect_request(previous, &response))
+            else {
+                return Ok(response);
+            };
+            if redirects == MAX_REDIRECTS {
+                return Err(reqwest_middleware::Error::Middleware(anyhow::anyhow!(
+                    "Exceeded the maximum of {MAX_REDIRECTS} redirects for: {}",
+                    redact_url(response.url())
+                )));
+            }
+            redirects += 1;
+            trace!("Following redirect to: {}", redact_url(redirect.url()));
+            req = redirect;
+        }
+    }
+}
+
+/// Returns the request to send to follow the redirect in the given response, if any, following
+/// the same semantics as `reqwest`.
+fn redirect_request(mut req: Request, response: &Response) -> Option<Request> {
+    let status = response.status();
+    if !matches!(
+        status,
+        StatusCode::MOVED_PERMANENTLY
+            | StatusCode::FOUND
+            | StatusCode::SEE_OTHER
+            | StatusCode::TEMPORARY_REDIRECT
+            | StatusCode::PERMANENT_REDIRECT
+    ) {
+        return None;
+    }
+    let location = response.headers().get(LOCATION)?.to_str().ok()?;
+    let url = response.url().join(location).ok()?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+
+    // As with browsers, a `303 See Other` (or a `301` or `302` in response to a `POST`) is followed
+    // with a `GET`, without a body.
+    let see_other = status == StatusCode::SEE_OTHER && req.method() != Method::HEAD;
+    let moved = matches!(status, StatusCode::MOVED_PERMANENTLY | StatusCode::FOUND)
+        && req.method() == Method::POST;
+    if see_other || moved {
+        *req.method_mut() = Method::GET;
+        *req.body_mut() = None;
+        for header in [CONTENT_TYPE, CONTENT_LENGTH, TRANSFER_ENCODING] {
+            req.headers_mut().remove(header);
+        }
+    }
+
+    // Never send credentials to a different host.
+    if url.host_str() != req.url().host_str()
+        || url.port_or_known_default() != req.url().port_or_known_default()
+        || url.scheme() != req.url().scheme()
+    {
+        for header in [AUTHORIZATION, COOKIE, PROXY_AUTHORIZATION, WWW_AUTHENTICATE] {
+            req.headers_mut().remove(header);
+        }
+    }
+
+    *req.url_mut() = url;
+    Some(req)
+}
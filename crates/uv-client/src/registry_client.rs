@@ -1,7 +1,8 @@
 use std::collections::BTreeMap;
 use std::fmt::Debug;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
 use async_http_range_reader::AsyncHttpRangeReader;
 use futures::{FutureExt, TryStreamExt};
@@ -30,7 +31,10 @@ use crate::cached_client::CacheControl;
 use crate::html::SimpleHtml;
 use crate::remote_metadata::wheel_metadata_from_remote_zip;
 use crate::rkyvutil::OwnedArchive;
-use crate::{CachedClient, CachedClientError, Error, ErrorKind};
+use crate::{
+    CachedClient, CachedClientError, ClientCertificate, Error, ErrorKind, HostCaBundle,
+    ProxyConfig, TrustedHost, UserAgentSuffix,
+};
 
 /// A builder for an [`RegistryClient`].
 #[derive(Debug, Clone)]
@@ -39,6 +43,13 @@ pub struct RegistryClientBuilder<'a> {
     index_strategy: IndexStrategy,
     keyring_provider: KeyringProvider,
     native_tls: bool,
+    client_cert: Option<ClientCertificate>,
+    ca_bundles: Vec<HostCaBundle>,
+    insecure_hosts: Vec<TrustedHost>,
+    proxy: Option<ProxyConfig>,
+    linehaul: bool,
+    user_agent_suffixes: Vec<UserAgentSuffix>,
+    stale_if_error: Option<Duration>,
     retries: u32,
     connectivity: Connectivity,
     cache: Cache,
@@ -54,6 +65,13 @@ impl RegistryClientBuilder<'_> {
             index_strategy: IndexStrategy::default(),
             keyring_provider: KeyringProvider::default(),
             native_tls: false,
+            client_cert: None,
+            ca_bundles: Vec::new(),
+            insecure_hosts: Vec::new(),
+            proxy: None,
+            linehaul: true,
+            user_agent_suffixes: Vec::new(),
+            stale_if_error: None,
             cache,
             connectivity: Connectivity::Online,
             retries: 3,
@@ -101,6 +119,48 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn client_cert(mut self, client_cert: Option<ClientCertificate>) -> Self {
+        self.client_cert = client_cert;
+        self
+    }
+
+    #[must_use]
+    pub fn ca_bundles(mut self, ca_bundles: Vec<HostCaBundle>) -> Self {
+        self.ca_bundles = ca_bundles;
+        self
+    }
+
+    #[must_use]
+    pub fn allow_insecure_hosts(mut self, insecure_hosts: Vec<TrustedHost>) -> Self {
+        self.insecure_hosts = insecure_hosts;
+        self
+    }
+
+    #[must_use]
+    pub fn proxy(mut self, proxy: Option<ProxyConfig>) -> Self {
+        self.proxy = proxy;
+        self
+    }
+
+    #[must_use]
+    pub fn linehaul(mut self, linehaul: bool) -> Self {
+        self.linehaul = linehaul;
+        self
+    }
+
+    #[must_use]
+    pub fn user_agent_suffixes(mut self, user_agent_suffixes: Vec<UserAgentSuffix>) -> Self {
+        self.user_agent_suffixes = user_agent_suffixes;
+        self
+    }
+
+    #[must_use]
+    pub fn stale_if_error(mut self, stale_if_error: Option<Duration>) -> Self {
+        self.stale_if_error = stale_if_error;
+        self
+    }
+
     #[must_use]
     pub fn cache<T>(mut self, cache: Cache) -> Self {
         self.cache = cache;
@@ -145,6 +205,13 @@ impl<'a> RegistryClientBuilder<'a> {
             .retries(self.retries)
             .connectivity(self.connectivity)
             .native_tls(self.native_tls)
+            .client_cert(self.client_cert)
+            .ca_bundles(self.ca_bundles)
+            .allow_insecure_hosts(self.insecure_hosts)
+            .proxy(self.proxy)
+            .linehaul(self.linehaul)
+            .user_agent_suffixes(self.user_agent_suffixes)
+            .stale_if_error(self.stale_if_error)
             .keyring_provider(self.keyring_provider)
             .build();
 
@@ -219,6 +286,37 @@ impl RegistryClient {
         &self,
         package_name: &PackageName,
     ) -> Result<Vec<(IndexUrl, OwnedArchive<SimpleMetadata>)>, Error> {
+        // If the package is assigned to an index, consult that index exclusively.
+        if let Some(index) = self.index_urls.assigned_index(package_name) {
+            return match self.simple_single_index(package_name, &index.url).await? {
+                Ok(metadata) => Ok(vec![(index.url.clone(), metadata)]),
+                Err(CachedClientError::Client(err)) => match err.into_kind() {
+                    ErrorKind::Offline(_) => {
+                        Err(ErrorKind::Offline(package_name.to_string()).into())
+                    }
+                    ErrorKind::LocalPackageNotFound(..) => Err(ErrorKind::PackageNotFoundInIndex(
+                        package_name.to_string(),
+                        index.name.clone(),
+                    )
+                    .into()),
+                    ErrorKind::ReqwestError(err) => {
+                        if err.status() == Some(StatusCode::NOT_FOUND)
+                            || err.status() == Some(StatusCode::FORBIDDEN)
+                        {
+                            return Err(ErrorKind::PackageNotFoundInIndex(
+                                package_name.to_string(),
+                                index.name.clone(),
+                            )
+                            .into());
+                        }
+                        Err(ErrorKind::from(err).into())
+                    }
+                    other => Err(other.into()),
+                },
+                Err(CachedClientError::Callback(err)) => Err(err),
+            };
+        }
+
         let mut it = self.index_urls.indexes().peekable();
         if it.peek().is_none() {
             return Err(ErrorKind::NoIndex(package_name.as_ref().to_string()).into());
@@ -236,7 +334,7 @@ impl RegistryClient {
                     }
                 }
                 Err(CachedClientError::Client(err)) => match err.into_kind() {
-                    ErrorKind::Offline(_) => continue,
+                    ErrorKind::Offline(_) | ErrorKind::LocalPackageNotFound(..) => continue,
                     ErrorKind::ReqwestError(err) => {
                         if err.status() == Some(StatusCode::NOT_FOUND)
                             || err.status() == Some(StatusCode::FORBIDDEN)
@@ -280,6 +378,14 @@ impl RegistryClient {
 
         trace!("Fetching metadata for {package_name} from {url}");
 
+        // Local indexes are read from disk directly, bypassing the cache.
+        if url.scheme() == "file" {
+            return Ok(self
+                .simple_local_index(package_name, &url)
+                .await
+                .map_err(CachedClientError::Client));
+        }
+
         let cache_entry = self.cache.entry(
             CacheBucket::Simple,
             Path::new(&match index {
@@ -328,17 +434,11 @@ impl RegistryClient {
                 let unarchived = match media_type {
                     MediaType::Json => {
                         let bytes = response.bytes().await.map_err(ErrorKind::from)?;
-                        let data: SimpleJson = serde_json::from_slice(bytes.as_ref())
-                            .map_err(|err| Error::from_json_err(err, url.clone()))?;
-
-                        SimpleMetadata::from_files(data.files, package_name, &url)
+                        SimpleMetadata::from_json(bytes.as_ref(), package_name, &url)?
                     }
                     MediaType::Html => {
                         let text = response.text().await.map_err(ErrorKind::from)?;
-                        let SimpleHtml { base, files } = SimpleHtml::parse(&text, &url)
-                            .map_err(|err| Error::from_html_err(err, url.clone()))?;
-
-                        SimpleMetadata::from_files(files, package_name, base.as_url())
+                        SimpleMetadata::from_html(&text, package_name, &url)?
                     }
                 };
                 OwnedArchive::from_unarchived(&unarchived)
@@ -358,6 +458,38 @@ impl RegistryClient {
         Ok(result)
     }
 
+    /// Read the metadata for a package from a local (`file://`) index, laid out as a
+    /// [PEP 503](https://peps.python.org/pep-0503/) or [PEP 691](https://peps.python.org/pep-0691/)
+    /// directory tree (i.e., with a `<project>/index.html` or `<project>/index.json` file).
+    async fn simple_local_index(
+        &self,
+        package_name: &PackageName,
+        url: &Url,
+    ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
+        let path = url
+            .to_file_path()
+            .map_err(|()| ErrorKind::InvalidFileUrl(url.clone()))?;
+
+        // As with the `Accept` header for remote indexes, prefer the JSON API.
+        let json = path.join("index.json");
+        if json.is_file() {
+            let bytes = fs_err::tokio::read(&json).await.map_err(ErrorKind::Io)?;
+            let unarchived = SimpleMetadata::from_json(&bytes, package_name, url)?;
+            return OwnedArchive::from_unarchived(&unarchived);
+        }
+
+        let html = path.join("index.html");
+        if html.is_file() {
+            let text = fs_err::tokio::read_to_string(&html)
+                .await
+                .map_err(ErrorKind::Io)?;
+            let unarchived = SimpleMetadata::from_html(&text, package_name, url)?;
+            return OwnedArchive::from_unarchived(&unarchived);
+        }
+
+        Err(ErrorKind::LocalPackageNotFound(package_name.to_string(), path).into())
+    }
+
     /// Fetch the metadata for a remote wheel file.
     ///
     /// For a remote wheel, we try the following ways to fetch the metadata:
@@ -380,12 +512,35 @@ impl RegistryClient {
                         .await?
                 }
                 FileLocation::Path(path) => {
-                    let file = fs_err::tokio::File::open(&path)
-                        .await
-                        .map_err(ErrorKind::Io)?;
-                    let reader = tokio::io::BufReader::new(file);
-                    read_metadata_async_seek(&wheel.filename, built_dist.to_string(), reader)
-                        .await?
+                    // If a local index provides the metadata file alongside the wheel (PEP 658),
+                    // read it from there.
+                    if wheel
+                        .file
+                        .dist_info_metadata
+                        .as_ref()
+                        .is_some_and(pypi_types::DistInfoMetadata::is_available)
+                    {
+                        let mut metadata = path.clone().into_os_string();
+                        metadata.push(".metadata");
+                        let metadata = PathBuf::from(metadata);
+                        let bytes = fs_err::tokio::read(&metadata)
+                            .await
+                            .map_err(ErrorKind::Io)?;
+                        Metadata23::parse_metadata(&bytes).map_err(|err| {
+                            ErrorKind::MetadataParseError(
+                                wheel.filename.clone(),
+                                metadata.display().to_string(),
+                                Box::new(err),
+                            )
+                        })?
+                    } else {
+                        let file = fs_err::tokio::File::open(&path)
+                            .await
+                            .map_err(ErrorKind::Io)?;
+                        let reader = tokio::io::BufReader::new(file);
+                        read_metadata_async_seek(&wheel.filename, built_dist.to_string(), reader)
+                            .await?
+                    }
                 }
             },
             BuiltDist::DirectUrl(wheel) => {
@@ -764,6 +919,24 @@ impl SimpleMetadata {
         self.0.iter()
     }
 
+    /// Parse a [PEP 691](https://peps.python.org/pep-0691/) JSON response, using the given URL as
+    /// the base for relative file URLs.
+    fn from_json(bytes: &[u8], package_name: &PackageName, url: &Url) -> Result<Self, Error> {
+        let data: SimpleJson =
+            serde_json::from_slice(bytes).map_err(|err| Error::from_json_err(err, url.clone()))?;
+
+        Ok(Self::from_files(data.files, package_name, url))
+    }
+
+    /// Parse a [PEP 503](https://peps.python.org/pep-0503/) HTML response, using the given URL (or
+    /// the page's `<base>`, if any) as the base for relative file URLs.
+    fn from_html(text: &str, package_name: &PackageName, url: &Url) -> Result<Self, Error> {
+        let SimpleHtml { base, files } =
+            SimpleHtml::parse(text, url).map_err(|err| Error::from_html_err(err, url.clone()))?;
+
+        Ok(Self::from_files(files, package_name, base.as_url()))
+    }
+
     fn from_files(files: Vec<pypi_types::File>, package_name: &PackageName, base: &Url) -> Self {
         let mut map: BTreeMap<Version, VersionFiles> = BTreeMap::default();
 
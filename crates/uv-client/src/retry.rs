@@ -0,0 +1,185 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use reqwest_retry::{default_on_request_failure, default_on_request_success, Retryable};
+use task_local_extensions::Extensions;
+use tracing::debug;
+
+use pep508_rs::redact_url;
+
+/// The policy for retrying requests that fail with a transient error (e.g., a connection reset, a
+/// `503 Service Unavailable`, or a `429 Too Many Requests`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RetryPolicy {
+    /// The maximum number of retries for a single request.
+    max_retries: u32,
+    /// The backoff before the first retry, which doubles with each subsequent retry.
+    min_backoff: Duration,
+    /// The maximum backoff between two attempts, absent a `Retry-After` header.
+    max_backoff: Duration,
+    /// The maximum total time to wait across all retries of a single request.
+    max_total_wait: Duration,
+}
+
+impl RetryPolicy {
+    pub(crate) fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            min_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            max_total_wait: Duration::from_secs(120),
+        }
+    }
+
+    /// Return the delay before the given retry (zero-indexed).
+    ///
+    /// If the server provided a `Retry-After`, it's honored as-is, plus a small jitter, such that
+    /// many clients that were rate-limited at once don't all retry at the same instant. Otherwise,
+    /// the delay is an exponential backoff with "full jitter", i.e., uniformly distributed between
+    /// the minimum backoff and the (capped) exponential backoff.
+    fn delay(&self, retry: u32, retry_after: Option<Duration>) -> Duration {
+        let mut rng = rand::thread_rng();
+        if let Some(retry_after) = retry_after {
+            return retry_after + rng.gen_range(Duration::ZERO..=self.min_backoff);
+        }
+        let backoff = self
+            .min_backoff
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_backoff);
+        rng.gen_range(self.min_backoff..=backoff)
+    }
+}
+
+/// A middleware that retries transient failures, honoring any `Retry-After` header and capping
+/// the total time spent waiting.
+pub(crate) struct RetryMiddleware {
+    policy: RetryPolicy,
+}
+
+impl RetryMiddleware {
+    pub(crate) fn new(policy: RetryPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RetryMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let mut retry = 0;
+        let mut waited = Duration::ZERO;
+        loop {
+            // Requests with a streaming body can't be cloned, and so can't be retried.
+            let Some(attempt) = req.try_clone() else {
+                return next.run(req, extensions).await;
+            };
+            let result = next.clone().run(attempt, extensions).await;
+
+            let retryable = match &result {
+                Ok(response) => default_on_request_success(response),
+                Err(err) => default_on_request_failure(err),
+            };
+            if !matches!(retryable, Some(Retryable::Transient)) || retry >= self.policy.max_retries
+            {
+                return result;
+            }
+
+            let retry_after = result.as_ref().ok().and_then(retry_after);
+            let delay = self.policy.delay(retry, retry_after);
+            let reason = match &result {
+                Ok(response) => format!("`{}`", response.status()),
+                Err(err) => err.to_string(),
+            };
+            if waited + delay > self.policy.max_total_wait {
+                debug!(
+                    "Not retrying request to {} after {reason}: waiting {:.1}s would exceed the maximum total wait of {}s",
+                    redact_url(req.url()),
+                    delay.as_secs_f32(),
+                    self.policy.max_total_wait.as_secs()
+                );
+                return result;
+            }
+
+            retry += 1;
+            debug!(
+                "Retrying request to {} after {reason} in {:.1}s (retry {retry}/{})",
+                redact_url(req.url()),
+                delay.as_secs_f32(),
+                self.policy.max_retries
+            );
+            tokio::time::sleep(delay).await;
+            waited += delay;
+        }
+    }
+}
+
+/// Parse the `Retry-After` header of a response, which is either a number of seconds, or an HTTP
+/// date (e.g., `Wed, 21 Oct 2015 07:28:00 GMT`).
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    parse_retry_after(value, Utc::now())
+}
+
+fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    // A date in the past means the request can be retried immediately.
+    Some(
+        (date.with_timezone(&Utc) - now)
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::{TimeZone, Utc};
+
+    use super::{parse_retry_after, RetryPolicy};
+
+    #[test]
+    fn retry_after() {
+        let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap();
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:27:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[test]
+    fn delay() {
+        let policy = RetryPolicy::new(3);
+        for retry in 0..10 {
+            let delay = policy.delay(retry, None);
+            assert!(delay >= policy.min_backoff);
+            assert!(delay <= policy.max_backoff);
+        }
+
+        // `Retry-After` is honored, with at most the minimum backoff as jitter.
+        let delay = policy.delay(0, Some(Duration::from_secs(10)));
+        assert!(delay >= Duration::from_secs(10));
+        assert!(delay <= Duration::from_secs(10) + policy.min_backoff);
+    }
+}
@@ -0,0 +1,130 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use url::Url;
+
+/// A host to which insecure connections are allowed, i.e., over plain HTTP, or over HTTPS without
+/// verifying the server's certificate.
+///
+/// Equivalent to pip's `--trusted-host`, the host may include a port, in which case only
+/// connections to that port are allowed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TrustedHost {
+    host: String,
+    port: Option<u16>,
+}
+
+impl TrustedHost {
+    /// Returns `true` if the host (and port, if specified) matches that of the given URL.
+    pub fn matches(&self, url: &Url) -> bool {
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+        if !self.host.eq_ignore_ascii_case(host) {
+            return false;
+        }
+        match self.port {
+            Some(port) => url.port_or_known_default() == Some(port),
+            None => true,
+        }
+    }
+
+    /// Returns the host name, without the port.
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Returns the port, if the trusted host is limited to a single port.
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum TrustedHostError {
+    #[error("Expected a host, optionally followed by a port (e.g., `example.com:8080`), but found: `{0}`")]
+    InvalidHost(String),
+}
+
+impl FromStr for TrustedHost {
+    type Err = TrustedHostError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Accept a bare host (e.g., `example.com`), or a URL-like value (e.g.,
+        // `https://example.com:8080/simple`), from which we extract the host and port.
+        let value = s.trim();
+        let value = value
+            .split_once("://")
+            .map_or(value, |(_, rest)| rest)
+            .split('/')
+            .next()
+            .unwrap_or_default();
+
+        let (host, port) = match value.rsplit_once(':') {
+            // Avoid splitting IPv6 addresses (e.g., `[::1]`) on their internal colons.
+            Some((host, port)) if !port.contains(']') => {
+                let port = port
+                    .parse::<u16>()
+                    .map_err(|_| TrustedHostError::InvalidHost(s.to_string()))?;
+                (host, Some(port))
+            }
+            _ => (value, None),
+        };
+
+        if host.is_empty() || host.contains(char::is_whitespace) {
+            return Err(TrustedHostError::InvalidHost(s.to_string()));
+        }
+
+        Ok(Self {
+            host: host.to_ascii_lowercase(),
+            port,
+        })
+    }
+}
+
+impl Display for TrustedHost {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.port {
+            Some(port) => write!(f, "{}:{port}", self.host),
+            None => write!(f, "{}", self.host),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use url::Url;
+
+    use super::TrustedHost;
+
+    #[test]
+    fn parse() {
+        let host = TrustedHost::from_str("Mirror.Internal").unwrap();
+        assert_eq!(host.host(), "mirror.internal");
+        assert_eq!(host.port(), None);
+
+        let host = TrustedHost::from_str("mirror.internal:8080").unwrap();
+        assert_eq!(host.host(), "mirror.internal");
+        assert_eq!(host.port(), Some(8080));
+
+        let host = TrustedHost::from_str("http://mirror.internal:8080/simple").unwrap();
+        assert_eq!(host.to_string(), "mirror.internal:8080");
+
+        assert!(TrustedHost::from_str("mirror.internal:http").is_err());
+        assert!(TrustedHost::from_str("").is_err());
+    }
+
+    #[test]
+    fn matches() {
+        let host = TrustedHost::from_str("mirror.internal").unwrap();
+        assert!(host.matches(&Url::parse("http://mirror.internal/simple").unwrap()));
+        assert!(host.matches(&Url::parse("https://mirror.internal:8443/simple").unwrap()));
+        assert!(!host.matches(&Url::parse("https://pypi.org/simple").unwrap()));
+
+        let host = TrustedHost::from_str("mirror.internal:8080").unwrap();
+        assert!(host.matches(&Url::parse("http://mirror.internal:8080/simple").unwrap()));
+        assert!(!host.matches(&Url::parse("http://mirror.internal/simple").unwrap()));
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use http::HeaderValue;
+use reqwest::header::USER_AGENT;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use task_local_extensions::Extensions;
+use tracing::trace;
+use url::Url;
+
+use pep508_rs::redact_url;
+
+use crate::{TrustedHost, TrustedHostError};
+
+/// A suffix to append to the `User-Agent` of requests to a given host (e.g., to attribute traffic
+/// to an internal index to a team), provided as `HOST=SUFFIX`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UserAgentSuffix {
+    host: TrustedHost,
+    suffix: String,
+}
+
+impl UserAgentSuffix {
+    /// The host (and port, if specified) to which the suffix applies.
+    pub fn host(&self) -> &TrustedHost {
+        &self.host
+    }
+
+    /// The suffix itself, e.g., `team-ml/1.0`.
+    pub fn suffix(&self) -> &str {
+        &self.suffix
+    }
+}
+
+impl Display for UserAgentSuffix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.host, self.suffix)
+    }
+}
+
+impl FromStr for UserAgentSuffix {
+    type Err = UserAgentSuffixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((host, suffix)) = s.split_once('=') else {
+            return Err(UserAgentSuffixError::MissingHost(s.to_string()));
+        };
+        let host = TrustedHost::from_str(host)?;
+        let suffix = suffix.trim();
+        if suffix.is_empty() || !suffix.chars().all(|c| c.is_ascii_graphic()) {
+            return Err(UserAgentSuffixError::InvalidSuffix(suffix.to_string()));
+        }
+        Ok(Self {
+            host,
+            suffix: suffix.to_string(),
+        })
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum UserAgentSuffixError {
+    #[error("Expected `HOST=SUFFIX`, but found: `{0}`")]
+    MissingHost(String),
+    #[error("Expected a `User-Agent` suffix of printable ASCII characters without whitespace (e.g., `team-ml/1.0`), but found: `{0}`")]
+    InvalidSuffix(String),
+    #[error(transparent)]
+    InvalidHost(#[from] TrustedHostError),
+}
+
+/// A middleware that appends any configured suffixes to the `User-Agent` of requests to their
+/// hosts.
+pub(crate) struct UserAgentMiddleware {
+    user_agent: String,
+    suffixes: Vec<UserAgentSuffix>,
+}
+
+impl UserAgentMiddleware {
+    pub(crate) fn new(user_agent: String, suffixes: Vec<UserAgentSuffix>) -> Self {
+        Self {
+            user_agent,
+            suffixes,
+        }
+    }
+
+    /// Returns the `User-Agent` for a request to the given URL, if it differs from the default.
+    fn user_agent(&self, url: &Url) -> Option<String> {
+        let mut user_agent = self.user_agent.clone();
+        let mut matched = false;
+        for suffix in self
+            .suffixes
+            .iter()
+            .filter(|suffix| suffix.host.matches(url))
+        {
+            user_agent.push(' ');
+            user_agent.push_str(&suffix.suffix);
+            matched = true;
+        }
+        matched.then_some(user_agent)
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for UserAgentMiddleware {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        if !req.headers().contains_key(USER_AGENT) {
+            if let Some(user_agent) = self.user_agent(req.url()) {
+                if let Ok(value) = HeaderValue::from_str(&user_agent) {
+                    trace!(
+                        "Using `User-Agent` for {}: {user_agent}",
+                        redact_url(req.url())
+                    );
+                    req.headers_mut().insert(USER_AGENT, value);
+                }
+            }
+        }
+        next.run(req, extensions).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use url::Url;
+
+    use super::{UserAgentMiddleware, UserAgentSuffix};
+
+    #[test]
+    fn parse() {
+        let suffix = UserAgentSuffix::from_str("pypi.internal:8443=team-ml/1.0").unwrap();
+        assert_eq!(suffix.host().to_string(), "pypi.internal:8443");
+        assert_eq!(suffix.suffix(), "team-ml/1.0");
+
+        assert!(UserAgentSuffix::from_str("team-ml").is_err());
+        assert!(UserAgentSuffix::from_str("pypi.internal=").is_err());
+        assert!(UserAgentSuffix::from_str("pypi.internal=team ml").is_err());
+    }
+
+    #[test]
+    fn user_agent() {
+        let middleware = UserAgentMiddleware::new(
+            "uv/0.1.0".to_string(),
+            vec![
+                UserAgentSuffix::from_str("pypi.internal=team-ml").unwrap(),
+                UserAgentSuffix::from_str("pypi.internal=ci").unwrap(),
+            ],
+        );
+        assert_eq!(
+            middleware.user_agent(&Url::parse("https://pypi.internal/simple").unwrap()),
+            Some("uv/0.1.0 team-ml ci".to_string())
+        );
+        assert_eq!(
+            middleware.user_agent(&Url::parse("https://pypi.org/simple").unwrap()),
+            None
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use anyhow::Result;
+use futures::future;
+use hyper::header::LOCATION;
+use hyper::server::conn::Http;
+use hyper::service::service_fn;
+use hyper::{Body, Request, Response, StatusCode};
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::redirect::Policy;
+use tokio::net::TcpListener;
+
+use uv_cache::Cache;
+use uv_client::{RegistryClientBuilder, TrustedHost};
+
+/// Start a server that redirects every request to the given location or, if there is none,
+/// responds with the `X-Client` header it received.
+async fn start_server(location: Option<String>) -> Result<SocketAddr> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let location = location.clone();
+            let svc = service_fn(move |req: Request<Body>| {
+                let response = if let Some(location) = &location {
+                    Response::builder()
+                        .status(StatusCode::FOUND)
+                        .header(LOCATION, location.as_str())
+                        .body(Body::empty())
+                        .unwrap()
+                } else {
+                    let client = req
+                        .headers()
+                        .get("x-client")
+                        .and_then(|v| v.to_str().ok())
+                        .unwrap_or("none")
+                        .to_string();
+                    Response::new(Body::from(client))
+                };
+                future::ok::<_, hyper::Error>(response)
+            });
+            tokio::spawn(async move {
+                Http::new()
+                    .http1_keep_alive(false)
+                    .serve_connection(socket, svc)
+                    .await
+                    .expect("Server served the connection");
+            });
+        }
+    });
+
+    Ok(addr)
+}
+
+/// Build a default client that marks its requests with an `X-Client` header, to distinguish them
+/// from requests sent through the clients for hosts with custom TLS settings.
+fn default_client() -> Result<reqwest::Client> {
+    let mut headers = HeaderMap::new();
+    headers.insert("x-client", HeaderValue::from_static("default"));
+    Ok(reqwest::Client::builder()
+        .default_headers(headers)
+        .redirect(Policy::none())
+        .build()?)
+}
+
+/// A redirect from an insecure host to another host is followed with the default TLS settings.
+#[tokio::test]
+async fn test_redirect_from_insecure_host() -> Result<()> {
+    let target = start_server(None).await?;
+    let insecure = start_server(Some(format!("http://{target}/simple/"))).await?;
+
+    let client = RegistryClientBuilder::new(Cache::temp()?)
+        .client(default_client()?)
+        .allow_insecure_hosts(vec![TrustedHost::from_str(&insecure.to_string())?])
+        .build();
+
+    let res = client
+        .uncached_client()
+        .get(format!("http://{insecure}/simple/"))
+        .send()
+        .await?;
+    assert_eq!(res.url().as_str(), format!("http://{target}/simple/"));
+    assert_eq!(res.text().await?, "default");
+
+    Ok(())
+}
+
+/// A redirect to an insecure host is followed with the TLS settings for that host.
+#[tokio::test]
+async fn test_redirect_to_insecure_host() -> Result<()> {
+    let insecure = start_server(None).await?;
+    let source = start_server(Some(format!("http://{insecure}/simple/"))).await?;
+
+    let client = RegistryClientBuilder::new(Cache::temp()?)
+        .client(default_client()?)
+        .allow_insecure_hosts(vec![TrustedHost::from_str(&insecure.to_string())?])
+        .build();
+
+    let res = client
+        .uncached_client()
+        .get(format!("http://{source}/simple/"))
+        .send()
+        .await?;
+    assert_eq!(res.url().as_str(), format!("http://{insecure}/simple/"));
+    assert_eq!(res.text().await?, "none");
+
+    Ok(())
+}
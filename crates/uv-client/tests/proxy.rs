@@ -0,0 +1,114 @@
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use anyhow::Result;
+use futures::future;
+use hyper::header::PROXY_AUTHORIZATION;
+use hyper::server::conn::Http;
+use hyper::service::service_fn;
+use hyper::{Body, Request, Response};
+use tokio::net::TcpListener;
+use url::Url;
+
+use uv_cache::Cache;
+use uv_client::{ProxyBypass, ProxyConfig, RegistryClientBuilder};
+
+/// Start an HTTP forward proxy that, rather than forwarding each request, responds with its name,
+/// the requested URL, and the `Proxy-Authorization` header it received.
+async fn start_proxy(name: &'static str) -> Result<SocketAddr> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let svc = service_fn(move |req: Request<Body>| {
+                let authorization = req
+                    .headers()
+                    .get(PROXY_AUTHORIZATION)
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or("none")
+                    .to_string();
+                let body = format!("{name} {} {authorization}", req.uri());
+                future::ok::<_, hyper::Error>(Response::new(Body::from(body)))
+            });
+            tokio::spawn(async move {
+                Http::new()
+                    .http1_keep_alive(false)
+                    .serve_connection(socket, svc)
+                    .await
+                    .expect("Proxy served the connection");
+            });
+        }
+    });
+
+    Ok(addr)
+}
+
+#[tokio::test]
+async fn test_proxy_with_credentials() -> Result<()> {
+    let addr = start_proxy("proxy").await?;
+
+    let proxy = ProxyConfig::from_str(&format!("http://user:pass@{addr}"))?;
+    let client = RegistryClientBuilder::new(Cache::temp()?)
+        .proxy(Some(proxy))
+        .build();
+
+    let res = client
+        .uncached_client()
+        .get("http://index.invalid/simple/")
+        .send()
+        .await?;
+    assert!(res.status().is_success());
+
+    // The request is sent to the proxy, with the credentials from the proxy URL.
+    let body = res.text().await?;
+    assert_eq!(
+        body,
+        "proxy http://index.invalid/simple/ Basic dXNlcjpwYXNz"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_index_proxy() -> Result<()> {
+    let default_addr = start_proxy("default").await?;
+    let index_addr = start_proxy("index").await?;
+
+    let proxy = ProxyConfig::from_str(&format!("http://{default_addr}"))?
+        .with_bypass(vec![ProxyBypass::from_str("internal.invalid")?])
+        .with_index_proxy(
+            &Url::parse("http://internal.invalid/simple/")?,
+            Url::parse(&format!("http://{index_addr}"))?,
+        );
+    let client = RegistryClientBuilder::new(Cache::temp()?)
+        .proxy(Some(proxy))
+        .build();
+
+    // Requests to the index's host are sent through the index's proxy, even though the host
+    // bypasses the default proxy.
+    let body = client
+        .uncached_client()
+        .get("http://internal.invalid/files/foo-1.0.0.tar.gz")
+        .send()
+        .await?
+        .text()
+        .await?;
+    assert_eq!(
+        body,
+        "index http://internal.invalid/files/foo-1.0.0.tar.gz none"
+    );
+
+    // Requests to any other host are sent through the default proxy.
+    let body = client
+        .uncached_client()
+        .get("http://public.invalid/simple/")
+        .send()
+        .await?
+        .text()
+        .await?;
+    assert_eq!(body, "default http://public.invalid/simple/ none");
+
+    Ok(())
+}
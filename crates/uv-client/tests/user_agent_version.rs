@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use anyhow::Result;
 use futures::future;
 use hyper::header::USER_AGENT;
@@ -9,7 +11,7 @@ use platform_tags::{Arch, Os, Platform};
 use tokio::net::TcpListener;
 use uv_cache::Cache;
 use uv_client::LineHaul;
-use uv_client::RegistryClientBuilder;
+use uv_client::{RegistryClientBuilder, UserAgentSuffix};
 use uv_version::version;
 
 #[tokio::test]
@@ -63,6 +65,85 @@ async fn test_user_agent_has_version() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_user_agent_without_linehaul() -> Result<()> {
+    // Set up the TCP listener on a random available port
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    // Spawn the server loop in a background task
+    tokio::spawn(async move {
+        let svc = service_fn(move |req: Request<Body>| {
+            // Get User Agent Header and send it back in the response
+            let user_agent = req
+                .headers()
+                .get(USER_AGENT)
+                .and_then(|v| v.to_str().ok())
+                .map(|s| s.to_string())
+                .unwrap_or_default(); // Empty Default
+            future::ok::<_, hyper::Error>(Response::new(Body::from(user_agent)))
+        });
+        // Start Hyper Server
+        let (socket, _) = listener.accept().await.unwrap();
+        Http::new()
+            .http1_keep_alive(false)
+            .serve_connection(socket, svc)
+            .with_upgrades()
+            .await
+            .expect("Server Started");
+    });
+
+    let markers = MarkerEnvironment {
+        implementation_name: "cpython".to_string(),
+        implementation_version: StringVersion {
+            string: "3.12.2".to_string(),
+            version: "3.12.2".parse()?,
+        },
+        os_name: "posix".to_string(),
+        platform_machine: "x86_64".to_string(),
+        platform_python_implementation: "CPython".to_string(),
+        platform_release: "6.5.0-1016-azure".to_string(),
+        platform_system: "Linux".to_string(),
+        platform_version: "#16~22.04.1-Ubuntu SMP Fri Feb 16 15:42:02 UTC 2024".to_string(),
+        python_full_version: StringVersion {
+            string: "3.12.2".to_string(),
+            version: "3.12.2".parse()?,
+        },
+        python_version: StringVersion {
+            string: "3.12".to_string(),
+            version: "3.12".parse()?,
+        },
+        sys_platform: "linux".to_string(),
+    };
+
+    // Initialize uv-client, without linehaul, but with a suffix for our dummy server
+    let cache = Cache::temp()?;
+    let client = RegistryClientBuilder::new(cache)
+        .markers(&markers)
+        .linehaul(false)
+        .user_agent_suffixes(vec![
+            UserAgentSuffix::from_str(&format!("{addr}=team-ml/1.0"))?,
+            UserAgentSuffix::from_str("pypi.org=other-team")?,
+        ])
+        .build();
+
+    // Send request to our dummy server
+    let res = client
+        .uncached_client()
+        .get(format!("http://{addr}"))
+        .send()
+        .await?;
+
+    // Check the HTTP status
+    assert!(res.status().is_success());
+
+    // Check User Agent
+    let body = res.text().await?;
+    assert_eq!(body, format!("uv/{} team-ml/1.0", version()));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_user_agent_has_linehaul() -> Result<()> {
     // Set up the TCP listener on a random available port
@@ -1,9 +1,11 @@
 use std::env;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use clap::Parser;
 use fs_err as fs;
+use tokio::sync::Semaphore;
 
 use distribution_types::IndexLocations;
 use rustc_hash::FxHashMap;
@@ -15,7 +17,8 @@ use uv_interpreter::PythonEnvironment;
 use uv_resolver::InMemoryIndex;
 use uv_types::NoBinary;
 use uv_types::{
-    BuildContext, BuildIsolation, BuildKind, ConfigSettings, InFlight, NoBuild, SetupPyStrategy,
+    BuildContext, BuildIsolation, BuildKind, Concurrency, ConfigSettings, InFlight, NoBuild,
+    SetupPyStrategy,
 };
 
 #[derive(Parser)]
@@ -91,6 +94,7 @@ pub(crate) async fn build(args: BuildArgs) -> Result<PathBuf> {
         BuildIsolation::Isolated,
         build_kind,
         FxHashMap::default(),
+        Arc::new(Semaphore::new(Concurrency::default().builds)),
     )
     .await?;
     Ok(wheel_dir.join(builder.build(&wheel_dir).await?))
@@ -2,14 +2,17 @@
 //! [installer][`uv_installer`] and [build][`uv_build`] through [`BuildDispatch`]
 //! implementing [`BuildContext`].
 
+use std::collections::BTreeMap;
 use std::ffi::OsStr;
 use std::path::Path;
+use std::sync::{Arc, Mutex};
 use std::{ffi::OsString, future::Future};
 
 use anyhow::{bail, Context, Result};
 use futures::FutureExt;
 use itertools::Itertools;
 use rustc_hash::FxHashMap;
+use tokio::sync::Semaphore;
 use tracing::{debug, instrument};
 
 use distribution_types::{IndexLocations, Name, Resolution, SourceDist};
@@ -19,10 +22,11 @@ use uv_cache::Cache;
 use uv_client::{FlatIndex, RegistryClient};
 use uv_installer::{Downloader, Installer, Plan, Planner, SitePackages};
 use uv_interpreter::{Interpreter, PythonEnvironment};
-use uv_resolver::{InMemoryIndex, Manifest, Options, Resolver};
+use uv_normalize::PackageName;
+use uv_resolver::{Exclusions, InMemoryIndex, Manifest, Options, Resolver};
 use uv_types::{
-    BuildContext, BuildIsolation, BuildKind, ConfigSettings, EmptyInstalledPackages, InFlight,
-    NoBinary, NoBuild, Reinstall, SetupPyStrategy,
+    BuildContext, BuildIsolation, BuildKind, Concurrency, ConfigSettings, Constraints,
+    EmptyInstalledPackages, InFlight, NoBinary, NoBuild, Overrides, Reinstall, SetupPyStrategy,
 };
 
 /// The main implementation of [`BuildContext`], used by the CLI, see [`BuildContext`]
@@ -43,6 +47,14 @@ pub struct BuildDispatch<'a> {
     source_build_context: SourceBuildContext,
     options: Options,
     build_extra_env_vars: FxHashMap<OsString, OsString>,
+    build_constraints: Constraints,
+    build_requirements: Mutex<BTreeMap<String, Vec<Requirement>>>,
+    /// The number of source distributions of each package that were built, since last taken.
+    source_builds: Mutex<BTreeMap<PackageName, usize>>,
+    concurrency: Concurrency,
+    /// Limits the number of concurrent source distribution builds, shared by all builds
+    /// (including those of build requirements).
+    concurrent_builds: Arc<Semaphore>,
 }
 
 impl<'a> BuildDispatch<'a> {
@@ -77,6 +89,11 @@ impl<'a> BuildDispatch<'a> {
             source_build_context: SourceBuildContext::default(),
             options: Options::default(),
             build_extra_env_vars: FxHashMap::default(),
+            build_constraints: Constraints::default(),
+            build_requirements: Mutex::default(),
+            source_builds: Mutex::default(),
+            concurrency: Concurrency::default(),
+            concurrent_builds: Arc::new(Semaphore::new(Concurrency::default().builds)),
         }
     }
 
@@ -100,6 +117,43 @@ impl<'a> BuildDispatch<'a> {
             .collect();
         self
     }
+
+    /// Set the limits on concurrent downloads and builds.
+    #[must_use]
+    pub fn with_concurrency(mut self, concurrency: Concurrency) -> Self {
+        self.concurrent_builds = Arc::new(Semaphore::new(concurrency.builds));
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Set the constraints to apply when resolving the requirements of isolated build
+    /// environments.
+    #[must_use]
+    pub fn with_build_constraints(mut self, build_constraints: Constraints) -> Self {
+        self.build_constraints = build_constraints;
+        self
+    }
+
+    /// Return the resolved build requirements for each source distribution that was built in an
+    /// isolated environment (or whose isolated build was read from the cache), keyed by the
+    /// distribution's package ID.
+    pub fn build_requirements(&self) -> BTreeMap<String, Vec<Requirement>> {
+        self.build_requirements
+            .lock()
+            .expect("build requirements lock is poisoned")
+            .clone()
+    }
+
+    /// Return the number of source distributions of each package that were built (e.g., to read
+    /// their metadata) since the last call, resetting the counts.
+    pub fn take_source_builds(&self) -> BTreeMap<PackageName, usize> {
+        std::mem::take(
+            &mut *self
+                .source_builds
+                .lock()
+                .expect("source builds lock is poisoned"),
+        )
+    }
 }
 
 impl<'a> BuildContext for BuildDispatch<'a> {
@@ -133,12 +187,26 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         self.setup_py
     }
 
+    fn concurrency(&self) -> Concurrency {
+        self.concurrency
+    }
+
     async fn resolve<'data>(&'data self, requirements: &'data [Requirement]) -> Result<Resolution> {
         let markers = self.interpreter.markers();
         let tags = self.interpreter.tags()?;
         let resolver = Resolver::new(
-            Manifest::simple(requirements.to_vec()),
-            self.options,
+            Manifest::new(
+                requirements.to_vec(),
+                self.build_constraints.clone(),
+                Overrides::default(),
+                Vec::new(),
+                Vec::new(),
+                None,
+                Vec::new(),
+                Exclusions::default(),
+                Vec::new(),
+            ),
+            self.options.clone(),
             markers,
             self.interpreter,
             tags,
@@ -224,7 +292,8 @@ impl<'a> BuildContext for BuildDispatch<'a> {
                 vec![]
             } else {
                 // TODO(konstin): Check that there is no endless recursion.
-                let downloader = Downloader::new(self.cache, tags, self.client, self);
+                let downloader = Downloader::new(self.cache, tags, self.client, self)
+                    .with_concurrent_downloads(self.concurrency.downloads);
                 debug!(
                     "Downloading and building requirement{} for build: {}",
                     if remote.len() == 1 { "" } else { "s" },
@@ -313,9 +382,27 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             self.build_isolation,
             build_kind,
             self.build_extra_env_vars.clone(),
+            self.concurrent_builds.clone(),
         )
         .boxed()
         .await?;
+
+        if let Some(dist) = dist {
+            *self
+                .source_builds
+                .lock()
+                .expect("source builds lock is poisoned")
+                .entry(dist.name().clone())
+                .or_default() += 1;
+        }
+
         Ok(builder)
     }
+
+    fn record_build_requirements(&self, dist: &SourceDist, requirements: Vec<Requirement>) {
+        self.build_requirements
+            .lock()
+            .expect("build requirements lock is poisoned")
+            .insert(dist.to_string(), requirements);
+    }
 }
@@ -21,6 +21,7 @@ use distribution_types::{
     PathSourceDist, PathSourceUrl, RemoteSource, SourceDist, SourceUrl,
 };
 use install_wheel_rs::metadata::read_archive_metadata;
+use pep508_rs::Requirement;
 use platform_tags::Tags;
 use pypi_types::Metadata23;
 use uv_cache::{
@@ -55,6 +56,10 @@ pub(crate) const MANIFEST: &str = "manifest.msgpack";
 /// The name of the file that contains the cached distribution metadata, encoded via `MsgPack`.
 pub(crate) const METADATA: &str = "metadata.msgpack";
 
+/// The name of the file that contains the requirements installed into the isolated build
+/// environment, encoded via `MsgPack`.
+pub(crate) const BUILD_REQUIREMENTS: &str = "build-requirements.msgpack";
+
 impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     /// Initialize a [`SourceDistributionBuilder`] from a [`BuildContext`].
     pub fn new(client: &'a RegistryClient, build_context: &'a T) -> Self {
@@ -386,6 +391,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // If the cache contains a compatible wheel, return it.
         if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard) {
+            self.read_build_requirements(source, &cache_shard).await?;
             return Ok(built_wheel);
         }
 
@@ -492,6 +498,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let metadata_entry = cache_shard.entry(METADATA);
         if let Some(metadata) = read_cached_metadata(&metadata_entry).await? {
             debug!("Using cached metadata for: {source}");
+            self.read_build_requirements(source, &cache_shard).await?;
             return Ok(metadata);
         }
 
@@ -500,7 +507,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // If the backend supports `prepare_metadata_for_build_wheel`, use it.
         if let Some(metadata) = self
-            .build_metadata(source, source_dist_entry.path(), subdirectory)
+            .build_metadata(source, source_dist_entry.path(), subdirectory, &cache_shard)
             .boxed()
             .await?
         {
@@ -579,6 +586,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // If the cache contains a compatible wheel, return it.
         if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard) {
+            self.read_build_requirements(source, &cache_shard).await?;
             return Ok(built_wheel);
         }
 
@@ -659,13 +667,14 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         {
             if let Some(metadata) = read_cached_metadata(&metadata_entry).await? {
                 debug!("Using cached metadata for: {source}");
+                self.read_build_requirements(source, &cache_shard).await?;
                 return Ok(metadata);
             }
         }
 
         // If the backend supports `prepare_metadata_for_build_wheel`, use it.
         if let Some(metadata) = self
-            .build_metadata(source, source_root, None)
+            .build_metadata(source, source_root, None, &cache_shard)
             .boxed()
             .await?
         {
@@ -738,6 +747,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // If the cache contains a compatible wheel, return it.
         if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard) {
+            self.read_build_requirements(source, &cache_shard).await?;
             return Ok(built_wheel);
         }
 
@@ -811,13 +821,14 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         {
             if let Some(metadata) = read_cached_metadata(&metadata_entry).await? {
                 debug!("Using cached metadata for: {source}");
+                self.read_build_requirements(source, &cache_shard).await?;
                 return Ok(metadata);
             }
         }
 
         // If the backend supports `prepare_metadata_for_build_wheel`, use it.
         if let Some(metadata) = self
-            .build_metadata(source, fetch.path(), subdirectory.as_deref())
+            .build_metadata(source, fetch.path(), subdirectory.as_deref(), &cache_shard)
             .boxed()
             .await?
         {
@@ -931,7 +942,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         fs::create_dir_all(&cache_shard)
             .await
             .map_err(Error::CacheWrite)?;
-        let disk_filename = self
+        let builder = self
             .build_context
             .setup_build(
                 source_root,
@@ -941,10 +952,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 BuildKind::Wheel,
             )
             .await
-            .map_err(|err| Error::Build(source.to_string(), err))?
+            .map_err(|err| Error::Build(source.to_string(), err))?;
+        let disk_filename = builder
             .wheel(cache_shard)
             .await
             .map_err(|err| Error::Build(source.to_string(), err))?;
+        self.write_build_requirements(source, &builder, cache_shard)
+            .await?;
 
         // Read the metadata from the wheel.
         let filename = WheelFilename::from_str(&disk_filename)?;
@@ -971,6 +985,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         source: &BuildableSource<'_>,
         source_root: &Path,
         subdirectory: Option<&Path>,
+        cache_shard: &CacheShard,
     ) -> Result<Option<Metadata23>, Error> {
         debug!("Preparing metadata for: {source}");
 
@@ -1041,6 +1056,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let Some(dist_info) = dist_info else {
             return Ok(None);
         };
+        fs::create_dir_all(&cache_shard)
+            .await
+            .map_err(Error::CacheWrite)?;
+        self.write_build_requirements(source, &builder, cache_shard)
+            .await?;
 
         // Read the metadata from disk.
         debug!("Prepared metadata for: {source}");
@@ -1062,6 +1082,53 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         Ok(Some(metadata))
     }
 
+    /// Store the requirements installed into the isolated build environment of a source
+    /// distribution alongside its build, and report them to the build context.
+    async fn write_build_requirements(
+        &self,
+        source: &BuildableSource<'_>,
+        builder: &T::SourceDistBuilder,
+        cache_shard: &CacheShard,
+    ) -> Result<(), Error> {
+        let Some(dist) = source.as_dist() else {
+            return Ok(());
+        };
+        let Some(resolution) = builder.build_requirements() else {
+            return Ok(());
+        };
+        let requirements = resolution.requirements();
+        let cache_entry = cache_shard.entry(BUILD_REQUIREMENTS);
+        write_atomic(cache_entry.path(), rmp_serde::to_vec(&requirements)?)
+            .await
+            .map_err(Error::CacheWrite)?;
+        self.build_context
+            .record_build_requirements(dist, requirements);
+        Ok(())
+    }
+
+    /// Report the requirements installed into the isolated build environment of a source
+    /// distribution whose build was read from the cache, if any were stored.
+    async fn read_build_requirements(
+        &self,
+        source: &BuildableSource<'_>,
+        cache_shard: &CacheShard,
+    ) -> Result<(), Error> {
+        let Some(dist) = source.as_dist() else {
+            return Ok(());
+        };
+        let cache_entry = cache_shard.entry(BUILD_REQUIREMENTS);
+        match fs::read(cache_entry.path()).await {
+            Ok(cached) => {
+                let requirements = rmp_serde::from_slice::<Vec<Requirement>>(&cached)?;
+                self.build_context
+                    .record_build_requirements(dist, requirements);
+                Ok(())
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(Error::CacheRead(err)),
+        }
+    }
+
     /// Build a single directory into an editable wheel
     pub async fn build_editable(
         &self,
@@ -15,7 +15,7 @@ use platform_tags::Tags;
 use uv_cache::Cache;
 use uv_client::RegistryClient;
 use uv_distribution::{DistributionDatabase, LocalWheel, Unzip};
-use uv_types::{BuildContext, InFlight};
+use uv_types::{BuildContext, Concurrency, InFlight};
 
 use crate::editable::BuiltEditable;
 
@@ -42,6 +42,8 @@ pub struct Downloader<'a, Context: BuildContext + Send + Sync> {
     cache: &'a Cache,
     database: DistributionDatabase<'a, Context>,
     reporter: Option<Arc<dyn Reporter>>,
+    /// The maximum number of distributions to fetch concurrently.
+    concurrent_downloads: usize,
 }
 
 impl<'a, Context: BuildContext + Send + Sync> Downloader<'a, Context> {
@@ -56,6 +58,16 @@ impl<'a, Context: BuildContext + Send + Sync> Downloader<'a, Context> {
             cache,
             database: DistributionDatabase::new(client, build_context),
             reporter: None,
+            concurrent_downloads: Concurrency::DEFAULT_DOWNLOADS,
+        }
+    }
+
+    /// Set the maximum number of distributions to fetch concurrently.
+    #[must_use]
+    pub fn with_concurrent_downloads(self, concurrent_downloads: usize) -> Self {
+        Self {
+            concurrent_downloads,
+            ..self
         }
     }
 
@@ -68,6 +80,7 @@ impl<'a, Context: BuildContext + Send + Sync> Downloader<'a, Context> {
             cache: self.cache,
             database: self.database.with_reporter(Facade::from(reporter.clone())),
             reporter: Some(reporter.clone()),
+            concurrent_downloads: self.concurrent_downloads,
         }
     }
 
@@ -85,9 +98,9 @@ impl<'a, Context: BuildContext + Send + Sync> Downloader<'a, Context> {
                 }
                 Ok::<CachedDist, Error>(wheel)
             })
-            // TODO(charlie): The number of concurrent fetches, such that we limit the number of
-            // concurrent builds to the number of cores, while allowing more concurrent downloads.
-            .buffer_unordered(50)
+            // Builds are limited separately by the build context, such that we can allow more
+            // concurrent downloads than concurrent builds.
+            .buffer_unordered(self.concurrent_downloads)
     }
 
     /// Download, build, and unzip a set of downloaded wheels.
@@ -141,7 +154,7 @@ impl<'a, Context: BuildContext + Send + Sync> Downloader<'a, Context> {
                 }
                 Ok::<_, Error>((editable, cached_dist, metadata))
             })
-            .buffer_unordered(50);
+            .buffer_unordered(self.concurrent_downloads);
 
         while let Some((editable, wheel, metadata)) = fetches.next().await.transpose()? {
             if let Some(reporter) = self.reporter.as_ref() {
@@ -16,6 +16,7 @@ use uv_cache::{ArchiveTarget, ArchiveTimestamp, Cache, CacheBucket, WheelCache};
 use uv_distribution::{BuiltWheelIndex, RegistryWheelIndex};
 use uv_fs::Simplified;
 use uv_interpreter::PythonEnvironment;
+use uv_normalize::ExtraName;
 use uv_types::{NoBinary, Reinstall};
 
 use crate::{ResolvedEditable, SitePackages};
@@ -25,6 +26,7 @@ use crate::{ResolvedEditable, SitePackages};
 pub struct Planner<'a> {
     requirements: &'a [Requirement],
     editable_requirements: &'a [ResolvedEditable],
+    extras: &'a [ExtraName],
 }
 
 impl<'a> Planner<'a> {
@@ -34,6 +36,7 @@ impl<'a> Planner<'a> {
         Self {
             requirements,
             editable_requirements: &[],
+            extras: &[],
         }
     }
 
@@ -46,6 +49,12 @@ impl<'a> Planner<'a> {
         }
     }
 
+    /// Set the extras against which to evaluate `extra` markers in the requirements.
+    #[must_use]
+    pub fn with_extras(self, extras: &'a [ExtraName]) -> Self {
+        Self { extras, ..self }
+    }
+
     /// Partition a set of requirements into those that should be linked from the cache, those that
     /// need to be downloaded, and those that should be removed.
     ///
@@ -129,7 +138,7 @@ impl<'a> Planner<'a> {
 
         for requirement in self.requirements {
             // Filter out incompatible requirements.
-            if !requirement.evaluate_markers(venv.interpreter().markers(), &[]) {
+            if !requirement.evaluate_markers(venv.interpreter().markers(), self.extras) {
                 continue;
             }
 
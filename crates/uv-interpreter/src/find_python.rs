@@ -1,48 +1,45 @@
-use std::borrow::Cow;
 use std::env;
 use std::ffi::{OsStr, OsString};
-use std::path::PathBuf;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rustc_hash::FxHashSet;
 use tracing::{debug, instrument};
 
+use pep440_rs::{Operator, Version, VersionPattern, VersionSpecifier, VersionSpecifiers};
 use uv_cache::Cache;
 use uv_fs::normalize_path;
 
-use crate::interpreter::InterpreterInfoError;
 use crate::python_environment::{detect_python_executable, detect_virtual_env};
 use crate::{Error, Interpreter, PythonVersion};
 
 /// Find a Python of a specific version, a binary with a name or a path to a binary.
 ///
 /// Supported formats:
-/// * `-p 3.10` searches for an installed Python 3.10 (`py --list-paths` on Windows, `python3.10` on
-///   Linux/Mac). Specifying a patch version is not supported.
+/// * `-p 3.10` or `-p 3.10.4` is equivalent to `-p "==3.10.*"` or `-p "==3.10.4"` respectively.
+/// * `-p ">=3.10,<3.12"` searches every Python in `PATH` (and `py --list-paths` on Windows) and
+///   selects the newest one that satisfies the PEP 440 specifiers.
+/// * `-p cpython@3.11`, `-p pypy3.10` or `-p graalpy` searches every Python in `PATH` (and
+///   `py --list-paths` on Windows) and selects the newest one of the given implementation that
+///   matches the (optional) version or version specifiers.
 /// * `-p python3.10` or `-p python.exe` looks for a binary in `PATH`.
 /// * `-p /home/ferris/.local/bin/python3.10` uses this exact Python.
 ///
-/// When the user passes a patch version (e.g. 3.12.1), we currently search for a matching minor
-/// version (e.g. `python3.12` on unix) and error when the version mismatches, as a binary with the
-/// patch version (e.g. `python3.12.1`) is often not in `PATH` and we make the simplifying
-/// assumption that the user has only this one patch version installed.
+/// As every candidate is queried, a patch version matches any installation with that exact
+/// version, whether or not a binary named after the patch version (e.g. `python3.12.1`) exists.
 #[instrument(skip_all, fields(%request))]
 pub fn find_requested_python(request: &str, cache: &Cache) -> Result<Option<Interpreter>, Error> {
     debug!("Starting interpreter discovery for Python @ `{request}`");
-    let versions = request
-        .splitn(3, '.')
-        .map(str::parse::<u8>)
-        .collect::<Result<Vec<_>, _>>();
-    if let Ok(versions) = versions {
-        // `-p 3.10` or `-p 3.10.1`
-        let selector = match versions.as_slice() {
-            [requested_major] => PythonVersionSelector::Major(*requested_major),
-            [major, minor] => PythonVersionSelector::MajorMinor(*major, *minor),
-            [major, minor, requested_patch] => {
-                PythonVersionSelector::MajorMinorPatch(*major, *minor, *requested_patch)
-            }
-            // SAFETY: Guaranteed by the Ok(versions) guard
-            _ => unreachable!(),
-        };
-        find_python(selector, cache)
+    if let Some(python_request) = PythonRequest::parse(request)? {
+        // Python 2 installations are skipped during discovery, so reject the request upfront.
+        if python_request.version.requires_python2() {
+            return Err(Error::UnsupportedPythonRequest(request.to_string()));
+        }
+        // `-p 3.10`, `-p ">=3.10,<3.12"`, `-p cpython@3.11`, `-p pypy3.10` or `-p graalpy`
+        find_newest_python(&python_request, cache)
     } else if !request.contains(std::path::MAIN_SEPARATOR) {
         // `-p python3.10`; Generally not used on windows because all Python are `python.exe`.
         let Some(executable) = find_executable(request)? else {
@@ -75,31 +72,31 @@ pub fn find_default_python(cache: &Cache) -> Result<Interpreter, Error> {
 
 /// Same as [`find_default_python`] but returns `None` if no python is found instead of returning an `Err`.
 pub(crate) fn try_find_default_python(cache: &Cache) -> Result<Option<Interpreter>, Error> {
-    find_python(PythonVersionSelector::Default, cache)
+    find_python(cache)
 }
 
-/// Find a Python version matching `selector`.
+/// Find the default Python installation.
 ///
 /// It searches for an existing installation in the following order:
-/// * Search for the python binary in `PATH` (or `UV_TEST_PYTHON_PATH` if set). Visits each path and for each path resolves the
-///   files in the following order:
-///   * Major.Minor.Patch: `pythonx.y.z`, `pythonx.y`, `python.x`, `python`
-///   * Major.Minor: `pythonx.y`, `pythonx`, `python`
-///   * Major: `pythonx`, `python`
-///   * Default: `python3`, `python`
-///   * (windows): For each of the above, test for the existence of `python.bat` shim (pyenv-windows) last.
+/// * Search for the python binary in `PATH` (or `UV_TEST_PYTHON_PATH` if set). Visits each path
+///   and for each path resolves `python3`, then `python`.
+///   * (windows): Test for the existence of a `python.bat` shim (pyenv-windows) last.
 /// * (windows): Discover installations using `py --list-paths` (PEP514). Continue if `py` is not installed.
 ///
+/// Requests for a specific version or implementation are handled by [`find_newest_python`]
+/// instead, which also searches the installations managed by pyenv, asdf, conda and Homebrew.
+///
 /// (Windows): Filter out the Windows store shim (Enabled in Settings/Apps/Advanced app settings/App execution aliases).
-fn find_python(
-    selector: PythonVersionSelector,
-    cache: &Cache,
-) -> Result<Option<Interpreter>, Error> {
+fn find_python(cache: &Cache) -> Result<Option<Interpreter>, Error> {
     #[allow(non_snake_case)]
     let UV_TEST_PYTHON_PATH = env::var_os("UV_TEST_PYTHON_PATH");
 
     let use_override = UV_TEST_PYTHON_PATH.is_some();
-    let possible_names = selector.possible_names();
+    let possible_names = if cfg!(windows) {
+        ["python3.exe", "python.exe"]
+    } else {
+        ["python3", "python"]
+    };
 
     #[allow(non_snake_case)]
     let PATH = UV_TEST_PYTHON_PATH
@@ -110,37 +107,18 @@ fn find_python(
     // binary is executable and exists. It also has some extra logic that handles inconsistent casing on Windows
     // and expands `~`.
     for path in env::split_paths(&PATH) {
-        for name in possible_names.iter().flatten() {
-            if let Ok(paths) = which::which_in_global(&**name, Some(&path)) {
+        for name in possible_names {
+            if let Ok(paths) = which::which_in_global(name, Some(&path)) {
+                #[allow(clippy::never_loop)]
                 for path in paths {
                     #[cfg(windows)]
                     if windows::is_windows_store_shim(&path) {
                         continue;
                     }
 
-                    let interpreter = match Interpreter::query(&path, cache) {
-                        Ok(interpreter) => interpreter,
-                        Err(
-                            err @ Error::QueryScript {
-                                err: InterpreterInfoError::UnsupportedPythonVersion,
-                                ..
-                            },
-                        ) => {
-                            if selector.major() <= Some(2) {
-                                return Err(err);
-                            }
-                            // Skip over Python 2 or older installation when querying for a recent python installation.
-                            debug!("Found a Python 2 installation that isn't supported by uv, skipping.");
-                            continue;
-                        }
-                        Err(error) => return Err(error),
-                    };
-
-                    let installation = PythonInstallation::Interpreter(interpreter);
-
-                    if let Some(interpreter) = installation.select(selector, cache)? {
-                        return Ok(Some(interpreter));
-                    }
+                    // A Python 2 installation fails the query with an `UnsupportedPythonVersion`
+                    // error, which is surfaced as-is.
+                    return Interpreter::query(&path, cache).map(Some);
                 }
             }
         }
@@ -151,20 +129,13 @@ fn find_python(
         if cfg!(windows) {
             if let Ok(shims) = which::which_in_global("python.bat", Some(&path)) {
                 for shim in shims {
-                    let interpreter = match Interpreter::query(&shim, cache) {
-                        Ok(interpreter) => interpreter,
+                    match Interpreter::query(&shim, cache) {
+                        Ok(interpreter) => return Ok(Some(interpreter)),
                         Err(error) => {
                             // Don't fail when querying the shim failed. E.g it's possible that no python version is selected
                             // in the shim in which case pyenv prints to stdout.
                             tracing::warn!("Failed to query python shim: {error}");
-                            continue;
                         }
-                    };
-
-                    if let Some(interpreter) =
-                        PythonInstallation::Interpreter(interpreter).select(selector, cache)?
-                    {
-                        return Ok(Some(interpreter));
                     }
                 }
             }
@@ -175,11 +146,8 @@ fn find_python(
         // Use `py` to find the python installation on the system.
         match windows::py_list_paths() {
             Ok(paths) => {
-                for entry in paths {
-                    let installation = PythonInstallation::PyListPath(entry);
-                    if let Some(interpreter) = installation.select(selector, cache)? {
-                        return Ok(Some(interpreter));
-                    }
+                if let Some(entry) = paths.into_iter().next() {
+                    return Interpreter::query(entry.executable_path, cache).map(Some);
                 }
             }
             Err(Error::PyList(error)) => {
@@ -264,81 +232,551 @@ fn find_executable<R: AsRef<OsStr> + Into<OsString> + Copy>(
     Ok(None)
 }
 
-#[derive(Debug, Clone)]
-struct PyListPath {
-    major: u8,
-    minor: u8,
-    executable_path: PathBuf,
+/// Find the newest Python interpreter that satisfies the given [`PythonRequest`].
+///
+/// Unlike [`find_python`], this doesn't stop at the first matching binary: it queries every
+/// Python executable in `PATH` (or `UV_TEST_PYTHON_PATH` if set), i.e., every `python`, `pypy` and
+/// `graalpy` binary with an optional version suffix, along with every `py --list-paths` entry on
+/// Windows and every installation managed by pyenv, asdf, conda and Homebrew. Among the
+/// interpreters that satisfy the request, the one with the highest version is returned, with ties
+/// broken by discovery order.
+fn find_newest_python(
+    request: &PythonRequest,
+    cache: &Cache,
+) -> Result<Option<Interpreter>, Error> {
+    #[allow(non_snake_case)]
+    let UV_TEST_PYTHON_PATH = env::var_os("UV_TEST_PYTHON_PATH");
+
+    let use_override = UV_TEST_PYTHON_PATH.is_some();
+
+    #[allow(non_snake_case)]
+    let PATH = UV_TEST_PYTHON_PATH
+        .or(env::var_os("PATH"))
+        .unwrap_or_default();
+
+    let mut candidates = Vec::new();
+    for path in env::split_paths(&PATH) {
+        let Ok(entries) = fs_err::read_dir(&path) else {
+            continue;
+        };
+
+        // Sort the entries, such that the discovery order (and thus, tie-breaking) is stable.
+        let mut names = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|name| PYTHON_EXECUTABLE.is_match(name))
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+
+        // We use `which` here to check if the python binary is executable and exists.
+        for name in names {
+            if let Some(executable) = which::which_in_global(&name, Some(&path))
+                .ok()
+                .and_then(|mut paths| paths.next())
+            {
+                #[cfg(windows)]
+                if windows::is_windows_store_shim(&executable) {
+                    continue;
+                }
+
+                candidates.push((PythonProvider::Path, executable));
+            }
+        }
+    }
+
+    if cfg!(windows) && !use_override {
+        // Use `py` to find the python installation on the system.
+        match windows::py_list_paths() {
+            Ok(paths) => {
+                candidates.extend(
+                    paths
+                        .into_iter()
+                        .map(|entry| (PythonProvider::PyLauncher, entry.executable_path)),
+                );
+            }
+            Err(Error::PyList(error)) => {
+                if error.kind() == std::io::ErrorKind::NotFound {
+                    debug!("`py` is not installed");
+                }
+            }
+            Err(error) => return Err(error),
+        }
+    }
+
+    if !use_override {
+        for provider in PythonProvider::MANAGED {
+            candidates.extend(
+                provider
+                    .executables()
+                    .into_iter()
+                    .map(|executable| (provider, executable)),
+            );
+        }
+    }
+
+    let mut seen = FxHashSet::default();
+    let mut newest: Option<Interpreter> = None;
+    for (provider, executable) in candidates {
+        // Multiple names often point to the same binary (e.g., `python3` and `python3.12`).
+        let canonical = fs_err::canonicalize(&executable).unwrap_or_else(|_| executable.clone());
+        if !seen.insert(canonical) {
+            continue;
+        }
+
+        let Some(interpreter) = query_candidate(provider, &executable, cache) else {
+            continue;
+        };
+
+        if !request.is_satisfied_by(&interpreter) {
+            debug!(
+                "Skipping {} {} at `{}`, which does not satisfy `{request}`",
+                interpreter.implementation_name(),
+                interpreter.python_version(),
+                executable.display()
+            );
+            continue;
+        }
+
+        if newest.as_ref().map_or(true, |newest| {
+            interpreter.python_version() > newest.python_version()
+        }) {
+            newest = Some(interpreter);
+        }
+    }
+
+    if let Some(interpreter) = &newest {
+        debug!(
+            "Selected {} {} at `{}` for `{request}`",
+            interpreter.implementation_name(),
+            interpreter.python_version(),
+            interpreter.sys_executable().display()
+        );
+    }
+
+    Ok(newest)
 }
 
-#[derive(Debug, Clone)]
-enum PythonInstallation {
-    PyListPath(PyListPath),
-    Interpreter(Interpreter),
+/// Query a Python executable found by the given provider.
+///
+/// Returns `None` if the executable can't be queried, e.g., a Python 2 installation or a broken
+/// shim, such that a single bad candidate doesn't fail discovery as a whole.
+fn query_candidate(
+    provider: PythonProvider,
+    executable: &Path,
+    cache: &Cache,
+) -> Option<Interpreter> {
+    match Interpreter::query(executable, cache) {
+        Ok(interpreter) => {
+            debug!(
+                "Found {} {} at `{}` (via {provider})",
+                interpreter.implementation_name(),
+                interpreter.python_version(),
+                executable.display()
+            );
+            Some(interpreter)
+        }
+        Err(err) => {
+            debug!(
+                "Skipping Python candidate at `{}` (via {provider}): {err}",
+                executable.display()
+            );
+            None
+        }
+    }
+}
+
+/// A source of Python installations.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum PythonProvider {
+    /// An executable in `PATH` (or `UV_TEST_PYTHON_PATH`).
+    Path,
+    /// An installation listed by `py --list-paths` on Windows.
+    PyLauncher,
+    /// An installation in the pyenv root, e.g., `~/.pyenv/versions/3.11.8/bin/python3`.
+    Pyenv,
+    /// An installation in the asdf data directory, e.g.,
+    /// `~/.asdf/installs/python/3.11.8/bin/python3`.
+    Asdf,
+    /// The active conda environment (`CONDA_PREFIX`), or a base or named environment of a conda
+    /// installation, e.g., `~/miniconda3/envs/ml/bin/python3`.
+    Conda,
+    /// A Homebrew keg, e.g., `/opt/homebrew/Cellar/python@3.11/3.11.8/bin/python3.11`.
+    Homebrew,
 }
 
-impl PythonInstallation {
-    fn major(&self) -> u8 {
+impl PythonProvider {
+    /// The providers for tool-managed installations, which are often not in `PATH` by name, in
+    /// the order in which they are searched.
+    const MANAGED: [Self; 4] = [Self::Pyenv, Self::Asdf, Self::Conda, Self::Homebrew];
+
+    /// Return the Python executables of the installations managed by this provider.
+    fn executables(self) -> Vec<PathBuf> {
         match self {
-            Self::PyListPath(PyListPath { major, .. }) => *major,
-            Self::Interpreter(interpreter) => interpreter.python_major(),
+            // Discovered through `which` and `py --list-paths` respectively.
+            Self::Path | Self::PyLauncher => Vec::new(),
+            Self::Pyenv => {
+                if cfg!(windows) {
+                    // pyenv-win sets `PYENV_ROOT` to `~/.pyenv/pyenv-win`.
+                    let Some(root) = env::var_os("PYENV_ROOT").map(PathBuf::from).or_else(|| {
+                        home::home_dir().map(|home| home.join(".pyenv").join("pyenv-win"))
+                    }) else {
+                        return Vec::new();
+                    };
+                    glob_executables(&root.join("versions"), "*/python.exe")
+                } else {
+                    let Some(root) = env::var_os("PYENV_ROOT")
+                        .map(PathBuf::from)
+                        .or_else(|| home::home_dir().map(|home| home.join(".pyenv")))
+                    else {
+                        return Vec::new();
+                    };
+                    glob_executables(&root.join("versions"), "*/bin/python3")
+                }
+            }
+            Self::Asdf => {
+                let Some(root) = env::var_os("ASDF_DATA_DIR")
+                    .map(PathBuf::from)
+                    .or_else(|| home::home_dir().map(|home| home.join(".asdf")))
+                else {
+                    return Vec::new();
+                };
+                glob_executables(&root.join("installs").join("python"), "*/bin/python3")
+            }
+            Self::Conda => {
+                let mut prefixes = Vec::new();
+
+                // The active environment.
+                if let Some(prefix) = env::var_os("CONDA_PREFIX") {
+                    prefixes.push(PathBuf::from(prefix));
+                }
+
+                // The base environment and the named environments of each conda installation,
+                // e.g., `~/miniconda3/bin/conda` or `~/miniconda3/Scripts/conda.exe`.
+                let mut roots = Vec::new();
+                if let Some(root) = env::var_os("CONDA_EXE")
+                    .map(PathBuf::from)
+                    .and_then(|conda| Some(conda.parent()?.parent()?.to_path_buf()))
+                {
+                    roots.push(root);
+                }
+                if let Some(home) = home::home_dir() {
+                    for name in ["miniconda3", "anaconda3", "miniforge3", "mambaforge"] {
+                        roots.push(home.join(name));
+                    }
+                }
+                for root in roots {
+                    if !root.join("conda-meta").is_dir() {
+                        continue;
+                    }
+                    let mut envs = fs_err::read_dir(root.join("envs"))
+                        .into_iter()
+                        .flatten()
+                        .filter_map(Result::ok)
+                        .map(|entry| entry.path())
+                        .collect::<Vec<_>>();
+                    envs.sort_unstable();
+                    prefixes.push(root);
+                    prefixes.extend(envs);
+                }
+
+                prefixes
+                    .into_iter()
+                    .map(|prefix| {
+                        if cfg!(windows) {
+                            prefix.join("python.exe")
+                        } else {
+                            prefix.join("bin").join("python3")
+                        }
+                    })
+                    .filter(|executable| executable.is_file())
+                    .collect()
+            }
+            Self::Homebrew => {
+                let cellars = if let Some(cellar) = env::var_os("HOMEBREW_CELLAR") {
+                    vec![PathBuf::from(cellar)]
+                } else if let Some(prefix) = env::var_os("HOMEBREW_PREFIX") {
+                    vec![PathBuf::from(prefix).join("Cellar")]
+                } else {
+                    vec![
+                        PathBuf::from("/opt/homebrew/Cellar"),
+                        PathBuf::from("/usr/local/Cellar"),
+                        PathBuf::from("/home/linuxbrew/.linuxbrew/Cellar"),
+                    ]
+                };
+                cellars
+                    .iter()
+                    .flat_map(|cellar| glob_executables(cellar, "python@3*/*/bin/python3.*"))
+                    .collect()
+            }
         }
     }
+}
 
-    fn minor(&self) -> u8 {
+impl Display for PythonProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::PyListPath(PyListPath { minor, .. }) => *minor,
-            Self::Interpreter(interpreter) => interpreter.python_minor(),
+            Self::Path => f.write_str("`PATH`"),
+            Self::PyLauncher => f.write_str("`py --list-paths`"),
+            Self::Pyenv => f.write_str("pyenv"),
+            Self::Asdf => f.write_str("asdf"),
+            Self::Conda => f.write_str("conda"),
+            Self::Homebrew => f.write_str("Homebrew"),
         }
     }
+}
+
+/// Return the Python executables below `root` that match the glob `pattern`, in lexicographic
+/// order.
+///
+/// Only files that are named like a Python executable (see [`PYTHON_EXECUTABLE`]) are returned,
+/// which excludes, e.g., `python3.11-config`.
+fn glob_executables(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    if !root.is_dir() {
+        return Vec::new();
+    }
+    let pattern = format!(
+        "{}/{pattern}",
+        glob::Pattern::escape(&root.to_string_lossy())
+    );
+    let Ok(paths) = glob::glob(&pattern) else {
+        return Vec::new();
+    };
+    paths
+        .filter_map(Result::ok)
+        .filter(|path| {
+            path.file_name()
+                .and_then(OsStr::to_str)
+                .is_some_and(|name| PYTHON_EXECUTABLE.is_match(name))
+        })
+        .filter(|path| path.is_file())
+        .collect()
+}
 
-    /// Selects the interpreter if it matches the selector (version specification).
-    fn select(
-        self,
-        selector: PythonVersionSelector,
-        cache: &Cache,
-    ) -> Result<Option<Interpreter>, Error> {
-        let selected = match selector {
-            PythonVersionSelector::Default => true,
+/// Matches the names of Python executables, e.g., `python`, `python3.12`, `pypy3.10` or
+/// `graalpy.exe`.
+static PYTHON_EXECUTABLE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?:python|pypy|graalpy)(?:\d+(?:\.\d+)*)?(?:\.exe)?$").unwrap());
+
+/// A Python implementation that can be requested, e.g., with `-p pypy@3.10`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ImplementationName {
+    CPython,
+    PyPy,
+    GraalPy,
+}
 
-            PythonVersionSelector::Major(major) => self.major() == major,
+impl ImplementationName {
+    /// The known implementations, longest name first.
+    const ALL: [Self; 3] = [Self::CPython, Self::GraalPy, Self::PyPy];
 
-            PythonVersionSelector::MajorMinor(major, minor) => {
-                self.major() == major && self.minor() == minor
+    /// The name of the implementation, as reported by the `implementation_name` marker.
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::CPython => "cpython",
+            Self::PyPy => "pypy",
+            Self::GraalPy => "graalpy",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|implementation| name.eq_ignore_ascii_case(implementation.as_str()))
+    }
+}
+
+impl Display for ImplementationName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The version constraint of a [`PythonRequest`].
+#[derive(Debug, Clone)]
+enum VersionRequest {
+    /// A bare version, e.g., `3.10`, or no version at all.
+    Selector(PythonVersionSelector),
+    /// PEP 440 version specifiers, e.g., `>=3.10,<3.12`.
+    Specifiers(VersionSpecifiers),
+}
+
+impl VersionRequest {
+    /// Parse a version request, e.g., `3.10` or `>=3.10,<3.12`.
+    ///
+    /// An empty string requests any version.
+    fn parse(request: &str) -> Result<Option<Self>, Error> {
+        if request.is_empty() {
+            return Ok(Some(Self::Selector(PythonVersionSelector::Default)));
+        }
+        if request.starts_with(['<', '>', '=', '!', '~']) {
+            let specifiers = VersionSpecifiers::from_str(request)
+                .map_err(|err| Error::InvalidVersionSpecifiers(request.to_string(), err))?;
+            return Ok(Some(Self::Specifiers(specifiers)));
+        }
+        Ok(PythonVersionSelector::parse(request).map(Self::Selector))
+    }
+
+    /// Returns `true` if the request can only be satisfied by Python 2 (or older), e.g., `2.7`,
+    /// `==2.7.*` or `<3`.
+    fn requires_python2(&self) -> bool {
+        match self {
+            Self::Selector(PythonVersionSelector::Default) => false,
+            Self::Selector(
+                PythonVersionSelector::Major(major)
+                | PythonVersionSelector::MajorMinor(major, _)
+                | PythonVersionSelector::MajorMinorPatch(major, _, _),
+            ) => *major <= 2,
+            Self::Specifiers(specifiers) => specifiers.iter().any(|specifier| {
+                let python3 = Version::new([3]);
+                match specifier.operator() {
+                    Operator::Equal
+                    | Operator::EqualStar
+                    | Operator::ExactEqual
+                    | Operator::TildeEqual => specifier.version().release()[0] <= 2,
+                    Operator::LessThan => *specifier.version() <= python3,
+                    Operator::LessThanEqual => *specifier.version() < python3,
+                    Operator::NotEqual
+                    | Operator::NotEqualStar
+                    | Operator::GreaterThan
+                    | Operator::GreaterThanEqual => false,
+                }
+            }),
+        }
+    }
+
+    fn is_satisfied_by(&self, interpreter: &Interpreter) -> bool {
+        match self {
+            Self::Selector(PythonVersionSelector::Default) => true,
+            Self::Selector(PythonVersionSelector::Major(major)) => {
+                interpreter.python_major() == *major
+            }
+            Self::Selector(PythonVersionSelector::MajorMinor(major, minor)) => {
+                interpreter.python_tuple() == (*major, *minor)
             }
+            Self::Selector(PythonVersionSelector::MajorMinorPatch(major, minor, patch)) => {
+                interpreter.python_tuple() == (*major, *minor)
+                    && interpreter.python_patch() == *patch
+            }
+            Self::Specifiers(specifiers) => specifiers.contains(interpreter.python_version()),
+        }
+    }
+}
 
-            PythonVersionSelector::MajorMinorPatch(major, minor, requested_patch) => {
-                let interpreter = self.into_interpreter(cache)?;
-                return Ok(
-                    if major == interpreter.python_major()
-                        && minor == interpreter.python_minor()
-                        && requested_patch == interpreter.python_patch()
-                    {
-                        Some(interpreter)
-                    } else {
-                        None
-                    },
-                );
+impl Display for VersionRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Selector(PythonVersionSelector::Default) => Ok(()),
+            Self::Selector(PythonVersionSelector::Major(major)) => write!(f, "{major}"),
+            Self::Selector(PythonVersionSelector::MajorMinor(major, minor)) => {
+                write!(f, "{major}.{minor}")
             }
-        };
+            Self::Selector(PythonVersionSelector::MajorMinorPatch(major, minor, patch)) => {
+                write!(f, "{major}.{minor}.{patch}")
+            }
+            Self::Specifiers(specifiers) => write!(f, "{specifiers}"),
+        }
+    }
+}
 
-        if selected {
-            self.into_interpreter(cache).map(Some)
-        } else {
-            Ok(None)
+/// A request for a Python interpreter that requires inspecting every candidate, i.e., a request
+/// with version specifiers or for a specific implementation.
+#[derive(Debug, Clone)]
+struct PythonRequest {
+    implementation: Option<ImplementationName>,
+    version: VersionRequest,
+}
+
+impl PythonRequest {
+    /// Parse a request like `3.10`, `>=3.10,<3.12`, `cpython@3.11`, `pypy@>=3.9`, `pypy3.10` or
+    /// `graalpy`.
+    ///
+    /// Returns `Ok(None)` if the request should instead be treated as an executable name or path.
+    fn parse(request: &str) -> Result<Option<Self>, Error> {
+        // Ex) `3.10` or `3.10.4`, i.e., `==3.10.*` or `==3.10.4`
+        if let Some(selector) = PythonVersionSelector::parse(request) {
+            return Ok(Some(Self {
+                implementation: None,
+                version: VersionRequest::Specifiers(selector.specifiers()),
+            }));
         }
+
+        // Ex) `>=3.10,<3.12`
+        if request.starts_with(['<', '>', '=', '!', '~']) {
+            return Ok(VersionRequest::parse(request)?.map(|version| Self {
+                implementation: None,
+                version,
+            }));
+        }
+
+        // Ex) `cpython@3.11` or `pypy@>=3.9`
+        if let Some((name, version)) = request.split_once('@') {
+            let Some(implementation) = ImplementationName::from_name(name) else {
+                return Ok(None);
+            };
+            let Some(version) = VersionRequest::parse(version)? else {
+                return Err(Error::InvalidPythonRequest(request.to_string()));
+            };
+            return Ok(Some(Self {
+                implementation: Some(implementation),
+                version,
+            }));
+        }
+
+        // Ex) `pypy3.10`, `cpython3` or `graalpy`
+        for implementation in ImplementationName::ALL {
+            let Some(prefix) = request.get(..implementation.as_str().len()) else {
+                continue;
+            };
+            if !prefix.eq_ignore_ascii_case(implementation.as_str()) {
+                continue;
+            }
+            let version = &request[implementation.as_str().len()..];
+            let version = if version.is_empty() {
+                PythonVersionSelector::Default
+            } else if let Some(selector) = PythonVersionSelector::parse(version) {
+                selector
+            } else {
+                return Ok(None);
+            };
+            return Ok(Some(Self {
+                implementation: Some(implementation),
+                version: VersionRequest::Selector(version),
+            }));
+        }
+
+        Ok(None)
     }
 
-    pub(super) fn into_interpreter(self, cache: &Cache) -> Result<Interpreter, Error> {
-        match self {
-            Self::PyListPath(PyListPath {
-                executable_path, ..
-            }) => Interpreter::query(executable_path, cache),
-            Self::Interpreter(interpreter) => Ok(interpreter),
+    fn is_satisfied_by(&self, interpreter: &Interpreter) -> bool {
+        if let Some(implementation) = self.implementation {
+            if !interpreter
+                .implementation_name()
+                .eq_ignore_ascii_case(implementation.as_str())
+            {
+                return false;
+            }
         }
+        self.version.is_satisfied_by(interpreter)
     }
 }
 
+impl Display for PythonRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self.implementation, &self.version) {
+            (Some(implementation), VersionRequest::Selector(PythonVersionSelector::Default)) => {
+                write!(f, "{implementation}")
+            }
+            (Some(implementation), version) => write!(f, "{implementation}@{version}"),
+            (None, version) => write!(f, "{version}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PyListPath {
+    executable_path: PathBuf,
+}
+
 #[derive(Copy, Clone, Debug)]
 enum PythonVersionSelector {
     Default,
@@ -348,50 +786,42 @@ enum PythonVersionSelector {
 }
 
 impl PythonVersionSelector {
-    fn possible_names(self) -> [Option<Cow<'static, str>>; 4] {
-        let (python, python3, extension) = if cfg!(windows) {
-            (
-                Cow::Borrowed("python.exe"),
-                Cow::Borrowed("python3.exe"),
-                ".exe",
-            )
-        } else {
-            (Cow::Borrowed("python"), Cow::Borrowed("python3"), "")
-        };
-
-        match self {
-            Self::Default => [Some(python3), Some(python), None, None],
-            Self::Major(major) => [
-                Some(Cow::Owned(format!("python{major}{extension}"))),
-                Some(python),
-                None,
-                None,
-            ],
-            Self::MajorMinor(major, minor) => [
-                Some(Cow::Owned(format!("python{major}.{minor}{extension}"))),
-                Some(Cow::Owned(format!("python{major}{extension}"))),
-                Some(python),
-                None,
-            ],
-            Self::MajorMinorPatch(major, minor, patch) => [
-                Some(Cow::Owned(format!(
-                    "python{major}.{minor}.{patch}{extension}",
-                ))),
-                Some(Cow::Owned(format!("python{major}.{minor}{extension}"))),
-                Some(Cow::Owned(format!("python{major}{extension}"))),
-                Some(python),
-            ],
-        }
-    }
-
-    fn major(self) -> Option<u8> {
-        match self {
-            Self::Default => None,
-            Self::Major(major) => Some(major),
-            Self::MajorMinor(major, _) => Some(major),
-            Self::MajorMinorPatch(major, _, _) => Some(major),
+    /// Parse a bare version like `3`, `3.10` or `3.10.1`.
+    fn parse(request: &str) -> Option<Self> {
+        let versions = request
+            .splitn(3, '.')
+            .map(str::parse::<u8>)
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?;
+        match versions.as_slice() {
+            [requested_major] => Some(Self::Major(*requested_major)),
+            [major, minor] => Some(Self::MajorMinor(*major, *minor)),
+            [major, minor, requested_patch] => {
+                Some(Self::MajorMinorPatch(*major, *minor, *requested_patch))
+            }
+            _ => None,
         }
     }
+
+    /// The PEP 440 specifiers matching the selected versions, e.g., `==3.10.*` for `3.10` or
+    /// `==3.10.4` for `3.10.4`.
+    fn specifiers(self) -> VersionSpecifiers {
+        let pattern = match self {
+            Self::Default => return std::iter::empty().collect(),
+            Self::Major(major) => VersionPattern::wildcard(Version::new([u64::from(major)])),
+            Self::MajorMinor(major, minor) => {
+                VersionPattern::wildcard(Version::new([u64::from(major), u64::from(minor)]))
+            }
+            Self::MajorMinorPatch(major, minor, patch) => VersionPattern::verbatim(Version::new([
+                u64::from(major),
+                u64::from(minor),
+                u64::from(patch),
+            ])),
+        };
+        VersionSpecifier::from_pattern(Operator::Equal, pattern)
+            .expect("`==` is valid for any release version")
+            .into()
+    }
 }
 
 /// Find a matching Python or any fallback Python.
@@ -448,7 +878,8 @@ pub fn find_best_python(
 /// - `UV_DEFAULT_PYTHON`, which is set to the python interpreter when using `python -m uv`.
 /// - `VIRTUAL_ENV` and `CONDA_PREFIX`
 /// - A `.venv` folder
-/// - If a python version is given: Search `PATH` and `py --list-paths`, see `find_python`
+/// - If a python version is given: Search `PATH`, `py --list-paths` and tool-managed
+///   installations, see [`find_requested_python`]
 /// - `python3` (unix) or `python.exe` (windows)
 ///
 /// If `UV_TEST_PYTHON_PATH` is set, we will not check for Python versions in the
@@ -550,18 +981,10 @@ mod windows {
 
         Ok(PY_LIST_PATHS
             .captures_iter(&stdout)
-            .filter_map(|captures| {
-                let (_, [major, minor, path]) = captures.extract();
-                if let (Some(major), Some(minor)) =
-                    (major.parse::<u8>().ok(), minor.parse::<u8>().ok())
-                {
-                    Some(PyListPath {
-                        major,
-                        minor,
-                        executable_path: PathBuf::from(path),
-                    })
-                } else {
-                    None
+            .map(|captures| {
+                let (_, [_major, _minor, path]) = captures.extract();
+                PyListPath {
+                    executable_path: PathBuf::from(path),
                 }
             })
             .collect())
@@ -738,7 +1161,7 @@ mod tests {
 
     use uv_cache::Cache;
 
-    use crate::find_python::find_requested_python;
+    use crate::find_python::{find_requested_python, glob_executables, PythonRequest};
     use crate::Error;
 
     fn format_err<T: std::fmt::Debug>(err: Result<T, Error>) -> String {
@@ -783,4 +1206,104 @@ mod tests {
           Caused by: No such file or directory (os error 2)
         "###);
     }
+
+    #[test]
+    fn parse_python_request() {
+        let parse = |request: &str| {
+            PythonRequest::parse(request)
+                .unwrap()
+                .as_ref()
+                .map(ToString::to_string)
+        };
+
+        assert_eq!(parse("3").as_deref(), Some("==3.*"));
+        assert_eq!(parse("3.10").as_deref(), Some("==3.10.*"));
+        assert_eq!(parse("3.10.4").as_deref(), Some("==3.10.4"));
+        assert_eq!(parse(">=3.10,<3.12").as_deref(), Some(">=3.10, <3.12"));
+        assert_eq!(parse("cpython@3.11").as_deref(), Some("cpython@3.11"));
+        assert_eq!(parse("pypy@>=3.9").as_deref(), Some("pypy@>=3.9"));
+        assert_eq!(parse("pypy3.10").as_deref(), Some("pypy@3.10"));
+        assert_eq!(parse("PyPy3").as_deref(), Some("pypy@3"));
+        assert_eq!(parse("graalpy").as_deref(), Some("graalpy"));
+        assert_eq!(parse("python3.10"), None);
+        assert_eq!(parse("pypy-c"), None);
+        assert_eq!(parse("/usr/bin/pypy3.10"), None);
+    }
+
+    #[test]
+    fn python2_request() {
+        for request in ["2", "2.7", "==2.7.*", "<3", "cpython@2.7", "pypy2.7"] {
+            assert_eq!(
+                find_requested_python(request, &Cache::temp().unwrap())
+                    .unwrap_err()
+                    .to_string(),
+                format!("Python 2 is not supported, but `{request}` only matches Python 2. Please use Python 3.8 or newer."),
+                "{request}"
+            );
+        }
+        assert!(!PythonRequest::parse(">=2.7")
+            .unwrap()
+            .unwrap()
+            .version
+            .requires_python2());
+    }
+
+    #[test]
+    fn invalid_python_request() {
+        assert_snapshot!(
+            format_err(PythonRequest::parse("cpython@latest")),
+            @"Invalid Python request `cpython@latest`: expected a version (e.g., `3.10`) or version specifiers (e.g., `>=3.10`) after the implementation name"
+        );
+        assert_eq!(
+            PythonRequest::parse(">=3.10,<").unwrap_err().to_string(),
+            "Invalid Python version specifiers `>=3.10,<`"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(unix), ignore)]
+    fn no_such_python_specifiers() {
+        let request = ">=3.1000";
+        let result = find_requested_python(request, &Cache::temp().unwrap())
+            .unwrap()
+            .ok_or(Error::NoSuchPython(request.to_string()));
+        assert_snapshot!(
+            format_err(result),
+            @"No Python >=3.1000 in `PATH`. Is Python >=3.1000 installed?"
+        );
+    }
+
+    #[test]
+    fn glob_provider_executables() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        for path in [
+            "python@3.11/3.11.8/bin/python3.11",
+            "python@3.11/3.11.8/bin/python3.11-config",
+            "python@3.12/3.12.2/bin/python3.12",
+            "pipx/1.4.3/bin/python3.12",
+        ] {
+            let path = root.path().join(path);
+            fs_err::create_dir_all(path.parent().unwrap())?;
+            fs_err::write(&path, "")?;
+        }
+
+        let executables = glob_executables(root.path(), "python@3*/*/bin/python3.*")
+            .into_iter()
+            .map(|path| {
+                path.strip_prefix(root.path())
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            executables,
+            [
+                "python@3.11/3.11.8/bin/python3.11",
+                "python@3.12/3.12.2/bin/python3.12"
+            ]
+        );
+
+        Ok(())
+    }
 }
@@ -57,6 +57,12 @@ pub enum Error {
     #[cfg(unix)]
     #[error("No Python {0} in `PATH`. Is Python {0} installed?")]
     NoSuchPython(String),
+    #[error("Invalid Python request `{0}`: expected a version (e.g., `3.10`) or version specifiers (e.g., `>=3.10`) after the implementation name")]
+    InvalidPythonRequest(String),
+    #[error("Invalid Python version specifiers `{0}`")]
+    InvalidVersionSpecifiers(String, #[source] pep440_rs::VersionSpecifiersParseError),
+    #[error("Python 2 is not supported, but `{0}` only matches Python 2. Please use Python 3.8 or newer.")]
+    UnsupportedPythonRequest(String),
     #[error("Neither `python` nor `python3` are in `PATH`. Is Python installed?")]
     NoPythonInstalledUnix,
     #[error(
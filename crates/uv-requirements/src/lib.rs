@@ -1,14 +1,18 @@
 pub use crate::lookahead::*;
+pub use crate::pyproject_mut::*;
 pub use crate::source_tree::*;
 pub use crate::sources::*;
 pub use crate::specification::*;
 pub use crate::unnamed::*;
+pub use crate::workspace::*;
 
 mod confirm;
 mod lookahead;
 mod pyproject;
+mod pyproject_mut;
 mod source_tree;
 mod sources;
 mod specification;
 mod unnamed;
 pub mod upgrade;
+mod workspace;
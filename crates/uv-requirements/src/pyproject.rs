@@ -1,10 +1,15 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
 use indexmap::IndexMap;
 use rustc_hash::FxHashSet;
-use serde::{Deserialize, Serialize};
-use std::str::FromStr;
+use serde::{de, Deserialize, Deserializer, Serialize};
 
-use pep508_rs::Requirement;
+use pep508_rs::{Requirement, VerbatimUrl, VersionOrUrl};
 use pypi_types::LenientRequirement;
+use requirements_txt::EditableRequirement;
+use uv_fs::Simplified;
 use uv_normalize::{ExtraName, PackageName};
 
 use crate::ExtrasSpecification;
@@ -15,6 +20,8 @@ use crate::ExtrasSpecification;
 pub(crate) struct PyProjectToml {
     /// Project metadata
     pub(crate) project: Option<Project>,
+    /// Tool-specific metadata
+    pub(crate) tool: Option<Tool>,
 }
 
 /// PEP 621 project metadata.
@@ -37,6 +44,159 @@ pub(crate) struct Project {
     pub(crate) dynamic: Option<Vec<String>>,
 }
 
+/// The `[tool]` table of a `pyproject.toml`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct Tool {
+    pub(crate) uv: Option<ToolUv>,
+}
+
+/// The `[tool.uv]` table of a `pyproject.toml`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct ToolUv {
+    pub(crate) workspace: Option<ToolUvWorkspace>,
+    pub(crate) sources: Option<BTreeMap<PackageName, Source>>,
+}
+
+/// The `[tool.uv.workspace]` table of a `pyproject.toml`, which marks the root of a workspace.
+///
+/// For example:
+/// ```toml
+/// [tool.uv.workspace]
+/// members = ["packages/*"]
+/// exclude = ["packages/legacy"]
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct ToolUvWorkspace {
+    /// Glob patterns, relative to the workspace root, matching the member directories.
+    pub(crate) members: Option<Vec<String>>,
+    /// Glob patterns, relative to the workspace root, for directories to exclude from the members.
+    pub(crate) exclude: Option<Vec<String>>,
+}
+
+/// A `[tool.uv.sources]` entry, which overrides where a dependency is fetched from without
+/// changing the requirement in the published metadata.
+///
+/// For example:
+/// ```toml
+/// [tool.uv.sources]
+/// foo = { git = "https://github.com/acme/foo", branch = "main" }
+/// bar = { path = "../bar", editable = true }
+/// baz = { url = "https://example.com/baz-1.0.0-py3-none-any.whl" }
+/// ```
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub(crate) enum Source {
+    /// A Git repository, optionally at a given commit, tag, or branch.
+    Git {
+        git: String,
+        rev: Option<String>,
+        tag: Option<String>,
+        branch: Option<String>,
+        subdirectory: Option<String>,
+    },
+    /// A remote wheel or source distribution.
+    Url {
+        url: String,
+        subdirectory: Option<String>,
+    },
+    /// A local directory or archive, relative to the project root.
+    Path {
+        path: String,
+        editable: Option<bool>,
+    },
+}
+
+/// The fields of a `[tool.uv.sources]` entry, before determining the kind of [`Source`].
+///
+/// Deserializing the fields of every kind at once (rather than trying each kind in turn, as with
+/// an untagged enum) means that errors can name the offending key.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SourceFields {
+    git: Option<String>,
+    url: Option<String>,
+    path: Option<String>,
+    rev: Option<String>,
+    tag: Option<String>,
+    branch: Option<String>,
+    subdirectory: Option<String>,
+    editable: Option<bool>,
+}
+
+impl<'de> Deserialize<'de> for Source {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let SourceFields {
+            git,
+            url,
+            path,
+            rev,
+            tag,
+            branch,
+            subdirectory,
+            editable,
+        } = SourceFields::deserialize(deserializer)?;
+
+        // Reject any field that doesn't apply to the kind of source.
+        let unsupported = |kind: &str, fields: &[(&str, bool)]| -> Result<(), D::Error> {
+            fields
+                .iter()
+                .find(|(_, present)| *present)
+                .map_or(Ok(()), |(field, _)| {
+                    Err(de::Error::custom(format!(
+                        "`{field}` can't be used with a `{kind}` source"
+                    )))
+                })
+        };
+
+        match (git, url, path) {
+            (Some(git), None, None) => {
+                unsupported("git", &[("editable", editable.is_some())])?;
+                Ok(Self::Git {
+                    git,
+                    rev,
+                    tag,
+                    branch,
+                    subdirectory,
+                })
+            }
+            (None, Some(url), None) => {
+                unsupported(
+                    "url",
+                    &[
+                        ("rev", rev.is_some()),
+                        ("tag", tag.is_some()),
+                        ("branch", branch.is_some()),
+                        ("editable", editable.is_some()),
+                    ],
+                )?;
+                Ok(Self::Url { url, subdirectory })
+            }
+            (None, None, Some(path)) => {
+                unsupported(
+                    "path",
+                    &[
+                        ("rev", rev.is_some()),
+                        ("tag", tag.is_some()),
+                        ("branch", branch.is_some()),
+                        ("subdirectory", subdirectory.is_some()),
+                    ],
+                )?;
+                Ok(Self::Path { path, editable })
+            }
+            (None, None, None) => Err(de::Error::custom("expected one of `git`, `url`, or `path`")),
+            _ => Err(de::Error::custom(
+                "only one of `git`, `url`, or `path` may be specified",
+            )),
+        }
+    }
+}
+
 /// The PEP 621 project metadata, with static requirements extracted in advance.
 #[derive(Debug)]
 pub(crate) struct Pep621Metadata {
@@ -44,6 +204,8 @@ pub(crate) struct Pep621Metadata {
     pub(crate) name: PackageName,
     /// The requirements extracted from the project.
     pub(crate) requirements: Vec<Requirement>,
+    /// The requirements that should be installed as editables, per `[tool.uv.sources]`.
+    pub(crate) editables: Vec<EditableRequirement>,
     /// The extras used to collect requirements.
     pub(crate) used_extras: FxHashSet<ExtraName>,
 }
@@ -52,6 +214,14 @@ pub(crate) struct Pep621Metadata {
 pub(crate) enum Pep621Error {
     #[error(transparent)]
     Pep508(#[from] pep508_rs::Pep508Error),
+    #[error("Failed to parse the source for `{0}` in `tool.uv.sources`")]
+    SourceUrl(PackageName, #[source] url::ParseError),
+    #[error("The source for `{0}` in `tool.uv.sources` may only specify one of `rev`, `tag`, or `branch`")]
+    MultipleGitReferences(PackageName),
+    #[error("The source for `{0}` in `tool.uv.sources` is not a local directory, and so can't be installed as editable")]
+    EditableNotDirectory(PackageName),
+    #[error("Failed to apply `tool.uv.sources` for workspace member: `{}`", _0.user_display())]
+    WorkspaceMember(PathBuf, #[source] Box<Pep621Error>),
 }
 
 impl Pep621Metadata {
@@ -61,9 +231,14 @@ impl Pep621Metadata {
     /// If the project specifies dynamic dependencies, or if the project specifies dynamic optional
     /// dependencies and the extras are requested, the requirements cannot be extracted.
     ///
+    /// Any `[tool.uv.sources]` entries are applied to the extracted requirements, with paths
+    /// resolved relative to `project_root`.
+    ///
     /// Returns an error if the requirements are not valid PEP 508 requirements.
     pub(crate) fn try_from(
         project: Project,
+        sources: &BTreeMap<PackageName, Source>,
+        project_root: &Path,
         extras: &ExtrasSpecification,
     ) -> Result<Option<Self>, Pep621Error> {
         if let Some(dynamic) = project.dynamic.as_ref() {
@@ -120,14 +295,118 @@ impl Pep621Metadata {
             }
         }
 
+        // Apply any `[tool.uv.sources]` overrides.
+        let mut editables = Vec::new();
+        let requirements = requirements
+            .into_iter()
+            .map(|requirement| {
+                let Some(source) = sources.get(&requirement.name) else {
+                    return Ok(requirement);
+                };
+                match source.lower(requirement, project_root)? {
+                    LoweredRequirement::Requirement(requirement) => Ok(requirement),
+                    LoweredRequirement::Editable(requirement, editable) => {
+                        editables.push(editable);
+                        Ok(requirement)
+                    }
+                }
+            })
+            .collect::<Result<Vec<_>, Pep621Error>>()?;
+
         Ok(Some(Self {
             name,
             requirements,
+            editables,
             used_extras,
         }))
     }
 }
 
+/// A requirement to which a [`Source`] has been applied.
+enum LoweredRequirement {
+    /// A requirement with its URL replaced by that of the source.
+    Requirement(Requirement),
+    /// A requirement that should be satisfied by the accompanying editable.
+    Editable(Requirement, EditableRequirement),
+}
+
+impl Source {
+    /// Apply the [`Source`] to a [`Requirement`], replacing any version specifiers or URL.
+    fn lower(
+        &self,
+        mut requirement: Requirement,
+        project_root: &Path,
+    ) -> Result<LoweredRequirement, Pep621Error> {
+        let url = match self {
+            Self::Git {
+                git,
+                rev,
+                tag,
+                branch,
+                subdirectory,
+            } => {
+                let mut references = [rev, tag, branch].into_iter().flatten();
+                let reference = references.next();
+                if references.next().is_some() {
+                    return Err(Pep621Error::MultipleGitReferences(requirement.name));
+                }
+
+                let mut given = if git.starts_with("git+") {
+                    git.clone()
+                } else {
+                    format!("git+{git}")
+                };
+                if let Some(reference) = reference {
+                    given = format!("{given}@{reference}");
+                }
+                if let Some(subdirectory) = subdirectory {
+                    given = format!("{given}#subdirectory={subdirectory}");
+                }
+                VerbatimUrl::parse_url(&given)
+                    .map_err(|err| Pep621Error::SourceUrl(requirement.name.clone(), err))?
+                    .with_given(given)
+            }
+            Self::Url { url, subdirectory } => {
+                let given = if let Some(subdirectory) = subdirectory {
+                    format!("{url}#subdirectory={subdirectory}")
+                } else {
+                    url.clone()
+                };
+                VerbatimUrl::parse_url(&given)
+                    .map_err(|err| Pep621Error::SourceUrl(requirement.name.clone(), err))?
+                    .with_given(given)
+            }
+            Self::Path { path, editable } => {
+                let url = VerbatimUrl::parse_path(path, project_root).with_given(path.clone());
+                if editable.unwrap_or(false) {
+                    let Ok(install_path) = url.to_file_path() else {
+                        return Err(Pep621Error::EditableNotDirectory(requirement.name));
+                    };
+                    if !install_path.is_dir() {
+                        return Err(Pep621Error::EditableNotDirectory(requirement.name));
+                    }
+
+                    // The editable determines the URL, so only the name and markers of the
+                    // requirement are retained.
+                    if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
+                        requirement.version_or_url = None;
+                    }
+                    let editable = EditableRequirement {
+                        url,
+                        extras: requirement.extras.clone(),
+                        path: install_path,
+                    };
+                    return Ok(LoweredRequirement::Editable(requirement, editable));
+                }
+                url
+            }
+        };
+
+        requirement.version_or_url = Some(VersionOrUrl::Url(url));
+        Ok(LoweredRequirement::Requirement(requirement))
+    }
+}
+
 /// Given an extra in a project that may contain references to the project
 /// itself, flatten it into a list of requirements.
 ///
@@ -189,3 +468,69 @@ fn flatten_extra(
         &mut FxHashSet::default(),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use uv_normalize::PackageName;
+
+    use super::Source;
+
+    fn parse(source: &str) -> Result<Source, String> {
+        toml::from_str::<BTreeMap<PackageName, Source>>(&format!("foo = {source}"))
+            .map(|mut sources| sources.pop_first().unwrap().1)
+            .map_err(|err| err.message().to_string())
+    }
+
+    #[test]
+    fn parse_sources() {
+        assert_eq!(
+            parse(r#"{ git = "https://github.com/acme/foo", branch = "main" }"#),
+            Ok(Source::Git {
+                git: "https://github.com/acme/foo".to_string(),
+                rev: None,
+                tag: None,
+                branch: Some("main".to_string()),
+                subdirectory: None,
+            })
+        );
+        assert_eq!(
+            parse(r#"{ url = "https://example.com/foo-1.0.0.tar.gz", subdirectory = "foo" }"#),
+            Ok(Source::Url {
+                url: "https://example.com/foo-1.0.0.tar.gz".to_string(),
+                subdirectory: Some("foo".to_string()),
+            })
+        );
+        assert_eq!(
+            parse(r#"{ path = "../foo", editable = true }"#),
+            Ok(Source::Path {
+                path: "../foo".to_string(),
+                editable: Some(true),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_invalid_sources() {
+        let err = parse(r#"{ git = "https://github.com/acme/foo", brnch = "main" }"#).unwrap_err();
+        assert!(err.starts_with("unknown field `brnch`"), "{err}");
+
+        assert_eq!(
+            parse(r#"{ path = "../foo", tag = "v1.0.0" }"#),
+            Err("`tag` can't be used with a `path` source".to_string())
+        );
+        assert_eq!(
+            parse(r#"{ git = "https://github.com/acme/foo", editable = true }"#),
+            Err("`editable` can't be used with a `git` source".to_string())
+        );
+        assert_eq!(
+            parse(r#"{ git = "https://github.com/acme/foo", path = "../foo" }"#),
+            Err("only one of `git`, `url`, or `path` may be specified".to_string())
+        );
+        assert_eq!(
+            parse(r#"{ branch = "main" }"#),
+            Err("expected one of `git`, `url`, or `path`".to_string())
+        );
+    }
+}
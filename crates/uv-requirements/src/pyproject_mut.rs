@@ -0,0 +1,548 @@
+use std::fmt;
+use std::str::FromStr;
+
+use toml_edit::{Array, DocumentMut, Item, RawString, Table, TomlError, Value};
+
+use pep508_rs::Requirement;
+use pypi_types::LenientRequirement;
+use uv_normalize::{ExtraName, PackageName};
+
+/// A `pyproject.toml` that can be modified in place, retaining its formatting and comments.
+///
+/// Unlike [`crate::pyproject::PyProjectToml`], which is deserialized into a fixed schema, this
+/// operates on the raw TOML document, such that any content that uv doesn't understand is
+/// written back verbatim.
+#[derive(Debug, Clone)]
+pub struct PyProjectTomlMut {
+    doc: DocumentMut,
+}
+
+/// The section of a `pyproject.toml` in which a dependency is declared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DependencyType {
+    /// A dependency in `[project.dependencies]`.
+    Production,
+    /// A dependency in `[project.optional-dependencies]`, under the given extra.
+    Optional(ExtraName),
+    /// A dependency in `[tool.uv.dev-dependencies]`.
+    Dev,
+}
+
+impl fmt::Display for DependencyType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Production => write!(f, "`project.dependencies`"),
+            Self::Optional(extra) => write!(f, "`project.optional-dependencies.{extra}`"),
+            Self::Dev => write!(f, "`tool.uv.dev-dependencies`"),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PyProjectTomlMutError {
+    #[error("Failed to parse `pyproject.toml`")]
+    Parse(#[from] Box<TomlError>),
+    #[error("`pyproject.toml` is missing a `[project]` table")]
+    MissingProject,
+    #[error("Expected {0} to be an array of PEP 508 requirement strings")]
+    MalformedDependencies(DependencyType),
+    #[error("Expected `{0}` to be a table")]
+    MalformedTable(&'static str),
+}
+
+impl PyProjectTomlMut {
+    /// Parse a [`PyProjectTomlMut`] from the raw contents of a `pyproject.toml`.
+    pub fn from_toml(raw: &str) -> Result<Self, PyProjectTomlMutError> {
+        Ok(Self {
+            doc: raw.parse().map_err(Box::new)?,
+        })
+    }
+
+    /// Add a requirement to the given section, creating the section if necessary.
+    ///
+    /// If the section already contains a requirement for the same package with the same markers
+    /// (or a single, unconditional requirement for the package), it's replaced in place;
+    /// otherwise, the requirement is appended. Requirements for the package with other markers
+    /// (e.g., with different bounds for different Python versions) are retained.
+    pub fn add_dependency(
+        &mut self,
+        requirement: &Requirement,
+        dependency_type: &DependencyType,
+    ) -> Result<(), PyProjectTomlMutError> {
+        let dependencies = self.dependencies_mut(dependency_type)?;
+
+        let existing = find_dependencies(&requirement.name, dependencies);
+        let replace = existing
+            .iter()
+            .find(|(_, existing)| existing.marker == requirement.marker)
+            .or_else(|| match existing.as_slice() {
+                [existing] if existing.1.marker.is_none() => Some(existing),
+                _ => None,
+            });
+        if let Some((index, _)) = replace {
+            // Replace the declaration, retaining its formatting.
+            dependencies.replace(*index, requirement.to_string());
+        } else {
+            push_dependency(requirement, dependencies);
+        }
+
+        Ok(())
+    }
+
+    /// Remove all requirements for the given package from the given section.
+    ///
+    /// Returns the removed requirements, which is empty if the package wasn't declared in the
+    /// section (or the section doesn't exist).
+    pub fn remove_dependency(
+        &mut self,
+        name: &PackageName,
+        dependency_type: &DependencyType,
+    ) -> Result<Vec<Requirement>, PyProjectTomlMutError> {
+        let Some(dependencies) = self.existing_dependencies_mut(dependency_type)? else {
+            return Ok(Vec::new());
+        };
+
+        let mut removed = Vec::new();
+        for (index, _) in find_dependencies(name, dependencies).into_iter().rev() {
+            let value = dependencies.remove(index);
+            retain_comment(&value, index, dependencies);
+            if let Some(requirement) = value.as_str().and_then(parse_requirement) {
+                removed.push(requirement);
+            }
+        }
+        removed.reverse();
+
+        Ok(removed)
+    }
+
+    /// Return the name declared in the `[project]` table, if any.
+    pub fn name(&self) -> Option<PackageName> {
+        self.doc
+            .get("project")
+            .and_then(|project| project.get("name"))
+            .and_then(Item::as_str)
+            .and_then(|name| PackageName::from_str(name).ok())
+    }
+
+    /// Return all requirements declared in the project, across its dependencies, all of its
+    /// optional dependencies and its development dependencies.
+    ///
+    /// Entries that aren't valid PEP 508 requirements are skipped.
+    pub fn requirements(&self) -> Vec<(DependencyType, Requirement)> {
+        let mut requirements = Vec::new();
+
+        let project = self.doc.get("project").and_then(Item::as_table_like);
+        if let Some(dependencies) = project
+            .and_then(|project| project.get("dependencies"))
+            .and_then(Item::as_array)
+        {
+            requirements.extend(
+                dependencies
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .filter_map(parse_requirement)
+                    .map(|requirement| (DependencyType::Production, requirement)),
+            );
+        }
+
+        if let Some(optional_dependencies) = project
+            .and_then(|project| project.get("optional-dependencies"))
+            .and_then(Item::as_table_like)
+        {
+            for (extra, dependencies) in optional_dependencies.iter() {
+                let Ok(extra) = ExtraName::from_str(extra) else {
+                    continue;
+                };
+                let Some(dependencies) = dependencies.as_array() else {
+                    continue;
+                };
+                requirements.extend(
+                    dependencies
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .filter_map(parse_requirement)
+                        .map(|requirement| (DependencyType::Optional(extra.clone()), requirement)),
+                );
+            }
+        }
+
+        if let Some(dependencies) = self
+            .doc
+            .get("tool")
+            .and_then(|tool| tool.get("uv"))
+            .and_then(|uv| uv.get("dev-dependencies"))
+            .and_then(Item::as_array)
+        {
+            requirements.extend(
+                dependencies
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .filter_map(parse_requirement)
+                    .map(|requirement| (DependencyType::Dev, requirement)),
+            );
+        }
+
+        requirements
+    }
+
+    /// Return the dependency array for the given section, creating it (and any parent tables) if
+    /// necessary.
+    fn dependencies_mut(
+        &mut self,
+        dependency_type: &DependencyType,
+    ) -> Result<&mut Array, PyProjectTomlMutError> {
+        let item = match dependency_type {
+            DependencyType::Production => self
+                .doc
+                .get_mut("project")
+                .and_then(Item::as_table_like_mut)
+                .ok_or(PyProjectTomlMutError::MissingProject)?
+                .entry("dependencies")
+                .or_insert(Item::Value(Value::Array(Array::new()))),
+            DependencyType::Optional(extra) => self
+                .doc
+                .get_mut("project")
+                .and_then(Item::as_table_like_mut)
+                .ok_or(PyProjectTomlMutError::MissingProject)?
+                .entry("optional-dependencies")
+                .or_insert(Item::Table(Table::new()))
+                .as_table_like_mut()
+                .ok_or(PyProjectTomlMutError::MalformedTable(
+                    "project.optional-dependencies",
+                ))?
+                .entry(extra.as_ref())
+                .or_insert(Item::Value(Value::Array(Array::new()))),
+            DependencyType::Dev => self
+                .doc
+                .entry("tool")
+                .or_insert(implicit_table())
+                .as_table_like_mut()
+                .ok_or(PyProjectTomlMutError::MalformedTable("tool"))?
+                .entry("uv")
+                .or_insert(Item::Table(Table::new()))
+                .as_table_like_mut()
+                .ok_or(PyProjectTomlMutError::MalformedTable("tool.uv"))?
+                .entry("dev-dependencies")
+                .or_insert(Item::Value(Value::Array(Array::new()))),
+        };
+        item.as_array_mut()
+            .ok_or_else(|| PyProjectTomlMutError::MalformedDependencies(dependency_type.clone()))
+    }
+
+    /// Return the dependency array for the given section, if it exists.
+    fn existing_dependencies_mut(
+        &mut self,
+        dependency_type: &DependencyType,
+    ) -> Result<Option<&mut Array>, PyProjectTomlMutError> {
+        let item = match dependency_type {
+            DependencyType::Production => self
+                .doc
+                .get_mut("project")
+                .and_then(Item::as_table_like_mut)
+                .and_then(|project| project.get_mut("dependencies")),
+            DependencyType::Optional(extra) => self
+                .doc
+                .get_mut("project")
+                .and_then(Item::as_table_like_mut)
+                .and_then(|project| project.get_mut("optional-dependencies"))
+                .and_then(Item::as_table_like_mut)
+                .and_then(|extras| extras.get_mut(extra.as_ref())),
+            DependencyType::Dev => self
+                .doc
+                .get_mut("tool")
+                .and_then(Item::as_table_like_mut)
+                .and_then(|tool| tool.get_mut("uv"))
+                .and_then(Item::as_table_like_mut)
+                .and_then(|uv| uv.get_mut("dev-dependencies")),
+        };
+        let Some(item) = item else {
+            return Ok(None);
+        };
+        item.as_array_mut()
+            .map(Some)
+            .ok_or_else(|| PyProjectTomlMutError::MalformedDependencies(dependency_type.clone()))
+    }
+}
+
+impl fmt::Display for PyProjectTomlMut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.doc.fmt(f)
+    }
+}
+
+/// A table that's only rendered through its children, like `tool` in `[tool.uv]`.
+fn implicit_table() -> Item {
+    let mut table = Table::new();
+    table.set_implicit(true);
+    Item::Table(table)
+}
+
+/// Parse a requirement from a dependency array, leniently.
+fn parse_requirement(requirement: &str) -> Option<Requirement> {
+    LenientRequirement::from_str(requirement)
+        .map(Requirement::from)
+        .ok()
+}
+
+/// Return all requirements for the given package in a dependency array, along with their indices.
+fn find_dependencies(name: &PackageName, dependencies: &Array) -> Vec<(usize, Requirement)> {
+    dependencies
+        .iter()
+        .enumerate()
+        .filter_map(|(index, value)| {
+            let requirement = parse_requirement(value.as_str()?)?;
+            (requirement.name == *name).then_some((index, requirement))
+        })
+        .collect()
+}
+
+/// Return the whitespace (and comments) preceding a value, if any.
+fn prefix(value: &Value) -> Option<&str> {
+    value.decor().prefix().and_then(RawString::as_str)
+}
+
+/// Retain the comment on the line of the entry preceding a removed value.
+///
+/// A comment after an entry (e.g., `"anyio",  # Async runtime.`) is parsed as part of the prefix of
+/// the next entry (or the array's trailing whitespace), so it would otherwise be removed along with
+/// the value at `index`.
+fn retain_comment(removed: &Value, index: usize, dependencies: &mut Array) {
+    let Some(comment) = prefix(removed)
+        .and_then(|prefix| prefix.find('\n').map(|newline| &prefix[..newline]))
+        .filter(|comment| !comment.trim().is_empty())
+    else {
+        return;
+    };
+
+    if let Some(next) = dependencies.get_mut(index) {
+        let next_prefix = prefix(next).unwrap_or_default();
+        let next_prefix = format!("{comment}{next_prefix}");
+        next.decor_mut().set_prefix(next_prefix);
+    } else {
+        let trailing = dependencies.trailing().as_str().unwrap_or_default();
+        let trailing = format!("{comment}{trailing}");
+        dependencies.set_trailing(trailing);
+    }
+}
+
+/// Append a requirement to a dependency array, following the array's existing layout.
+///
+/// If the array spans multiple lines, the requirement is placed on its own line, with the same
+/// indentation as the preceding entry and a trailing comma.
+fn push_dependency(requirement: &Requirement, dependencies: &mut Array) {
+    let trailing = dependencies.trailing().as_str().unwrap_or_default();
+
+    let multiline = if dependencies.is_empty() {
+        trailing.contains('\n')
+    } else {
+        dependencies
+            .iter()
+            .any(|value| prefix(value).is_some_and(|prefix| prefix.contains('\n')))
+    };
+
+    if !multiline {
+        dependencies.push(requirement.to_string());
+        return;
+    }
+
+    // Ex) `\n    # Used for the CLI.\n    ` becomes `\n    `.
+    let indent = dependencies
+        .iter()
+        .last()
+        .and_then(prefix)
+        .and_then(|prefix| prefix.rfind('\n').map(|index| &prefix[index..]))
+        .unwrap_or("\n    ");
+
+    // Any comment after the last entry (e.g., `"anyio",  # Pinned.`) is part of the array's
+    // trailing whitespace, so it's moved before the new entry to keep it on the same line.
+    let comment = trailing
+        .rfind('\n')
+        .map_or(trailing, |index| &trailing[..index]);
+
+    let mut value = Value::from(requirement.to_string());
+    value.decor_mut().set_prefix(format!("{comment}{indent}"));
+    dependencies.push_formatted(value);
+    dependencies.set_trailing_comma(true);
+    dependencies.set_trailing("\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use indoc::indoc;
+
+    use pep508_rs::Requirement;
+    use uv_normalize::{ExtraName, PackageName};
+
+    use crate::{DependencyType, PyProjectTomlMut};
+
+    #[test]
+    fn add_dependency_multiline() {
+        let mut pyproject = PyProjectTomlMut::from_toml(indoc! {r#"
+            [project]
+            name = "project"
+            dependencies = [
+                # Used for the CLI.
+                "click>=8",
+                "anyio",  # Pinned by the framework.
+            ]
+        "#})
+        .unwrap();
+
+        pyproject
+            .add_dependency(
+                &Requirement::from_str("httpx>=0.27").unwrap(),
+                &DependencyType::Production,
+            )
+            .unwrap();
+        pyproject
+            .add_dependency(
+                &Requirement::from_str("click>=8.1.7").unwrap(),
+                &DependencyType::Production,
+            )
+            .unwrap();
+
+        assert_eq!(
+            pyproject.to_string(),
+            indoc! {r#"
+            [project]
+            name = "project"
+            dependencies = [
+                # Used for the CLI.
+                "click>=8.1.7",
+                "anyio",  # Pinned by the framework.
+                "httpx>=0.27",
+            ]
+        "#}
+        );
+    }
+
+    #[test]
+    fn add_dependency_marker_split() {
+        let mut pyproject = PyProjectTomlMut::from_toml(indoc! {r#"
+            [project]
+            name = "project"
+            dependencies = [
+                "foo>=1; python_version < '3.9'",
+                "foo>=2; python_version >= '3.9'",
+            ]
+        "#})
+        .unwrap();
+
+        // Only the requirement with the same markers is replaced.
+        pyproject
+            .add_dependency(
+                &Requirement::from_str("foo>=2.1; python_version >= '3.9'").unwrap(),
+                &DependencyType::Production,
+            )
+            .unwrap();
+
+        // A requirement with other markers is appended.
+        pyproject
+            .add_dependency(
+                &Requirement::from_str("foo>=3").unwrap(),
+                &DependencyType::Production,
+            )
+            .unwrap();
+
+        assert_eq!(
+            pyproject.to_string(),
+            indoc! {r#"
+            [project]
+            name = "project"
+            dependencies = [
+                "foo>=1; python_version < '3.9'",
+                "foo>=2.1 ; python_version >= '3.9'",
+                "foo>=3",
+            ]
+        "#}
+        );
+    }
+
+    #[test]
+    fn add_dependency_new_sections() {
+        let mut pyproject = PyProjectTomlMut::from_toml(indoc! {r#"
+            [project]
+            name = "project"
+            dependencies = ["anyio"]
+        "#})
+        .unwrap();
+
+        pyproject
+            .add_dependency(
+                &Requirement::from_str("pytest>=8").unwrap(),
+                &DependencyType::Dev,
+            )
+            .unwrap();
+        pyproject
+            .add_dependency(
+                &Requirement::from_str("rich").unwrap(),
+                &DependencyType::Optional(ExtraName::from_str("cli").unwrap()),
+            )
+            .unwrap();
+        pyproject
+            .add_dependency(
+                &Requirement::from_str("idna").unwrap(),
+                &DependencyType::Production,
+            )
+            .unwrap();
+
+        assert_eq!(
+            pyproject.to_string(),
+            indoc! {r#"
+            [project]
+            name = "project"
+            dependencies = ["anyio", "idna"]
+
+            [project.optional-dependencies]
+            cli = ["rich"]
+
+            [tool.uv]
+            dev-dependencies = ["pytest>=8"]
+        "#}
+        );
+    }
+
+    #[test]
+    fn remove_dependency() {
+        let mut pyproject = PyProjectTomlMut::from_toml(indoc! {r#"
+            [project]
+            name = "project"
+            dependencies = [
+                "anyio",  # Async runtime.
+                "httpx>=0.27",
+                "idna",
+            ]
+        "#})
+        .unwrap();
+
+        let removed = pyproject
+            .remove_dependency(
+                &PackageName::from_str("httpx").unwrap(),
+                &DependencyType::Production,
+            )
+            .unwrap();
+        assert_eq!(removed, vec![Requirement::from_str("httpx>=0.27").unwrap()]);
+
+        let removed = pyproject
+            .remove_dependency(
+                &PackageName::from_str("httpx").unwrap(),
+                &DependencyType::Dev,
+            )
+            .unwrap();
+        assert!(removed.is_empty());
+
+        assert_eq!(
+            pyproject.to_string(),
+            indoc! {r#"
+            [project]
+            name = "project"
+            dependencies = [
+                "anyio",  # Async runtime.
+                "idna",
+            ]
+        "#}
+        );
+    }
+}
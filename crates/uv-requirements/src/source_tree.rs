@@ -29,6 +29,8 @@ pub struct SourceTreeResolver<'a, Context: BuildContext + Send + Sync> {
     index: &'a InMemoryIndex,
     /// The database for fetching and building distributions.
     database: DistributionDatabase<'a, Context>,
+    /// The maximum number of source trees to resolve concurrently.
+    concurrent_downloads: usize,
 }
 
 impl<'a, Context: BuildContext + Send + Sync> SourceTreeResolver<'a, Context> {
@@ -45,6 +47,7 @@ impl<'a, Context: BuildContext + Send + Sync> SourceTreeResolver<'a, Context> {
             extras,
             index,
             database: DistributionDatabase::new(client, context),
+            concurrent_downloads: context.concurrency().downloads,
         }
     }
 
@@ -61,7 +64,7 @@ impl<'a, Context: BuildContext + Send + Sync> SourceTreeResolver<'a, Context> {
     pub async fn resolve(self) -> Result<Vec<Requirement>> {
         let requirements: Vec<_> = futures::stream::iter(self.source_trees.iter())
             .map(|source_tree| async { self.resolve_source_tree(source_tree).await })
-            .buffered(50)
+            .buffered(self.concurrent_downloads)
             .try_collect()
             .await?;
         Ok(requirements.into_iter().flatten().collect())
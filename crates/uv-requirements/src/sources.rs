@@ -1,9 +1,11 @@
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use console::Term;
+use rustc_hash::FxHashSet;
 
 use uv_fs::Simplified;
-use uv_normalize::ExtraName;
+use uv_normalize::{ExtraName, InvalidNameError};
 use uv_warnings::warn_user;
 
 use crate::confirm;
@@ -167,4 +169,157 @@ impl ExtrasSpecification<'_> {
     pub fn is_empty(&self) -> bool {
         matches!(self, ExtrasSpecification::None)
     }
+
+    /// Split the extras specification into forks, such that no fork enables more than one extra
+    /// from any set of [`ConflictingExtras`].
+    ///
+    /// The `available` extras are those provided by the project, and are used to expand
+    /// `--all-extras`.
+    ///
+    /// Returns an empty list if at most one extra from each set of conflicting extras is enabled,
+    /// in which case no forking is required.
+    pub fn forks(
+        &self,
+        available: &FxHashSet<ExtraName>,
+        conflicts: &[ConflictingExtras],
+    ) -> Vec<ExtrasFork> {
+        let mut requested = match self {
+            ExtrasSpecification::None => return Vec::new(),
+            ExtrasSpecification::All => available
+                .iter()
+                .chain(conflicts.iter().flat_map(ConflictingExtras::iter))
+                .cloned()
+                .collect::<Vec<_>>(),
+            ExtrasSpecification::Some(extras) => extras.to_vec(),
+        };
+        requested.sort_unstable();
+        requested.dedup();
+
+        // Determine the sets of conflicting extras that were requested together.
+        let groups = conflicts
+            .iter()
+            .map(|conflict| {
+                conflict
+                    .iter()
+                    .filter(|extra| requested.contains(extra))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|group| group.len() > 1)
+            .collect::<Vec<_>>();
+        if groups.is_empty() {
+            return Vec::new();
+        }
+
+        // Enable all other extras in every fork.
+        let base = requested
+            .iter()
+            .filter(|extra| !groups.iter().flatten().any(|conflict| conflict == extra))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // Select one extra from each group, for every combination of groups.
+        let mut selections = vec![Vec::<ExtraName>::new()];
+        for group in groups {
+            selections = selections
+                .into_iter()
+                .flat_map(|selection| {
+                    group.iter().map(move |extra| {
+                        let mut selection = selection.clone();
+                        if !selection.contains(extra) {
+                            selection.push((*extra).clone());
+                        }
+                        selection
+                    })
+                })
+                .collect();
+        }
+
+        selections
+            .into_iter()
+            .map(|selected| {
+                let mut extras = base.iter().chain(&selected).cloned().collect::<Vec<_>>();
+                extras.sort_unstable();
+                ExtrasFork { extras, selected }
+            })
+            .collect()
+    }
+}
+
+/// A set of extras that are mutually exclusive, and so can't be enabled in the same resolution
+/// (e.g., `cpu` and `cu121` extras that require incompatible builds of the same package).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConflictingExtras(Vec<ExtraName>);
+
+impl ConflictingExtras {
+    /// Iterate over the extras in the set.
+    pub fn iter(&self) -> impl Iterator<Item = &ExtraName> {
+        self.0.iter()
+    }
+}
+
+impl FromStr for ConflictingExtras {
+    type Err = InvalidNameError;
+
+    /// Parse a comma-separated list of extras, e.g., `cpu,cu121`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|extra| ExtraName::from_str(extra.trim()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+/// A fork of a resolution over [`ConflictingExtras`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtrasFork {
+    /// The extras to enable in the fork.
+    pub extras: Vec<ExtraName>,
+    /// The conflicting extras selected for the fork, one from each set of conflicting extras.
+    pub selected: Vec<ExtraName>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rustc_hash::FxHashSet;
+    use uv_normalize::ExtraName;
+
+    use super::{ConflictingExtras, ExtrasFork, ExtrasSpecification};
+
+    fn extras(names: &[&str]) -> Vec<ExtraName> {
+        names
+            .iter()
+            .map(|name| ExtraName::from_str(name).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn forks() {
+        let conflicts = vec![ConflictingExtras::from_str("cpu, cu121").unwrap()];
+        let available = extras(&["cpu", "cu121", "dev"])
+            .into_iter()
+            .collect::<FxHashSet<_>>();
+
+        // Requesting a single conflicting extra doesn't require forking.
+        let requested = extras(&["cpu", "dev"]);
+        assert!(ExtrasSpecification::Some(&requested)
+            .forks(&available, &conflicts)
+            .is_empty());
+
+        // Requesting all extras forks once per conflicting extra.
+        assert_eq!(
+            ExtrasSpecification::All.forks(&available, &conflicts),
+            vec![
+                ExtrasFork {
+                    extras: extras(&["cpu", "dev"]),
+                    selected: extras(&["cpu"]),
+                },
+                ExtrasFork {
+                    extras: extras(&["cu121", "dev"]),
+                    selected: extras(&["cu121"]),
+                },
+            ]
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use rustc_hash::FxHashSet;
@@ -8,13 +8,13 @@ use cache_key::CanonicalUrl;
 use distribution_types::{FlatIndexLocation, IndexUrl};
 use pep508_rs::{Requirement, RequirementsTxtRequirement};
 use requirements_txt::{EditableRequirement, FindLink, RequirementsTxt};
-use uv_client::BaseClientBuilder;
+use uv_client::{BaseClientBuilder, TrustedHost};
 use uv_fs::Simplified;
 use uv_normalize::{ExtraName, PackageName};
 use uv_types::{NoBinary, NoBuild};
 
-use crate::pyproject::{Pep621Metadata, PyProjectToml};
-use crate::{ExtrasSpecification, RequirementsSource};
+use crate::pyproject::{Pep621Error, Pep621Metadata, PyProjectToml};
+use crate::{ExtrasSpecification, RequirementsSource, Workspace};
 
 #[derive(Debug, Default)]
 pub struct RequirementsSpecification {
@@ -44,6 +44,8 @@ pub struct RequirementsSpecification {
     pub no_binary: NoBinary,
     /// The `--no-build` flags to enforce when selecting distributions.
     pub no_build: NoBuild,
+    /// The `--trusted-host` hosts to which insecure connections are allowed.
+    pub trusted_hosts: Vec<TrustedHost>,
 }
 
 impl RequirementsSpecification {
@@ -72,6 +74,7 @@ impl RequirementsSpecification {
                     find_links: vec![],
                     no_binary: NoBinary::default(),
                     no_build: NoBuild::default(),
+                    trusted_hosts: vec![],
                 }
             }
             RequirementsSource::Editable(name) => {
@@ -91,6 +94,7 @@ impl RequirementsSpecification {
                     find_links: vec![],
                     no_binary: NoBinary::default(),
                     no_build: NoBuild::default(),
+                    trusted_hosts: vec![],
                 }
             }
             RequirementsSource::RequirementsTxt(path) => {
@@ -125,6 +129,15 @@ impl RequirementsSpecification {
                         .collect(),
                     no_binary: requirements_txt.no_binary,
                     no_build: requirements_txt.only_binary,
+                    trusted_hosts: requirements_txt
+                        .trusted_hosts
+                        .iter()
+                        .map(|host| {
+                            host.parse::<TrustedHost>().with_context(|| {
+                                format!("Invalid `--trusted-host` in `{}`", path.user_display())
+                            })
+                        })
+                        .collect::<Result<_>>()?,
                 }
             }
             RequirementsSource::PyprojectToml(path) => {
@@ -132,7 +145,29 @@ impl RequirementsSpecification {
                 let pyproject = toml::from_str::<PyProjectToml>(&contents)
                     .with_context(|| format!("Failed to parse `{}`", path.user_display()))?;
 
-                // Attempt to read metadata from the `pyproject.toml` directly.
+                let project_root = fs_err::canonicalize(path)?
+                    .parent()
+                    .map(Path::to_path_buf)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "The file `{}` appears to be a `pyproject.toml` file, which must be in a directory",
+                            path.user_display()
+                        )
+                    })?;
+
+                // Collect any `[tool.uv.sources]` overrides, to apply to the project requirements.
+                let sources = pyproject
+                    .tool
+                    .as_ref()
+                    .and_then(|tool| tool.uv.as_ref())
+                    .and_then(|uv| uv.sources.clone())
+                    .unwrap_or_default();
+
+                // If the project is part of a workspace, resolve all of the workspace members
+                // together, installing each member as an editable. Dependencies between members
+                // are then satisfied by the local projects, rather than by the index.
+                //
+                // Otherwise, attempt to read metadata from the `pyproject.toml` directly.
                 //
                 // If we fail to extract the PEP 621 metadata, fall back to treating it as a source
                 // tree, as there are some cases where the `pyproject.toml` may not be a valid PEP
@@ -142,9 +177,49 @@ impl RequirementsSpecification {
                 // For example, Hatch's "Context formatting" API is not compliant with PEP 621, as
                 // it expects dynamic processing by the build backend for the static metadata
                 // fields. See: https://hatch.pypa.io/latest/config/context/
-                if let Some(project) = pyproject
+                if let Some(workspace) = Workspace::discover(&project_root)? {
+                    // Each member's `[tool.uv.sources]` are applied to its own dependencies, and
+                    // the lowered requirements are added alongside the members.
+                    let (requirements, source_editables) =
+                        workspace.sources(&project_root, extras)?;
+                    let mut editables = workspace.editables(&project_root, extras)?;
+                    let used_extras = editables
+                        .iter()
+                        .flat_map(|editable| editable.extras.iter().cloned())
+                        .collect();
+                    editables.extend(source_editables);
+                    Self {
+                        project: pyproject.project.map(|project| project.name),
+                        requirements: requirements
+                            .into_iter()
+                            .map(RequirementsTxtRequirement::Pep508)
+                            .collect(),
+                        constraints: vec![],
+                        overrides: vec![],
+                        extras: used_extras,
+                        editables,
+                        source_trees: vec![],
+                        index_url: None,
+                        extra_index_urls: vec![],
+                        no_index: false,
+                        find_links: vec![],
+                        no_binary: NoBinary::default(),
+                        no_build: NoBuild::default(),
+                        trusted_hosts: vec![],
+                    }
+                } else if let Some(project) = pyproject
                     .project
-                    .and_then(|project| Pep621Metadata::try_from(project, extras).ok().flatten())
+                    .map(|project| {
+                        Pep621Metadata::try_from(project, &sources, &project_root, extras)
+                    })
+                    .transpose()
+                    .or_else(|err| match err {
+                        // Invalid requirements may still resolve under PEP 517 (see above), but an
+                        // invalid `[tool.uv.sources]` entry is always an error.
+                        Pep621Error::Pep508(_) => Ok(None),
+                        err => Err(err),
+                    })?
+                    .flatten()
                 {
                     Self {
                         project: Some(project.name),
@@ -155,7 +230,7 @@ impl RequirementsSpecification {
                             .collect(),
                         constraints: vec![],
                         overrides: vec![],
-                        editables: vec![],
+                        editables: project.editables,
                         source_trees: vec![],
                         extras: project.used_extras,
                         index_url: None,
@@ -164,22 +239,16 @@ impl RequirementsSpecification {
                         find_links: vec![],
                         no_binary: NoBinary::default(),
                         no_build: NoBuild::default(),
+                        trusted_hosts: vec![],
                     }
                 } else {
-                    let path = fs_err::canonicalize(path)?;
-                    let source_tree = path.parent().ok_or_else(|| {
-                        anyhow::anyhow!(
-                            "The file `{}` appears to be a `pyproject.toml` file, which must be in a directory",
-                            path.user_display()
-                        )
-                    })?;
                     Self {
                         project: None,
                         requirements: vec![],
                         constraints: vec![],
                         overrides: vec![],
                         editables: vec![],
-                        source_trees: vec![source_tree.to_path_buf()],
+                        source_trees: vec![project_root],
                         extras: FxHashSet::default(),
                         index_url: None,
                         extra_index_urls: vec![],
@@ -187,6 +256,7 @@ impl RequirementsSpecification {
                         find_links: vec![],
                         no_binary: NoBinary::default(),
                         no_build: NoBuild::default(),
+                        trusted_hosts: vec![],
                     }
                 }
             }
@@ -212,6 +282,7 @@ impl RequirementsSpecification {
                     find_links: vec![],
                     no_binary: NoBinary::default(),
                     no_build: NoBuild::default(),
+                    trusted_hosts: vec![],
                 }
             }
         })
@@ -259,6 +330,7 @@ impl RequirementsSpecification {
             spec.find_links.extend(source.find_links);
             spec.no_binary.extend(source.no_binary);
             spec.no_build.extend(source.no_build);
+            spec.trusted_hosts.extend(source.trusted_hosts);
         }
 
         // Read all constraints, treating _everything_ as a constraint.
@@ -294,6 +366,7 @@ impl RequirementsSpecification {
             spec.find_links.extend(source.find_links);
             spec.no_binary.extend(source.no_binary);
             spec.no_build.extend(source.no_build);
+            spec.trusted_hosts.extend(source.trusted_hosts);
         }
 
         // Read all overrides, treating both requirements _and_ constraints as overrides.
@@ -329,6 +402,7 @@ impl RequirementsSpecification {
             spec.find_links.extend(source.find_links);
             spec.no_binary.extend(source.no_binary);
             spec.no_build.extend(source.no_build);
+            spec.trusted_hosts.extend(source.trusted_hosts);
         }
 
         Ok(spec)
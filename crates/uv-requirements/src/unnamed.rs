@@ -31,6 +31,8 @@ pub struct NamedRequirementsResolver<'a, Context: BuildContext + Send + Sync> {
     index: &'a InMemoryIndex,
     /// The database for fetching and building distributions.
     database: DistributionDatabase<'a, Context>,
+    /// The maximum number of requirements to resolve concurrently.
+    concurrent_downloads: usize,
 }
 
 impl<'a, Context: BuildContext + Send + Sync> NamedRequirementsResolver<'a, Context> {
@@ -45,6 +47,7 @@ impl<'a, Context: BuildContext + Send + Sync> NamedRequirementsResolver<'a, Cont
             requirements,
             index,
             database: DistributionDatabase::new(client, context),
+            concurrent_downloads: context.concurrency().downloads,
         }
     }
 
@@ -63,6 +66,7 @@ impl<'a, Context: BuildContext + Send + Sync> NamedRequirementsResolver<'a, Cont
             requirements,
             index,
             database,
+            concurrent_downloads,
         } = self;
         futures::stream::iter(requirements)
             .map(|requirement| async {
@@ -73,7 +77,7 @@ impl<'a, Context: BuildContext + Send + Sync> NamedRequirementsResolver<'a, Cont
                     }
                 }
             })
-            .buffered(50)
+            .buffered(concurrent_downloads)
             .try_collect()
             .await
     }
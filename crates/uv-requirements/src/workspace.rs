@@ -0,0 +1,623 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use glob::{glob, GlobError, Pattern, PatternError};
+use tracing::debug;
+
+use pep508_rs::{Requirement, VerbatimUrl};
+use requirements_txt::EditableRequirement;
+use uv_fs::Simplified;
+use uv_normalize::{ExtraName, PackageName};
+use uv_warnings::warn_user;
+
+use crate::pyproject::{
+    Pep621Error, Pep621Metadata, Project, PyProjectToml, Source, ToolUvWorkspace,
+};
+use crate::ExtrasSpecification;
+
+#[derive(thiserror::Error, Debug)]
+pub enum WorkspaceError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Failed to parse `{}`", _0.user_display())]
+    Toml(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Invalid workspace member pattern: `{0}`")]
+    Pattern(String, #[source] PatternError),
+    #[error(transparent)]
+    Glob(#[from] GlobError),
+    #[error("Workspace member `{}` is missing a `pyproject.toml`", _0.user_display())]
+    MissingPyprojectToml(PathBuf),
+    #[error("Workspace member `{}` is missing a `[project]` table in its `pyproject.toml`", _0.user_display())]
+    MissingProject(PathBuf),
+    #[error("Two workspace members are both named `{0}`: `{}` and `{}`", _1.user_display(), _2.user_display())]
+    DuplicatePackage(PackageName, PathBuf, PathBuf),
+}
+
+/// A workspace member, i.e., a project within the workspace.
+#[derive(Debug, Clone)]
+pub struct WorkspaceMember {
+    /// The directory containing the member's `pyproject.toml`.
+    root: PathBuf,
+    /// The extras declared in the member's `project.optional-dependencies`.
+    extras: Vec<ExtraName>,
+    /// The member's `[project]` table.
+    project: Project,
+    /// The member's `[tool.uv.sources]` table.
+    sources: BTreeMap<PackageName, Source>,
+}
+
+impl WorkspaceMember {
+    /// The directory containing the member's `pyproject.toml`.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+}
+
+/// A set of projects that are resolved together and installed into a single, shared environment.
+///
+/// The workspace root is a directory whose `pyproject.toml` contains a `[tool.uv.workspace]`
+/// table, listing the member directories as glob patterns. If the root `pyproject.toml` itself
+/// contains a `[project]` table, the root is a member too.
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    /// The directory containing the workspace's `pyproject.toml`.
+    root: PathBuf,
+    /// The members of the workspace, by package name.
+    members: BTreeMap<PackageName, WorkspaceMember>,
+}
+
+impl Workspace {
+    /// Find the workspace containing the project at the given directory, if any.
+    ///
+    /// Searches the project directory and its ancestors for a `pyproject.toml` with a
+    /// `[tool.uv.workspace]` table. The nearest such workspace is returned, provided the project
+    /// is the workspace root or one of its members.
+    pub fn discover(project_root: &Path) -> Result<Option<Self>, WorkspaceError> {
+        let project_root = fs_err::canonicalize(project_root)?;
+
+        for directory in project_root.ancestors() {
+            let path = directory.join("pyproject.toml");
+            if !path.is_file() {
+                continue;
+            }
+            let contents = fs_err::read_to_string(&path)?;
+            let pyproject = match toml::from_str::<PyProjectToml>(&contents) {
+                Ok(pyproject) => pyproject,
+                // An unrelated `pyproject.toml` in an ancestor directory (e.g., one using a
+                // dynamic `[project]` layout we don't support) shouldn't prevent discovery, so
+                // skip it unless it declares a workspace.
+                Err(err)
+                    if directory != project_root.as_path() && !declares_workspace(&contents) =>
+                {
+                    warn_user!(
+                        "Ignoring `{}` while searching for a workspace, as it could not be parsed",
+                        path.user_display()
+                    );
+                    debug!("Failed to parse `{}`: {err}", path.user_display());
+                    continue;
+                }
+                Err(err) => return Err(WorkspaceError::Toml(path, Box::new(err))),
+            };
+            let Some(workspace) = pyproject
+                .tool
+                .and_then(|tool| tool.uv)
+                .and_then(|uv| uv.workspace)
+            else {
+                continue;
+            };
+
+            let workspace = Self::from_root(directory, pyproject.project.is_some(), &workspace)?;
+            if workspace.root == project_root
+                || workspace
+                    .members
+                    .values()
+                    .any(|member| member.root == project_root)
+            {
+                debug!(
+                    "Found workspace at `{}` with {} member(s)",
+                    workspace.root.user_display(),
+                    workspace.members.len()
+                );
+                return Ok(Some(workspace));
+            }
+
+            debug!(
+                "Project at `{}` is not a member of the workspace at `{}`",
+                project_root.user_display(),
+                workspace.root.user_display()
+            );
+            return Ok(None);
+        }
+
+        Ok(None)
+    }
+
+    /// Collect the members of the workspace rooted at the given directory.
+    fn from_root(
+        root: &Path,
+        is_project: bool,
+        workspace: &ToolUvWorkspace,
+    ) -> Result<Self, WorkspaceError> {
+        let exclude = workspace
+            .exclude
+            .iter()
+            .flatten()
+            .map(|pattern| {
+                let absolute = Pattern::escape(&root.to_string_lossy()) + "/" + pattern;
+                Pattern::new(&absolute).map_err(|err| WorkspaceError::Pattern(pattern.clone(), err))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut directories = Vec::new();
+        if is_project {
+            directories.push(root.to_path_buf());
+        }
+        for pattern in workspace.members.iter().flatten() {
+            let absolute = Pattern::escape(&root.to_string_lossy()) + "/" + pattern;
+            for entry in
+                glob(&absolute).map_err(|err| WorkspaceError::Pattern(pattern.clone(), err))?
+            {
+                let directory = entry?;
+                if !directory.is_dir() {
+                    continue;
+                }
+                if exclude
+                    .iter()
+                    .any(|exclude| exclude.matches_path(&directory))
+                {
+                    debug!("Excluding workspace member: `{}`", directory.user_display());
+                    continue;
+                }
+                directories.push(fs_err::canonicalize(&directory)?);
+            }
+        }
+
+        let mut members = BTreeMap::new();
+        for directory in directories {
+            let path = directory.join("pyproject.toml");
+            if !path.is_file() {
+                return Err(WorkspaceError::MissingPyprojectToml(directory));
+            }
+            let pyproject = read_pyproject(&path)?;
+            let Some(project) = pyproject.project else {
+                return Err(WorkspaceError::MissingProject(directory));
+            };
+            let sources = pyproject
+                .tool
+                .and_then(|tool| tool.uv)
+                .and_then(|uv| uv.sources)
+                .unwrap_or_default();
+            let member = WorkspaceMember {
+                root: directory,
+                extras: project
+                    .optional_dependencies
+                    .as_ref()
+                    .map(|extras| extras.keys().cloned().collect())
+                    .unwrap_or_default(),
+                project: project.clone(),
+                sources,
+            };
+            if let Some(existing) = members.get(&project.name) {
+                // The same directory may be matched by multiple patterns.
+                if existing.root == member.root {
+                    continue;
+                }
+                return Err(WorkspaceError::DuplicatePackage(
+                    project.name,
+                    existing.root.clone(),
+                    member.root,
+                ));
+            }
+            members.insert(project.name, member);
+        }
+
+        Ok(Self {
+            root: fs_err::canonicalize(root)?,
+            members,
+        })
+    }
+
+    /// The directory containing the workspace's `pyproject.toml`.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The members of the workspace, by package name.
+    pub fn members(&self) -> &BTreeMap<PackageName, WorkspaceMember> {
+        &self.members
+    }
+
+    /// Return the workspace members as editable requirements, such that all members are resolved
+    /// together and dependencies between members are satisfied by the local projects.
+    ///
+    /// The requested extras are enabled for the project at `project_root`, if it declares them.
+    pub fn editables(
+        &self,
+        project_root: &Path,
+        extras: &ExtrasSpecification,
+    ) -> Result<Vec<EditableRequirement>, WorkspaceError> {
+        let project_root = fs_err::canonicalize(project_root)?;
+        Ok(self
+            .members
+            .values()
+            .map(|member| {
+                let extras = if member.root == project_root {
+                    member
+                        .extras
+                        .iter()
+                        .filter(|extra| extras.contains(extra))
+                        .cloned()
+                        .collect()
+                } else {
+                    vec![]
+                };
+                let given = match member.root.strip_prefix(uv_fs::CWD.simplified()) {
+                    Ok(relative) if relative.as_os_str().is_empty() => ".".to_string(),
+                    Ok(relative) => format!(".{}{}", std::path::MAIN_SEPARATOR, relative.display()),
+                    Err(_) => member.root.simplified_display().to_string(),
+                };
+                EditableRequirement {
+                    url: VerbatimUrl::from_path(&member.root).with_given(given),
+                    extras,
+                    path: member.root.clone(),
+                }
+            })
+            .collect())
+    }
+
+    /// Apply each member's `[tool.uv.sources]` to its dependencies, returning the lowered
+    /// requirements along with any editables they introduce.
+    ///
+    /// Dependencies on other workspace members are omitted, as the members are already installed
+    /// as editables. Members with dynamic dependencies are skipped, but invalid requirements are
+    /// an error, as the member's sources would otherwise be silently ignored.
+    ///
+    /// The requested extras are enabled for the project at `project_root`, which must be
+    /// canonicalized.
+    pub(crate) fn sources(
+        &self,
+        project_root: &Path,
+        extras: &ExtrasSpecification,
+    ) -> Result<(Vec<Requirement>, Vec<EditableRequirement>), Pep621Error> {
+        let mut requirements = Vec::new();
+        let mut editables = Vec::new();
+        for member in self.members.values() {
+            if member.sources.is_empty() {
+                continue;
+            }
+            let extras = if member.root == project_root {
+                extras
+            } else {
+                &ExtrasSpecification::None
+            };
+            let Some(metadata) = Pep621Metadata::try_from(
+                member.project.clone(),
+                &member.sources,
+                &member.root,
+                extras,
+            )
+            .map_err(|err| Pep621Error::WorkspaceMember(member.root.clone(), Box::new(err)))?
+            else {
+                debug!(
+                    "Unable to apply `tool.uv.sources` for workspace member with dynamic dependencies: `{}`",
+                    member.root.user_display()
+                );
+                continue;
+            };
+            requirements.extend(metadata.requirements.into_iter().filter(|requirement| {
+                member.sources.contains_key(&requirement.name)
+                    && !self.members.contains_key(&requirement.name)
+            }));
+            editables.extend(metadata.editables.into_iter().filter(|editable| {
+                !fs_err::canonicalize(&editable.path)
+                    .is_ok_and(|path| self.members.values().any(|member| member.root == path))
+            }));
+        }
+        Ok((requirements, editables))
+    }
+}
+
+/// Returns `true` if the contents of a `pyproject.toml` declare a `[tool.uv.workspace]` table.
+///
+/// If the contents aren't valid TOML, falls back to searching for the table header.
+fn declares_workspace(contents: &str) -> bool {
+    let Ok(table) = toml::from_str::<toml::Table>(contents) else {
+        return contents.contains("[tool.uv.workspace]");
+    };
+    table
+        .get("tool")
+        .and_then(|tool| tool.get("uv"))
+        .and_then(|uv| uv.get("workspace"))
+        .is_some()
+}
+
+fn read_pyproject(path: &Path) -> Result<PyProjectToml, WorkspaceError> {
+    let contents = fs_err::read_to_string(path)?;
+    toml::from_str(&contents).map_err(|err| WorkspaceError::Toml(path.to_path_buf(), Box::new(err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+    use indoc::indoc;
+
+    use uv_fs::Simplified;
+    use uv_normalize::ExtraName;
+
+    use crate::{ExtrasSpecification, Workspace};
+
+    fn write(root: &Path, path: &str, contents: &str) -> Result<()> {
+        let path = root.join(path);
+        fs_err::create_dir_all(path.parent().unwrap())?;
+        fs_err::write(path, contents)?;
+        Ok(())
+    }
+
+    #[test]
+    fn discover_members() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let root = temp_dir.path();
+        write(
+            root,
+            "pyproject.toml",
+            indoc! {r#"
+                [tool.uv.workspace]
+                members = ["packages/*"]
+                exclude = ["packages/legacy"]
+            "#},
+        )?;
+        write(
+            root,
+            "packages/core/pyproject.toml",
+            indoc! {r#"
+                [project]
+                name = "acme-core"
+                version = "0.1.0"
+
+                [project.optional-dependencies]
+                fast = ["orjson"]
+            "#},
+        )?;
+        write(
+            root,
+            "packages/web/pyproject.toml",
+            indoc! {r#"
+                [project]
+                name = "acme-web"
+                version = "0.1.0"
+                dependencies = ["acme-core"]
+            "#},
+        )?;
+        write(root, "packages/legacy/setup.py", "")?;
+
+        let workspace = Workspace::discover(&root.join("packages/core"))?.unwrap();
+        assert_eq!(workspace.root(), fs_err::canonicalize(root)?);
+        assert_eq!(
+            workspace
+                .members()
+                .keys()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["acme-core", "acme-web"]
+        );
+
+        let extras = vec![ExtraName::new("fast".to_string())?];
+        let editables = workspace.editables(
+            &root.join("packages/core"),
+            &ExtrasSpecification::Some(&extras),
+        )?;
+        assert_eq!(editables.len(), 2);
+        assert_eq!(editables[0].extras, extras);
+        assert!(editables[1].extras.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn member_sources() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let root = temp_dir.path();
+        write(
+            root,
+            "pyproject.toml",
+            indoc! {r#"
+                [tool.uv.workspace]
+                members = ["packages/*"]
+            "#},
+        )?;
+        write(
+            root,
+            "packages/core/pyproject.toml",
+            indoc! {r#"
+                [project]
+                name = "acme-core"
+                version = "0.1.0"
+                dependencies = ["anyio", "tqdm"]
+
+                [project.optional-dependencies]
+                fast = ["orjson"]
+
+                [tool.uv.sources]
+                tqdm = { git = "https://github.com/tqdm/tqdm", tag = "v4.66.2" }
+                orjson = { url = "https://example.com/orjson-3.10.0.tar.gz" }
+            "#},
+        )?;
+        write(
+            root,
+            "packages/web/pyproject.toml",
+            indoc! {r#"
+                [project]
+                name = "acme-web"
+                version = "0.1.0"
+                dependencies = ["acme-core", "helpers"]
+
+                [tool.uv.sources]
+                acme-core = { path = "../core", editable = true }
+                helpers = { path = "../../vendor/helpers", editable = true }
+            "#},
+        )?;
+        write(
+            root,
+            "vendor/helpers/pyproject.toml",
+            indoc! {r#"
+                [project]
+                name = "helpers"
+                version = "0.1.0"
+            "#},
+        )?;
+
+        let project_root = fs_err::canonicalize(root.join("packages/core"))?;
+        let workspace = Workspace::discover(&project_root)?.unwrap();
+
+        // Only dependencies with a source are lowered, and dependencies on other members are
+        // omitted.
+        let (requirements, editables) =
+            workspace.sources(&project_root, &ExtrasSpecification::None)?;
+        assert_eq!(
+            requirements
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["tqdm @ git+https://github.com/tqdm/tqdm@v4.66.2", "helpers"]
+        );
+        assert_eq!(editables.len(), 1);
+        assert_eq!(
+            fs_err::canonicalize(&editables[0].path)?,
+            fs_err::canonicalize(root.join("vendor/helpers"))?
+        );
+
+        // The requested extras are only enabled for the current project.
+        let extras = vec![ExtraName::new("fast".to_string())?];
+        let (requirements, _) =
+            workspace.sources(&project_root, &ExtrasSpecification::Some(&extras))?;
+        assert_eq!(
+            requirements
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "tqdm @ git+https://github.com/tqdm/tqdm@v4.66.2",
+                "orjson @ https://example.com/orjson-3.10.0.tar.gz",
+                "helpers"
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn member_sources_invalid_requirement() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let root = temp_dir.path();
+        write(
+            root,
+            "pyproject.toml",
+            indoc! {r#"
+                [tool.uv.workspace]
+                members = ["packages/*"]
+            "#},
+        )?;
+        write(
+            root,
+            "packages/core/pyproject.toml",
+            indoc! {r#"
+                [project]
+                name = "acme-core"
+                version = "0.1.0"
+                dependencies = ["tqdm[extra"]
+
+                [tool.uv.sources]
+                tqdm = { git = "https://github.com/tqdm/tqdm", tag = "v4.66.2" }
+            "#},
+        )?;
+
+        // The member's sources can't be applied, which is an error rather than falling back to
+        // the index.
+        let project_root = fs_err::canonicalize(root.join("packages/core"))?;
+        let workspace = Workspace::discover(&project_root)?.unwrap();
+        let err = workspace
+            .sources(&project_root, &ExtrasSpecification::None)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Failed to apply `tool.uv.sources` for workspace member: `{}`",
+                project_root.user_display()
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn discover_unparsable_ancestor() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let root = temp_dir.path();
+        write(
+            root,
+            "pyproject.toml",
+            indoc! {r#"
+                [tool.uv.workspace]
+                members = ["packages/*"]
+            "#},
+        )?;
+        // Neither valid TOML, nor a workspace.
+        write(root, "packages/pyproject.toml", "[project\nname = ")?;
+        write(
+            root,
+            "packages/core/pyproject.toml",
+            indoc! {r#"
+                [project]
+                name = "acme-core"
+                version = "0.1.0"
+            "#},
+        )?;
+
+        let workspace = Workspace::discover(&root.join("packages/core"))?.unwrap();
+        assert_eq!(workspace.root(), fs_err::canonicalize(root)?);
+
+        // An unparsable ancestor that declares a workspace is still an error.
+        write(
+            root,
+            "packages/pyproject.toml",
+            indoc! {r#"
+                [project]
+                version = "0.1.0"
+
+                [tool.uv.workspace]
+                members = ["*"]
+            "#},
+        )?;
+        assert!(Workspace::discover(&root.join("packages/core")).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn discover_non_member() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let root = temp_dir.path();
+        write(
+            root,
+            "pyproject.toml",
+            indoc! {r#"
+                [tool.uv.workspace]
+                members = ["packages/*"]
+            "#},
+        )?;
+        write(
+            root,
+            "scripts/pyproject.toml",
+            indoc! {r#"
+                [project]
+                name = "scripts"
+                version = "0.1.0"
+            "#},
+        )?;
+
+        assert!(Workspace::discover(&root.join("scripts"))?.is_none());
+        Ok(())
+    }
+}
@@ -23,7 +23,7 @@ pub(crate) struct CandidateSelector {
 impl CandidateSelector {
     /// Return a [`CandidateSelector`] for the given [`Manifest`].
     pub(crate) fn for_resolution(
-        options: Options,
+        options: &Options,
         manifest: &Manifest,
         markers: &MarkerEnvironment,
     ) -> Self {
@@ -35,6 +35,7 @@ impl CandidateSelector {
             ),
             prerelease_strategy: PreReleaseStrategy::from_mode(
                 options.prerelease_mode,
+                &options.prerelease_packages,
                 manifest,
                 markers,
             ),
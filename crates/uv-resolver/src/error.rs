@@ -4,9 +4,10 @@ use std::ops::Deref;
 
 use dashmap::{DashMap, DashSet};
 use indexmap::IndexMap;
+use itertools::Itertools;
 use pubgrub::range::Range;
 use pubgrub::report::{DefaultStringReporter, DerivationTree, Reporter};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use distribution_types::{
     BuiltDist, IndexLocations, InstalledDist, PathBuiltDist, PathSourceDist, SourceDist,
@@ -18,15 +19,20 @@ use uv_normalize::PackageName;
 
 use crate::candidate_selector::CandidateSelector;
 use crate::dependency_provider::UvDependencyProvider;
-use crate::pubgrub::{PubGrubPackage, PubGrubPython, PubGrubReportFormatter};
+use crate::pubgrub::{
+    NoSolutionReport, PubGrubPackage, PubGrubPython, PubGrubReportFormatter, ReportHint,
+};
 use crate::python_requirement::PythonRequirement;
-use crate::resolver::{UnavailablePackage, VersionsResponse};
+use crate::resolver::{UnavailablePackage, UnavailableVersion, VersionsResponse};
 
 #[derive(Debug, thiserror::Error)]
 pub enum ResolveError {
     #[error("Failed to find a version of {0} that satisfies the requirement")]
     NotFound(Requirement),
 
+    #[error("`{0}` from {1} is missing an upload time, which is required to apply `--exclude-newer` (use `--missing-upload-time` to include or exclude such files instead)")]
+    MissingUploadTime(String, String),
+
     #[error(transparent)]
     Client(#[from] uv_client::Error),
 
@@ -125,6 +131,7 @@ impl From<pubgrub::error::PubGrubError<UvDependencyProvider>> for ResolveError {
                     python_requirement: None,
                     index_locations: None,
                     unavailable_packages: FxHashMap::default(),
+                    unavailable_versions: FxHashMap::default(),
                 })
             }
             pubgrub::error::PubGrubError::SelfDependency { package, version } => {
@@ -146,6 +153,7 @@ pub struct NoSolutionError {
     python_requirement: Option<PythonRequirement>,
     index_locations: Option<IndexLocations>,
     unavailable_packages: FxHashMap<PackageName, UnavailablePackage>,
+    unavailable_versions: FxHashMap<(PackageName, Version), UnavailableVersion>,
 }
 
 impl std::error::Error for NoSolutionError {}
@@ -176,6 +184,50 @@ impl std::fmt::Display for NoSolutionError {
 }
 
 impl NoSolutionError {
+    /// Return a machine-readable report of the resolution failure, including the derivation tree
+    /// and any hints.
+    pub fn report(&self) -> NoSolutionReport {
+        let formatter = PubGrubReportFormatter {
+            available_versions: &self.available_versions,
+            python_requirement: self.python_requirement.as_ref(),
+        };
+        NoSolutionReport {
+            message: DefaultStringReporter::report_with_formatter(
+                &self.derivation_tree,
+                &formatter,
+            ),
+            derivation_tree: formatter.report_node(
+                &self.derivation_tree,
+                &self.unavailable_packages,
+                &self.unavailable_versions,
+            ),
+            hints: formatter
+                .hints(
+                    &self.derivation_tree,
+                    &self.selector,
+                    &self.index_locations,
+                    &self.unavailable_packages,
+                )
+                .iter()
+                .map(ReportHint::from)
+                .collect(),
+        }
+    }
+
+    /// Return the names of the packages involved in the resolution failure, in sorted order.
+    pub fn packages(&self) -> Vec<&PackageName> {
+        self.derivation_tree
+            .packages()
+            .into_iter()
+            .filter_map(|package| match package {
+                PubGrubPackage::Package(name, ..) => Some(name),
+                _ => None,
+            })
+            .sorted()
+            .dedup()
+            .collect()
+    }
+
     /// Update the available versions attached to the error using the given package version index.
     ///
     /// Only packages used in the error's derivation tree will be retrieved.
@@ -261,6 +313,31 @@ impl NoSolutionError {
         self
     }
 
+    /// Update the unavailable versions attached to the error.
+    #[must_use]
+    pub(crate) fn with_unavailable_versions(
+        mut self,
+        unavailable_versions: &DashMap<(PackageName, Version), UnavailableVersion>,
+    ) -> Self {
+        let packages = self
+            .derivation_tree
+            .packages()
+            .into_iter()
+            .filter_map(|package| match package {
+                PubGrubPackage::Package(name, ..) => Some(name),
+                _ => None,
+            })
+            .collect::<FxHashSet<_>>();
+        let mut new = FxHashMap::default();
+        for entry in unavailable_versions.iter() {
+            if packages.contains(&entry.key().0) {
+                new.insert(entry.key().clone(), entry.value().clone());
+            }
+        }
+        self.unavailable_versions = new;
+        self
+    }
+
     /// Update the Python requirements attached to the error.
     #[must_use]
     pub(crate) fn with_python_requirement(
@@ -1,10 +1,15 @@
 pub use dependency_mode::DependencyMode;
-pub use error::ResolveError;
+pub use error::{NoSolutionError, ResolveError};
 pub use exclusions::Exclusions;
 pub use manifest::Manifest;
+pub use missing_upload_time::MissingUploadTime;
 pub use options::{Options, OptionsBuilder};
 pub use preferences::{Preference, PreferenceError};
 pub use prerelease_mode::PreReleaseMode;
+pub use pubgrub::{
+    HintKind, IncompatibilityKind, NoSolutionReport, ReportHint, ReportIncompatibility, ReportNode,
+    ReportPackageRange,
+};
 pub use python_requirement::PythonRequirement;
 pub use resolution::{AnnotationStyle, Diagnostic, DisplayResolutionGraph, ResolutionGraph};
 pub use resolution_mode::ResolutionMode;
@@ -13,7 +18,9 @@ pub use resolver::{
     Reporter as ResolverReporter, Resolver, ResolverProvider, VersionsResponse,
     WheelMetadataResult,
 };
+pub use stats::{IndexStatsReport, PackageStatsReport, ResolverStats, ResolverStatsReport};
 pub use version_map::VersionMap;
+pub use why::{RejectionReason, Why, WhyEdge};
 pub use yanks::AllowedYanks;
 
 mod bare;
@@ -25,6 +32,7 @@ mod editables;
 mod error;
 mod exclusions;
 mod manifest;
+mod missing_upload_time;
 mod options;
 mod pins;
 mod preferences;
@@ -35,5 +43,7 @@ mod redirect;
 mod resolution;
 mod resolution_mode;
 mod resolver;
+mod stats;
 mod version_map;
+mod why;
 mod yanks;
@@ -1,3 +1,5 @@
+use rustc_hash::FxHashSet;
+
 use distribution_types::LocalEditable;
 use pep508_rs::{MarkerEnvironment, Requirement};
 use pypi_types::Metadata23;
@@ -18,6 +20,12 @@ pub struct Manifest {
     /// The overrides for the project.
     pub(crate) overrides: Overrides,
 
+    /// The packages to exclude from the resolution entirely.
+    ///
+    /// Any requirement on an excluded package is dropped, whether it's declared by the project
+    /// itself or by any of its dependencies.
+    pub(crate) excludes: FxHashSet<PackageName>,
+
     /// The preferences for the project.
     ///
     /// These represent "preferred" versions of a given package. For example, they may be the
@@ -54,6 +62,7 @@ impl Manifest {
         requirements: Vec<Requirement>,
         constraints: Constraints,
         overrides: Overrides,
+        excludes: Vec<PackageName>,
         preferences: Vec<Preference>,
         project: Option<PackageName>,
         editables: Vec<(LocalEditable, Metadata23)>,
@@ -64,6 +73,7 @@ impl Manifest {
             requirements,
             constraints,
             overrides,
+            excludes: excludes.into_iter().collect(),
             preferences,
             project,
             editables,
@@ -77,6 +87,7 @@ impl Manifest {
             requirements,
             constraints: Constraints::default(),
             overrides: Overrides::default(),
+            excludes: FxHashSet::default(),
             preferences: Vec::new(),
             project: None,
             editables: Vec::new(),
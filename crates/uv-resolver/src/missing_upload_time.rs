@@ -0,0 +1,15 @@
+/// The behavior when `--exclude-newer` is provided, but a distribution is missing an upload time
+/// (e.g., because the index only serves the HTML Simple API, which predates PEP 700).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum MissingUploadTime {
+    /// Fail the resolution.
+    Error,
+
+    /// Exclude the distribution from the resolution, and warn the user.
+    #[default]
+    Warn,
+
+    /// Treat the distribution as eligible, as if it were uploaded before the cutoff.
+    Include,
+}
@@ -1,14 +1,20 @@
 use chrono::{DateTime, Utc};
+use rustc_hash::FxHashMap;
 
-use crate::{DependencyMode, PreReleaseMode, ResolutionMode};
+use uv_normalize::PackageName;
+
+use crate::{DependencyMode, MissingUploadTime, PreReleaseMode, ResolutionMode};
 
 /// Options for resolving a manifest.
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct Options {
     pub resolution_mode: ResolutionMode,
     pub prerelease_mode: PreReleaseMode,
+    pub prerelease_packages: Vec<PackageName>,
     pub dependency_mode: DependencyMode,
     pub exclude_newer: Option<DateTime<Utc>>,
+    pub exclude_newer_package: FxHashMap<PackageName, Option<DateTime<Utc>>>,
+    pub missing_upload_time: MissingUploadTime,
 }
 
 /// Builder for [`Options`].
@@ -16,8 +22,11 @@ pub struct Options {
 pub struct OptionsBuilder {
     resolution_mode: ResolutionMode,
     prerelease_mode: PreReleaseMode,
+    prerelease_packages: Vec<PackageName>,
     dependency_mode: DependencyMode,
     exclude_newer: Option<DateTime<Utc>>,
+    exclude_newer_package: FxHashMap<PackageName, Option<DateTime<Utc>>>,
+    missing_upload_time: MissingUploadTime,
 }
 
 impl OptionsBuilder {
@@ -40,6 +49,14 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the packages for which pre-release versions are allowed, regardless of the
+    /// [`PreReleaseMode`].
+    #[must_use]
+    pub fn prerelease_packages(mut self, prerelease_packages: Vec<PackageName>) -> Self {
+        self.prerelease_packages = prerelease_packages;
+        self
+    }
+
     /// Sets the dependency mode.
     #[must_use]
     pub fn dependency_mode(mut self, dependency_mode: DependencyMode) -> Self {
@@ -54,13 +71,34 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the per-package exclusion dates, which take precedence over the global exclusion
+    /// date. A `None` date disables the exclusion for the package.
+    #[must_use]
+    pub fn exclude_newer_package(
+        mut self,
+        exclude_newer_package: FxHashMap<PackageName, Option<DateTime<Utc>>>,
+    ) -> Self {
+        self.exclude_newer_package = exclude_newer_package;
+        self
+    }
+
+    /// Sets the [`MissingUploadTime`] behavior.
+    #[must_use]
+    pub fn missing_upload_time(mut self, missing_upload_time: MissingUploadTime) -> Self {
+        self.missing_upload_time = missing_upload_time;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
             resolution_mode: self.resolution_mode,
             prerelease_mode: self.prerelease_mode,
+            prerelease_packages: self.prerelease_packages,
             dependency_mode: self.dependency_mode,
             exclude_newer: self.exclude_newer,
+            exclude_newer_package: self.exclude_newer_package,
+            missing_upload_time: self.missing_upload_time,
         }
     }
 }
@@ -9,7 +9,7 @@ use pep508_rs::{
 use pypi_types::{HashError, Hashes};
 use requirements_txt::RequirementEntry;
 use tracing::trace;
-use uv_normalize::PackageName;
+use uv_normalize::{ExtraName, PackageName};
 
 #[derive(thiserror::Error, Debug)]
 pub enum PreferenceError {
@@ -62,6 +62,25 @@ impl Preference {
     pub fn requirement(&self) -> &Requirement {
         &self.requirement
     }
+
+    /// Return the [`Preference`] with its markers removed, if they evaluate to `true` for the given
+    /// environment and extras.
+    ///
+    /// Used to carry pins that are gated by `extra == "..."` markers (e.g., from a forked
+    /// resolution) into a resolution for the corresponding extras.
+    #[must_use]
+    pub fn for_extras(self, markers: &MarkerEnvironment, extras: &[ExtraName]) -> Option<Self> {
+        if !self.requirement.evaluate_markers(markers, extras) {
+            return None;
+        }
+        Some(Self {
+            requirement: Requirement {
+                marker: None,
+                ..self.requirement
+            },
+            hashes: self.hashes,
+        })
+    }
 }
 
 /// A set of pinned packages that should be preserved during resolution, if possible.
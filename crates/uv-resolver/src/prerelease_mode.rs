@@ -41,67 +41,67 @@ pub(crate) enum PreReleaseStrategy {
     IfNecessary,
 
     /// Allow pre-release versions for first-party packages with explicit pre-release markers in
-    /// their version requirements.
+    /// their version requirements, and for any packages named via `--prerelease-package`.
     Explicit(FxHashSet<PackageName>),
 
     /// Allow pre-release versions if all versions of a package are pre-release, or if the package
-    /// has an explicit pre-release marker in its version requirements.
+    /// has an explicit pre-release marker in its version requirements, or if the package was
+    /// named via `--prerelease-package`.
     IfNecessaryOrExplicit(FxHashSet<PackageName>),
 }
 
 impl PreReleaseStrategy {
     pub(crate) fn from_mode(
         mode: PreReleaseMode,
+        packages: &[PackageName],
         manifest: &Manifest,
         markers: &MarkerEnvironment,
     ) -> Self {
         match mode {
-            PreReleaseMode::Disallow => Self::Disallow,
+            // Pre-releases for the named packages, and nothing else, is exactly `explicit`.
+            PreReleaseMode::Disallow if packages.is_empty() => Self::Disallow,
+            PreReleaseMode::Disallow => Self::Explicit(packages.iter().cloned().collect()),
             PreReleaseMode::Allow => Self::Allow,
-            PreReleaseMode::IfNecessary => Self::IfNecessary,
+            PreReleaseMode::IfNecessary if packages.is_empty() => Self::IfNecessary,
+            PreReleaseMode::IfNecessary => {
+                Self::IfNecessaryOrExplicit(packages.iter().cloned().collect())
+            }
             PreReleaseMode::Explicit => Self::Explicit(
-                manifest
-                    .requirements(markers)
-                    .filter(|requirement| {
-                        let Some(version_or_url) = &requirement.version_or_url else {
-                            return false;
-                        };
-                        let version_specifiers = match version_or_url {
-                            VersionOrUrl::VersionSpecifier(version_specifiers) => {
-                                version_specifiers
-                            }
-                            VersionOrUrl::Url(_) => return false,
-                        };
-                        version_specifiers
-                            .iter()
-                            .any(pep440_rs::VersionSpecifier::any_prerelease)
-                    })
-                    .map(|requirement| requirement.name.clone())
+                Self::explicit_packages(manifest, markers)
+                    .chain(packages.iter().cloned())
                     .collect(),
             ),
             PreReleaseMode::IfNecessaryOrExplicit => Self::IfNecessaryOrExplicit(
-                manifest
-                    .requirements(markers)
-                    .filter(|requirement| {
-                        let Some(version_or_url) = &requirement.version_or_url else {
-                            return false;
-                        };
-                        let version_specifiers = match version_or_url {
-                            VersionOrUrl::VersionSpecifier(version_specifiers) => {
-                                version_specifiers
-                            }
-                            VersionOrUrl::Url(_) => return false,
-                        };
-                        version_specifiers
-                            .iter()
-                            .any(pep440_rs::VersionSpecifier::any_prerelease)
-                    })
-                    .map(|requirement| requirement.name.clone())
+                Self::explicit_packages(manifest, markers)
+                    .chain(packages.iter().cloned())
                     .collect(),
             ),
         }
     }
 
+    /// Return the names of the direct dependencies with pre-release markers in their version
+    /// requirements.
+    fn explicit_packages<'a>(
+        manifest: &'a Manifest,
+        markers: &'a MarkerEnvironment,
+    ) -> impl Iterator<Item = PackageName> + 'a {
+        manifest
+            .requirements(markers)
+            .filter(|requirement| {
+                let Some(version_or_url) = &requirement.version_or_url else {
+                    return false;
+                };
+                let version_specifiers = match version_or_url {
+                    VersionOrUrl::VersionSpecifier(version_specifiers) => version_specifiers,
+                    VersionOrUrl::Url(_) => return false,
+                };
+                version_specifiers
+                    .iter()
+                    .any(pep440_rs::VersionSpecifier::any_prerelease)
+            })
+            .map(|requirement| requirement.name.clone())
+    }
+
     /// Returns `true` if a [`PackageName`] is allowed to have pre-release versions.
     pub(crate) fn allows(&self, package: &PackageName) -> bool {
         match self {
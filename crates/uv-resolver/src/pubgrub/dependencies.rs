@@ -1,5 +1,6 @@
 use itertools::Itertools;
 use pubgrub::range::Range;
+use rustc_hash::FxHashSet;
 use tracing::warn;
 
 use distribution_types::Verbatim;
@@ -23,6 +24,7 @@ impl PubGrubDependencies {
         requirements: &[Requirement],
         constraints: &Constraints,
         overrides: &Overrides,
+        excludes: &FxHashSet<PackageName>,
         source_name: Option<&PackageName>,
         source_extra: Option<&ExtraName>,
         urls: &Urls,
@@ -33,6 +35,11 @@ impl PubGrubDependencies {
 
         // Iterate over all declared requirements.
         for requirement in overrides.apply(requirements) {
+            // If the package was excluded, drop the requirement entirely.
+            if excludes.contains(&requirement.name) {
+                continue;
+            }
+
             // If the requirement isn't relevant for the current platform, skip it.
             if let Some(extra) = source_extra {
                 if !requirement.evaluate_markers(env, std::slice::from_ref(extra)) {
@@ -3,7 +3,11 @@ pub(crate) use crate::pubgrub::distribution::PubGrubDistribution;
 pub(crate) use crate::pubgrub::package::{PubGrubPackage, PubGrubPython};
 pub(crate) use crate::pubgrub::priority::{PubGrubPriorities, PubGrubPriority};
 pub(crate) use crate::pubgrub::report::PubGrubReportFormatter;
-pub(crate) use crate::pubgrub::specifier::PubGrubSpecifier;
+pub(crate) use crate::pubgrub::specifier::{specifiers, PubGrubSpecifier};
+pub use crate::pubgrub::structured::{
+    HintKind, IncompatibilityKind, NoSolutionReport, ReportHint, ReportIncompatibility, ReportNode,
+    ReportPackageRange,
+};
 
 mod dependencies;
 mod distribution;
@@ -11,3 +15,4 @@ mod package;
 mod priority;
 mod report;
 mod specifier;
+mod structured;
@@ -16,6 +16,13 @@ impl PubGrubPriorities {
         self.0.entry(package).or_insert(priority);
     }
 
+    /// Iterate over the packages in the priority map, along with their position in the queue.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&PackageName, usize)> {
+        self.0
+            .iter()
+            .map(|(package, priority)| (package, *priority))
+    }
+
     /// Return the priority of the given package, if it exists.
     pub(crate) fn get(&self, package: &PubGrubPackage) -> Option<PubGrubPriority> {
         match package {
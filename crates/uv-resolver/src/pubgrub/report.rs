@@ -320,7 +320,7 @@ impl ReportFormatter<PubGrubPackage, Range<Version>> for PubGrubReportFormatter<
 
 impl PubGrubReportFormatter<'_> {
     /// Simplify a [`Range`] of versions using the available versions for a package.
-    fn simplify_set<'a>(
+    pub(super) fn simplify_set<'a>(
         &self,
         set: &'a Range<Version>,
         package: &PubGrubPackage,
@@ -381,14 +381,19 @@ impl PubGrubReportFormatter<'_> {
                             }
                         } else if let Some(version) =
                             self.available_versions.get(package).and_then(|versions| {
-                                versions
-                                    .iter()
-                                    .rev()
-                                    .filter(|version| version.any_prerelease())
-                                    .find(|version| set.contains(version))
+                                // Enabling pre-releases only resolves the conflict if every
+                                // available version in the range is a pre-release.
+                                let mut matching =
+                                    versions.iter().filter(|version| set.contains(version));
+                                if matching.clone().all(Version::any_prerelease) {
+                                    matching.next_back()
+                                } else {
+                                    None
+                                }
                             })
                         {
-                            // There are pre-release versions available for the package.
+                            // Only pre-release versions are available for the package in the
+                            // requested range.
                             if !allowed_prerelease(package, selector) {
                                 hints.insert(PubGrubHint::PreReleaseAvailable {
                                     package: package.clone(),
@@ -466,27 +471,43 @@ pub(crate) enum PubGrubHint {
     Offline,
 }
 
+/// The command-line flag(s) that would enable pre-releases for a given package.
+struct PreReleaseFlag<'a>(&'a PubGrubPackage);
+
+impl std::fmt::Display for PreReleaseFlag<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            PubGrubPackage::Package(name, ..) => {
+                write!(f, "`--prerelease-package {name}` or `--prerelease=allow`")
+            }
+            _ => write!(f, "`--prerelease=allow`"),
+        }
+    }
+}
+
 impl std::fmt::Display for PubGrubHint {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::PreReleaseAvailable { package, version } => {
                 write!(
                     f,
-                    "{}{} Pre-releases are available for {} in the requested range (e.g., {}), but pre-releases weren't enabled (try: `--prerelease=allow`)",
+                    "{}{} Pre-releases are available for {} in the requested range (e.g., {}), but pre-releases weren't enabled (try: {})",
                     "hint".bold().cyan(),
                     ":".bold(),
                     package.bold(),
-                    version.bold()
+                    version.bold(),
+                    PreReleaseFlag(package)
                 )
             }
             Self::PreReleaseRequested { package, range } => {
                 write!(
                     f,
-                    "{}{} {} was requested with a pre-release marker (e.g., {}), but pre-releases weren't enabled (try: `--prerelease=allow`)",
+                    "{}{} {} was requested with a pre-release marker (e.g., {}), but pre-releases weren't enabled (try: {})",
                     "hint".bold().cyan(),
                     ":".bold(),
                     package.bold(),
-                    PackageRange::compatibility(package, range).bold()
+                    PackageRange::compatibility(package, range).bold(),
+                    PreReleaseFlag(package)
                 )
             }
             Self::NoIndex => {
@@ -740,3 +761,90 @@ fn fmt_package(package: &PubGrubPackage) -> String {
         _ => format!("{package}"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::str::FromStr;
+
+    use indexmap::IndexMap;
+    use pep440_rs::Version;
+    use pep508_rs::{MarkerEnvironment, StringVersion};
+    use pubgrub::range::Range;
+    use pubgrub::report::{DerivationTree, External};
+    use rustc_hash::FxHashMap;
+
+    use uv_normalize::PackageName;
+
+    use crate::candidate_selector::CandidateSelector;
+    use crate::pubgrub::PubGrubPackage;
+    use crate::{Manifest, Options};
+
+    use super::{PubGrubHint, PubGrubReportFormatter};
+
+    /// Return the hints for a package for which no versions in `range` were found, given the
+    /// versions that were available.
+    fn hints(available: &[&str], range: Range<Version>) -> Vec<PubGrubHint> {
+        let package = PubGrubPackage::Package(PackageName::from_str("foo").unwrap(), None, None);
+        let available_versions = IndexMap::from_iter([(
+            package.clone(),
+            available
+                .iter()
+                .map(|version| Version::from_str(version).unwrap())
+                .collect::<BTreeSet<_>>(),
+        )]);
+        let formatter = PubGrubReportFormatter {
+            available_versions: &available_versions,
+            python_requirement: None,
+        };
+        let markers = MarkerEnvironment {
+            implementation_name: "cpython".to_string(),
+            implementation_version: StringVersion::from_str("3.12.1").unwrap(),
+            os_name: "posix".to_string(),
+            platform_machine: "x86_64".to_string(),
+            platform_python_implementation: "CPython".to_string(),
+            platform_release: "6.5.0".to_string(),
+            platform_system: "Linux".to_string(),
+            platform_version: "#1 SMP".to_string(),
+            python_full_version: StringVersion::from_str("3.12.1").unwrap(),
+            python_version: StringVersion::from_str("3.12").unwrap(),
+            sys_platform: "linux".to_string(),
+        };
+        let selector = CandidateSelector::for_resolution(
+            &Options::default(),
+            &Manifest::simple(vec![]),
+            &markers,
+        );
+        formatter
+            .hints(
+                &DerivationTree::External(External::NoVersions(package, range, None)),
+                &Some(selector),
+                &None,
+                &FxHashMap::default(),
+            )
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn prerelease_only_range() {
+        let hints = hints(
+            &["0.1.0", "1.0.0a1"],
+            Range::strictly_higher_than(Version::from_str("0.1.0").unwrap()),
+        );
+        assert!(matches!(
+            hints.as_slice(),
+            [PubGrubHint::PreReleaseAvailable { version, .. }] if version.to_string() == "1.0.0a1"
+        ));
+    }
+
+    #[test]
+    fn prerelease_and_stable_range() {
+        // A stable version in the range was available, so enabling pre-releases wouldn't help.
+        let hints = hints(
+            &["0.1.0", "1.0.0a1", "1.0.0"],
+            Range::strictly_higher_than(Version::from_str("0.1.0").unwrap()),
+        );
+        assert!(hints.is_empty());
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::Bound;
+
 use anyhow::Result;
 use pubgrub::range::Range;
 
@@ -114,3 +116,27 @@ impl TryFrom<&VersionSpecifier> for PubGrubSpecifier {
         Ok(Self(ranges))
     }
 }
+
+/// Format a [`Range`] as a list of PEP 440 specifiers, one per disjoint segment.
+pub(crate) fn specifiers(range: &Range<Version>) -> Vec<String> {
+    range
+        .iter()
+        .map(|segment| match segment {
+            (Bound::Unbounded, Bound::Unbounded) => String::new(),
+            (Bound::Unbounded, Bound::Included(v)) => format!("<={v}"),
+            (Bound::Unbounded, Bound::Excluded(v)) => format!("<{v}"),
+            (Bound::Included(v), Bound::Unbounded) => format!(">={v}"),
+            (Bound::Included(v), Bound::Included(b)) => {
+                if v == b {
+                    format!("=={v}")
+                } else {
+                    format!(">={v},<={b}")
+                }
+            }
+            (Bound::Included(v), Bound::Excluded(b)) => format!(">={v},<{b}"),
+            (Bound::Excluded(v), Bound::Unbounded) => format!(">{v}"),
+            (Bound::Excluded(v), Bound::Included(b)) => format!(">{v},<={b}"),
+            (Bound::Excluded(v), Bound::Excluded(b)) => format!(">{v},<{b}"),
+        })
+        .collect()
+}
@@ -0,0 +1,372 @@
+use pep440_rs::Version;
+use pubgrub::range::Range;
+use pubgrub::report::{DerivationTree, External, ReportFormatter};
+use pubgrub::term::Term;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use distribution_types::{IncompatibleDist, IncompatibleSource, IncompatibleWheel};
+use uv_normalize::PackageName;
+
+use crate::pubgrub::report::{PubGrubHint, PubGrubReportFormatter};
+use crate::pubgrub::{specifiers, PubGrubPackage};
+use crate::resolver::{UnavailablePackage, UnavailableVersion};
+
+/// A machine-readable report of a resolution failure, with the same derivation tree and hints as
+/// the human-readable [`NoSolutionError`](crate::NoSolutionError).
+#[derive(Debug, Clone, Serialize)]
+pub struct NoSolutionReport {
+    /// The human-readable explanation of the failure, excluding hints.
+    pub message: String,
+    /// The derivation tree explaining why the requirements are unsatisfiable.
+    pub derivation_tree: ReportNode,
+    /// Hints for resolving the failure.
+    pub hints: Vec<ReportHint>,
+}
+
+/// A node in the derivation tree of a resolution failure.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum ReportNode {
+    /// An incompatibility that stems directly from the requirements or the available packages.
+    External(ReportIncompatibility),
+    /// An incompatibility derived from two other incompatibilities.
+    Derived {
+        /// The package ranges that can't be selected together.
+        terms: Vec<ReportPackageRange>,
+        cause1: Box<ReportNode>,
+        cause2: Box<ReportNode>,
+    },
+}
+
+/// An incompatibility that stems directly from the requirements or the available packages.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportIncompatibility {
+    /// The kind of incompatibility.
+    pub kind: IncompatibilityKind,
+    /// The package to which the incompatibility applies, e.g., `flask` or `flask[async]`.
+    pub package: String,
+    /// The versions of the package to which the incompatibility applies.
+    pub range: Vec<String>,
+    /// For dependencies, the package and versions that are depended upon.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependency: Option<ReportPackageRange>,
+    /// For unavailable packages and versions, the reason that they couldn't be used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// The human-readable description of the incompatibility.
+    pub message: String,
+}
+
+/// A package, along with a range of its versions.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportPackageRange {
+    /// The package, e.g., `flask` or `flask[async]`.
+    pub package: String,
+    /// The range of versions, as a list of disjoint PEP 440 specifiers (e.g., `>=1.0.0,<2.0.0`).
+    /// An empty specifier matches all versions.
+    pub range: Vec<String>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IncompatibilityKind {
+    /// The root package, i.e., the requirements themselves, must be selected.
+    NotRoot,
+    /// A package depends on another package.
+    Dependency,
+    /// No versions of the package satisfy the range.
+    NoVersions,
+    /// The package or version can't be used, e.g., because it wasn't found in the registry or its
+    /// metadata is invalid.
+    Unavailable,
+    /// The package requires a different Python version.
+    RequiresPython,
+    /// The version was yanked.
+    Yanked,
+    /// The package wasn't found in the cache, and network access was disabled.
+    Offline,
+}
+
+/// A hint for resolving a resolution failure.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportHint {
+    /// The kind of hint.
+    pub kind: HintKind,
+    /// The package to which the hint applies, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    /// The human-readable hint.
+    pub message: String,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HintKind {
+    /// Pre-release versions are available for a package, but pre-releases weren't enabled.
+    PreReleaseAvailable,
+    /// A requirement included a pre-release marker, but pre-releases weren't enabled.
+    PreReleaseRequested,
+    /// Packages were unavailable because index lookups were disabled.
+    NoIndex,
+    /// Packages were unavailable because network access was disabled.
+    Offline,
+}
+
+impl From<&PubGrubHint> for ReportHint {
+    fn from(hint: &PubGrubHint) -> Self {
+        let message = anstream::adapter::strip_str(&hint.to_string()).to_string();
+        let message = message
+            .strip_prefix("hint: ")
+            .map(ToString::to_string)
+            .unwrap_or(message);
+        let (kind, package) = match hint {
+            PubGrubHint::PreReleaseAvailable { package, .. } => {
+                (HintKind::PreReleaseAvailable, Some(package.to_string()))
+            }
+            PubGrubHint::PreReleaseRequested { package, .. } => {
+                (HintKind::PreReleaseRequested, Some(package.to_string()))
+            }
+            PubGrubHint::NoIndex => (HintKind::NoIndex, None),
+            PubGrubHint::Offline => (HintKind::Offline, None),
+        };
+        Self {
+            kind,
+            package,
+            message,
+        }
+    }
+}
+
+impl ReportPackageRange {
+    fn new(package: &PubGrubPackage, range: &Range<Version>) -> Self {
+        Self {
+            package: package.to_string(),
+            range: specifiers(range),
+        }
+    }
+}
+
+impl PubGrubReportFormatter<'_> {
+    /// Convert a derivation tree into a [`ReportNode`].
+    pub(crate) fn report_node(
+        &self,
+        derivation_tree: &DerivationTree<PubGrubPackage, Range<Version>>,
+        unavailable_packages: &FxHashMap<PackageName, UnavailablePackage>,
+        unavailable_versions: &FxHashMap<(PackageName, Version), UnavailableVersion>,
+    ) -> ReportNode {
+        match derivation_tree {
+            DerivationTree::External(external) => ReportNode::External(self.report_external(
+                external,
+                unavailable_packages,
+                unavailable_versions,
+            )),
+            DerivationTree::Derived(derived) => ReportNode::Derived {
+                terms: derived
+                    .terms
+                    .iter()
+                    .map(|(package, term)| match term {
+                        Term::Positive(range) => {
+                            ReportPackageRange::new(package, &self.simplify_set(range, package))
+                        }
+                        Term::Negative(range) => ReportPackageRange::new(
+                            package,
+                            &self.simplify_set(range, package).complement(),
+                        ),
+                    })
+                    .collect(),
+                cause1: Box::new(self.report_node(
+                    &derived.cause1,
+                    unavailable_packages,
+                    unavailable_versions,
+                )),
+                cause2: Box::new(self.report_node(
+                    &derived.cause2,
+                    unavailable_packages,
+                    unavailable_versions,
+                )),
+            },
+        }
+    }
+
+    /// Convert an external incompatibility into a [`ReportIncompatibility`].
+    fn report_external(
+        &self,
+        external: &External<PubGrubPackage, Range<Version>>,
+        unavailable_packages: &FxHashMap<PackageName, UnavailablePackage>,
+        unavailable_versions: &FxHashMap<(PackageName, Version), UnavailableVersion>,
+    ) -> ReportIncompatibility {
+        let message = self.format_external(external);
+        match external {
+            External::NotRoot(package, version) => ReportIncompatibility {
+                kind: IncompatibilityKind::NotRoot,
+                package: package.to_string(),
+                range: specifiers(&Range::singleton(version.clone())),
+                dependency: None,
+                reason: None,
+                message,
+            },
+            External::NoVersions(package, set, reason) => {
+                let kind = match package {
+                    PubGrubPackage::Python(_) => IncompatibilityKind::RequiresPython,
+                    PubGrubPackage::Package(name, ..) => match unavailable_packages.get(name) {
+                        Some(UnavailablePackage::Offline) => IncompatibilityKind::Offline,
+                        Some(_) => IncompatibilityKind::Unavailable,
+                        None if reason.is_some() => IncompatibilityKind::Unavailable,
+                        None => IncompatibilityKind::NoVersions,
+                    },
+                    PubGrubPackage::Root(_) => IncompatibilityKind::NoVersions,
+                };
+                ReportIncompatibility {
+                    kind,
+                    package: package.to_string(),
+                    range: specifiers(&self.simplify_set(set, package)),
+                    dependency: None,
+                    reason: reason.clone(),
+                    message,
+                }
+            }
+            External::Unavailable(package, set, reason) => {
+                let yanked = match (package, set.as_singleton()) {
+                    (PubGrubPackage::Package(name, ..), Some(version)) => matches!(
+                        unavailable_versions.get(&(name.clone(), version.clone())),
+                        Some(UnavailableVersion::IncompatibleDist(
+                            IncompatibleDist::Wheel(IncompatibleWheel::Yanked(_))
+                                | IncompatibleDist::Source(IncompatibleSource::Yanked(_))
+                        ))
+                    ),
+                    _ => false,
+                };
+                ReportIncompatibility {
+                    kind: if yanked {
+                        IncompatibilityKind::Yanked
+                    } else {
+                        IncompatibilityKind::Unavailable
+                    },
+                    package: package.to_string(),
+                    range: specifiers(&self.simplify_set(set, package)),
+                    dependency: None,
+                    reason: Some(reason.clone()),
+                    message,
+                }
+            }
+            External::FromDependencyOf(package, package_set, dependency, dependency_set) => {
+                ReportIncompatibility {
+                    kind: if matches!(dependency, PubGrubPackage::Python(_)) {
+                        IncompatibilityKind::RequiresPython
+                    } else {
+                        IncompatibilityKind::Dependency
+                    },
+                    package: package.to_string(),
+                    range: specifiers(&self.simplify_set(package_set, package)),
+                    dependency: Some(ReportPackageRange::new(
+                        dependency,
+                        &self.simplify_set(dependency_set, dependency),
+                    )),
+                    reason: None,
+                    message,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use indexmap::IndexMap;
+    use pep440_rs::Version;
+    use pubgrub::range::Range;
+    use pubgrub::report::{DerivationTree, External};
+    use rustc_hash::FxHashMap;
+
+    use distribution_types::{IncompatibleDist, IncompatibleWheel};
+    use pypi_types::Yanked;
+    use uv_normalize::PackageName;
+
+    use crate::pubgrub::report::PubGrubReportFormatter;
+    use crate::pubgrub::{PubGrubPackage, PubGrubPython};
+    use crate::resolver::{UnavailablePackage, UnavailableVersion};
+
+    use super::{IncompatibilityKind, ReportIncompatibility, ReportNode};
+
+    fn report_external(
+        external: External<PubGrubPackage, Range<Version>>,
+        unavailable_packages: &FxHashMap<PackageName, UnavailablePackage>,
+        unavailable_versions: &FxHashMap<(PackageName, Version), UnavailableVersion>,
+    ) -> ReportIncompatibility {
+        let available_versions = IndexMap::default();
+        let formatter = PubGrubReportFormatter {
+            available_versions: &available_versions,
+            python_requirement: None,
+        };
+        let ReportNode::External(incompatibility) = formatter.report_node(
+            &DerivationTree::External(external),
+            unavailable_packages,
+            unavailable_versions,
+        ) else {
+            unreachable!()
+        };
+        incompatibility
+    }
+
+    #[test]
+    fn offline() {
+        let name = PackageName::from_str("tqdm").unwrap();
+        let unavailable_packages =
+            FxHashMap::from_iter([(name.clone(), UnavailablePackage::Offline)]);
+        let incompatibility = report_external(
+            External::NoVersions(
+                PubGrubPackage::Package(name, None, None),
+                Range::full(),
+                Some("was not found in the cache".to_string()),
+            ),
+            &unavailable_packages,
+            &FxHashMap::default(),
+        );
+        assert_eq!(incompatibility.kind, IncompatibilityKind::Offline);
+        assert_eq!(incompatibility.package, "tqdm");
+        assert_eq!(incompatibility.range, vec![String::new()]);
+        assert_eq!(incompatibility.message, "tqdm was not found in the cache");
+    }
+
+    #[test]
+    fn yanked() {
+        let name = PackageName::from_str("flask").unwrap();
+        let version = Version::from_str("3.0.0").unwrap();
+        let unavailable_versions = FxHashMap::from_iter([(
+            (name.clone(), version.clone()),
+            UnavailableVersion::IncompatibleDist(IncompatibleDist::Wheel(
+                IncompatibleWheel::Yanked(Yanked::Bool(true)),
+            )),
+        )]);
+        let incompatibility = report_external(
+            External::Unavailable(
+                PubGrubPackage::Package(name, None, None),
+                Range::singleton(version),
+                "it was yanked".to_string(),
+            ),
+            &FxHashMap::default(),
+            &unavailable_versions,
+        );
+        assert_eq!(incompatibility.kind, IncompatibilityKind::Yanked);
+        assert_eq!(incompatibility.reason.as_deref(), Some("it was yanked"));
+    }
+
+    #[test]
+    fn requires_python() {
+        let incompatibility = report_external(
+            External::FromDependencyOf(
+                PubGrubPackage::Package(PackageName::from_str("black").unwrap(), None, None),
+                Range::singleton(Version::from_str("23.10.1").unwrap()),
+                PubGrubPackage::Python(PubGrubPython::Target),
+                Range::higher_than(Version::from_str("3.8").unwrap()),
+            ),
+            &FxHashMap::default(),
+            &FxHashMap::default(),
+        );
+        assert_eq!(incompatibility.kind, IncompatibilityKind::RequiresPython);
+        assert_eq!(incompatibility.dependency.unwrap().package, "Python");
+    }
+}
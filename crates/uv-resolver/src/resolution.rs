@@ -4,6 +4,7 @@ use std::hash::BuildHasherDefault;
 use anyhow::Result;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use petgraph::graph::NodeIndex;
 use petgraph::visit::EdgeRef;
 use petgraph::Direction;
 use pubgrub::range::Range;
@@ -17,7 +18,7 @@ use distribution_types::{
 };
 use once_map::OnceMap;
 use pep440_rs::Version;
-use pep508_rs::MarkerEnvironment;
+use pep508_rs::{MarkerEnvironment, MarkerTree};
 use pypi_types::Hashes;
 use uv_distribution::to_precise;
 use uv_normalize::{ExtraName, PackageName};
@@ -29,6 +30,7 @@ use crate::preferences::Preferences;
 use crate::pubgrub::{PubGrubDistribution, PubGrubPackage};
 use crate::redirect::apply_redirect;
 use crate::resolver::{InMemoryIndex, MetadataResponse, VersionsResponse};
+use crate::why::Why;
 use crate::{Manifest, ResolveError};
 
 /// Indicate the style of annotation comments, used to indicate the dependencies that requested each
@@ -50,13 +52,18 @@ pub struct ResolutionGraph {
     /// The underlying graph.
     petgraph: petgraph::graph::Graph<ResolvedDist, Range<Version>, petgraph::Directed>,
     /// The metadata for every distribution in this resolution.
-    hashes: FxHashMap<PackageName, Vec<Hashes>>,
+    hashes: FxHashMap<NodeIndex, Vec<Hashes>>,
     /// The enabled extras for every distribution in this resolution.
     extras: FxHashMap<PackageName, Vec<ExtraName>>,
     /// The set of editable requirements in this resolution.
     editables: Editables,
+    /// The version ranges of the direct dependencies, as requested by the root requirements.
+    requirements: FxHashMap<PackageName, Range<Version>>,
     /// Any diagnostics that were encountered while building the graph.
     diagnostics: Vec<Diagnostic>,
+    /// The markers under which each distribution applies, for distributions that were only
+    /// selected in some forks of a forked resolution.
+    markers: FxHashMap<NodeIndex, MarkerTree>,
 }
 
 impl ResolutionGraph {
@@ -77,6 +84,7 @@ impl ResolutionGraph {
         let mut hashes =
             FxHashMap::with_capacity_and_hasher(selection.len(), BuildHasherDefault::default());
         let mut extras = FxHashMap::default();
+        let mut requirements = FxHashMap::<PackageName, Range<Version>>::default();
         let mut diagnostics = Vec::new();
 
         // Add every package to the graph.
@@ -94,25 +102,25 @@ impl ResolutionGraph {
                             .clone()
                     };
 
+                    // Add the distribution to the graph.
+                    let index = petgraph.add_node(pinned_package);
+                    inverse.insert(package_name, index);
+
                     // Add its hashes to the index, preserving those that were already present in
                     // the lockfile if necessary.
                     if let Some(hash) = preferences.match_hashes(package_name, version) {
-                        hashes.insert(package_name.clone(), hash.to_vec());
+                        hashes.insert(index, hash.to_vec());
                     } else if let Some(versions_response) = packages.get(package_name) {
                         if let VersionsResponse::Found(ref version_maps) = *versions_response {
                             for version_map in version_maps {
                                 if let Some(mut hash) = version_map.hashes(version) {
                                     hash.sort_unstable();
-                                    hashes.insert(package_name.clone(), hash);
+                                    hashes.insert(index, hash);
                                     break;
                                 }
                             }
                         }
                     }
-
-                    // Add the distribution to the graph.
-                    let index = petgraph.add_node(pinned_package);
-                    inverse.insert(package_name, index);
                 }
                 PubGrubPackage::Package(package_name, None, Some(url)) => {
                     // Create the distribution.
@@ -124,25 +132,25 @@ impl ResolutionGraph {
                         Dist::from_url(package_name.clone(), url)?
                     };
 
+                    // Add the distribution to the graph.
+                    let index = petgraph.add_node(pinned_package.into());
+                    inverse.insert(package_name, index);
+
                     // Add its hashes to the index, preserving those that were already present in
                     // the lockfile if necessary.
                     if let Some(hash) = preferences.match_hashes(package_name, version) {
-                        hashes.insert(package_name.clone(), hash.to_vec());
+                        hashes.insert(index, hash.to_vec());
                     } else if let Some(versions_response) = packages.get(package_name) {
                         if let VersionsResponse::Found(ref version_maps) = *versions_response {
                             for version_map in version_maps {
                                 if let Some(mut hash) = version_map.hashes(version) {
                                     hash.sort_unstable();
-                                    hashes.insert(package_name.clone(), hash);
+                                    hashes.insert(index, hash);
                                     break;
                                 }
                             }
                         }
                     }
-
-                    // Add the distribution to the graph.
-                    let index = petgraph.add_node(pinned_package.into());
-                    inverse.insert(package_name, index);
                 }
                 PubGrubPackage::Package(package_name, Some(extra), None) => {
                     // Validate that the `extra` exists.
@@ -273,14 +281,24 @@ impl ResolutionGraph {
                         continue;
                     }
 
-                    let PubGrubPackage::Package(self_package, _, _) = self_package else {
-                        continue;
-                    };
                     let PubGrubPackage::Package(dependency_package, _, _) = dependency_package
                     else {
                         continue;
                     };
 
+                    // Track the direct dependencies, as requested by the root requirements.
+                    if let PubGrubPackage::Root(_) = self_package {
+                        requirements
+                            .entry(dependency_package.clone())
+                            .and_modify(|range| *range = range.intersection(dependency_range))
+                            .or_insert_with(|| dependency_range.clone());
+                        continue;
+                    }
+
+                    let PubGrubPackage::Package(self_package, _, _) = self_package else {
+                        continue;
+                    };
+
                     // For extras, we include a dependency between the extra and the base package.
                     if self_package == dependency_package {
                         continue;
@@ -304,10 +322,103 @@ impl ResolutionGraph {
             hashes,
             extras,
             editables,
+            requirements,
             diagnostics,
+            markers: FxHashMap::default(),
         })
     }
 
+    /// Merge the resolutions from each fork of a forked resolution into a single graph.
+    ///
+    /// Each fork is paired with the marker under which it applies (e.g., `extra == "cpu"`).
+    /// Distributions that were selected in every fork are included unconditionally; all others
+    /// are gated by the markers of the forks that selected them.
+    pub fn from_forks(forks: Vec<(MarkerTree, ResolutionGraph)>) -> Self {
+        let mut petgraph = petgraph::graph::Graph::new();
+        let mut hashes = FxHashMap::default();
+        let mut extras = FxHashMap::<PackageName, Vec<ExtraName>>::default();
+        let mut editables = None;
+        let mut requirements = FxHashMap::<PackageName, Range<Version>>::default();
+        let mut diagnostics = Vec::new();
+        let mut seen_diagnostics = FxHashSet::default();
+
+        // The merged node for each distribution, along with the markers of the forks that
+        // selected it.
+        let mut inverse = FxHashMap::<PackageId, (NodeIndex, Vec<MarkerTree>)>::default();
+
+        let num_forks = forks.len();
+        for (marker, fork) in forks {
+            let mut indices = FxHashMap::default();
+            for index in fork.petgraph.node_indices() {
+                let dist = &fork.petgraph[index];
+                let (merged, markers) = inverse
+                    .entry(dist.package_id())
+                    .or_insert_with(|| (petgraph.add_node(dist.clone()), Vec::new()));
+                markers.push(marker.clone());
+                indices.insert(index, *merged);
+
+                if let Some(hash) = fork.hashes.get(&index) {
+                    hashes.entry(*merged).or_insert_with(|| hash.clone());
+                }
+            }
+
+            for edge in fork.petgraph.edge_references() {
+                petgraph.update_edge(
+                    indices[&edge.source()],
+                    indices[&edge.target()],
+                    edge.weight().clone(),
+                );
+            }
+
+            for (name, fork_extras) in fork.extras {
+                let entry = extras.entry(name).or_default();
+                for extra in fork_extras {
+                    if !entry.contains(&extra) {
+                        entry.push(extra);
+                    }
+                }
+            }
+
+            for (name, range) in fork.requirements {
+                requirements
+                    .entry(name)
+                    .and_modify(|existing| *existing = existing.union(&range))
+                    .or_insert(range);
+            }
+
+            for diagnostic in fork.diagnostics {
+                if seen_diagnostics.insert(diagnostic.message()) {
+                    diagnostics.push(diagnostic);
+                }
+            }
+
+            editables.get_or_insert(fork.editables);
+        }
+
+        let markers = inverse
+            .into_values()
+            .filter(|(_, markers)| markers.len() < num_forks)
+            .map(|(index, mut markers)| {
+                let marker = if markers.len() == 1 {
+                    markers.remove(0)
+                } else {
+                    MarkerTree::Or(markers)
+                };
+                (index, marker)
+            })
+            .collect();
+
+        Self {
+            petgraph,
+            hashes,
+            extras,
+            editables: editables.unwrap_or_default(),
+            requirements,
+            diagnostics,
+            markers,
+        }
+    }
+
     /// Return the number of packages in the graph.
     pub fn len(&self) -> usize {
         self.petgraph.node_count()
@@ -334,6 +445,38 @@ impl ResolutionGraph {
             .map(|node| node.weight)
     }
 
+    /// Explain why the given package was included in the resolution.
+    ///
+    /// Returns an explanation for every version of the package in the graph (e.g., a forked
+    /// resolution may select a different version in each fork), or an empty list if the package
+    /// is not present in the graph.
+    ///
+    /// The [`Manifest`], [`MarkerEnvironment`], and [`InMemoryIndex`] should be the same values
+    /// given to the resolver that produced this graph.
+    pub fn why(
+        &self,
+        name: &PackageName,
+        manifest: &Manifest,
+        markers: &MarkerEnvironment,
+        index: &InMemoryIndex,
+    ) -> Vec<Why> {
+        self.petgraph
+            .node_indices()
+            .filter(|index| self.petgraph[*index].name() == name)
+            .map(|target| {
+                Why::from_graph(
+                    &self.petgraph,
+                    &self.requirements,
+                    manifest,
+                    markers,
+                    target,
+                    self.markers.get(&target).cloned(),
+                    index,
+                )
+            })
+            .collect()
+    }
+
     /// Return the [`Diagnostic`]s that were encountered while building the graph.
     pub fn diagnostics(&self) -> &[Diagnostic] {
         &self.diagnostics
@@ -371,10 +514,9 @@ impl ResolutionGraph {
         manifest: &Manifest,
         index: &InMemoryIndex,
         marker_env: &MarkerEnvironment,
-    ) -> pep508_rs::MarkerTree {
+    ) -> MarkerTree {
         use pep508_rs::{
-            MarkerExpression, MarkerOperator, MarkerTree, MarkerValue, MarkerValueString,
-            MarkerValueVersion,
+            MarkerExpression, MarkerOperator, MarkerValue, MarkerValueString, MarkerValueVersion,
         };
 
         /// A subset of the possible marker values.
@@ -639,13 +781,20 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
             // Display the node itself.
             let mut line = node.verbatim().to_string();
 
+            // Display the markers under which the node applies, if it was only selected in some
+            // forks of the resolution.
+            if let Some(marker) = self.resolution.markers.get(&index) {
+                line.push_str(" ; ");
+                line.push_str(&marker.to_string());
+            }
+
             // Display the distribution hashes, if any.
             let mut has_hashes = false;
             if self.show_hashes {
                 if let Some(hashes) = self
                     .resolution
                     .hashes
-                    .get(node.name())
+                    .get(&index)
                     .filter(|hashes| !hashes.is_empty())
                 {
                     for hash in hashes {
@@ -671,6 +820,9 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                     .map(|edge| &self.resolution.petgraph[edge.source()])
                     .collect::<Vec<_>>();
                 edges.sort_unstable_by_key(|package| package.name());
+                // In a forked resolution, a package may depend on multiple versions of the same
+                // package.
+                edges.dedup_by_key(|package| package.name());
 
                 match self.annotation_style {
                     AnnotationStyle::Line => {
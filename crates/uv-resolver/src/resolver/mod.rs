@@ -4,6 +4,7 @@ use std::borrow::Cow;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 use std::sync::Arc;
+use std::time::Instant;
 
 use anyhow::Result;
 use dashmap::{DashMap, DashSet};
@@ -11,7 +12,7 @@ use futures::{FutureExt, StreamExt};
 use itertools::Itertools;
 use pubgrub::error::PubGrubError;
 use pubgrub::range::Range;
-use pubgrub::solver::{Incompatibility, State};
+use pubgrub::solver::{Incompatibility, Kind, State};
 use rustc_hash::{FxHashMap, FxHashSet};
 use tokio_stream::wrappers::ReceiverStream;
 use tracing::{debug, info_span, instrument, trace, warn, Instrument};
@@ -30,9 +31,10 @@ use uv_client::{FlatIndex, RegistryClient};
 use uv_distribution::DistributionDatabase;
 use uv_interpreter::Interpreter;
 use uv_normalize::PackageName;
-use uv_types::{BuildContext, Constraints, InstalledPackagesProvider, Overrides};
+use uv_types::{BuildContext, Concurrency, Constraints, InstalledPackagesProvider, Overrides};
 
 use crate::candidate_selector::{CandidateDist, CandidateSelector};
+use crate::dependency_provider::UvDependencyProvider;
 use crate::editables::Editables;
 use crate::error::ResolveError;
 use crate::manifest::Manifest;
@@ -51,6 +53,8 @@ pub use crate::resolver::provider::{
 };
 use crate::resolver::reporter::Facade;
 pub use crate::resolver::reporter::{BuildId, Reporter};
+use crate::stats::ResolverStats;
+use crate::version_map::VersionMap;
 use crate::yanks::AllowedYanks;
 use crate::{DependencyMode, Exclusions, Options};
 
@@ -77,6 +81,8 @@ pub(crate) enum UnavailablePackage {
     Offline,
     /// The package was not found in the registry
     NotFound,
+    /// The package was not found in the named index to which it's assigned
+    NotFoundInIndex(String),
     /// The wheel metadata was found, but could not be parsed.
     InvalidMetadata,
     /// The wheel has an invalid structure.
@@ -99,6 +105,7 @@ pub struct Resolver<
     requirements: Vec<Requirement>,
     constraints: Constraints,
     overrides: Overrides,
+    excludes: FxHashSet<PackageName>,
     preferences: Preferences,
     exclusions: Exclusions,
     editables: Editables,
@@ -112,9 +119,15 @@ pub struct Resolver<
     installed_packages: &'a InstalledPackages,
     /// Incompatibilities for packages that are entirely unavailable
     unavailable_packages: DashMap<PackageName, UnavailablePackage>,
+    /// Incompatibilities for individual package versions that are unavailable
+    unavailable_versions: DashMap<(PackageName, Version), UnavailableVersion>,
     /// The set of all registry-based packages visited during resolution.
     visited: DashSet<PackageName>,
     reporter: Option<Arc<dyn Reporter>>,
+    /// The collector for resolver statistics, if enabled.
+    stats: Option<&'a ResolverStats>,
+    /// The maximum number of in-flight metadata requests.
+    concurrent_downloads: usize,
     provider: Provider,
 }
 
@@ -148,10 +161,12 @@ impl<
             PythonRequirement::new(interpreter, markers),
             AllowedYanks::from_manifest(&manifest, markers),
             options.exclude_newer,
+            options.exclude_newer_package.clone(),
+            options.missing_upload_time,
             build_context.no_binary(),
             build_context.no_build(),
         );
-        Self::new_custom_io(
+        Ok(Self::new_custom_io(
             manifest,
             options,
             markers,
@@ -159,7 +174,8 @@ impl<
             index,
             provider,
             installed_packages,
-        )
+        )?
+        .with_concurrent_downloads(build_context.concurrency().downloads))
     }
 }
 
@@ -182,8 +198,9 @@ impl<
         Ok(Self {
             index,
             unavailable_packages: DashMap::default(),
+            unavailable_versions: DashMap::default(),
             visited: DashSet::default(),
-            selector: CandidateSelector::for_resolution(options, &manifest, markers),
+            selector: CandidateSelector::for_resolution(&options, &manifest, markers),
             dependency_mode: options.dependency_mode,
             urls: Urls::from_manifest(&manifest, markers)?,
             locals: Locals::from_manifest(&manifest, markers),
@@ -191,12 +208,15 @@ impl<
             requirements: manifest.requirements,
             constraints: manifest.constraints,
             overrides: manifest.overrides,
+            excludes: manifest.excludes,
             preferences: Preferences::from_iter(manifest.preferences, markers),
             exclusions: manifest.exclusions,
             editables: Editables::from_requirements(manifest.editables),
             markers,
             python_requirement,
             reporter: None,
+            stats: None,
+            concurrent_downloads: Concurrency::DEFAULT_DOWNLOADS,
             provider,
             installed_packages,
         })
@@ -213,12 +233,30 @@ impl<
         }
     }
 
+    /// Set the [`ResolverStats`] collector to use for this resolver.
+    #[must_use]
+    pub fn with_stats(self, stats: &'a ResolverStats) -> Self {
+        Self {
+            stats: Some(stats),
+            ..self
+        }
+    }
+
+    /// Set the maximum number of in-flight metadata requests.
+    #[must_use]
+    pub fn with_concurrent_downloads(self, concurrent_downloads: usize) -> Self {
+        Self {
+            concurrent_downloads,
+            ..self
+        }
+    }
+
     /// Resolve a set of requirements into a set of pinned versions.
     pub async fn resolve(self) -> Result<ResolutionGraph, ResolveError> {
         // A channel to fetch package metadata (e.g., given `flask`, fetch all versions) and version
         // metadata (e.g., given `flask==1.0.0`, fetch the metadata for that version).
         // Channel size is set to the same size as the task buffer for simplicity.
-        let (request_sink, request_stream) = tokio::sync::mpsc::channel(50);
+        let (request_sink, request_stream) = tokio::sync::mpsc::channel(self.concurrent_downloads);
 
         // Run the fetcher.
         let requests_fut = self.fetch(request_stream).fuse();
@@ -244,7 +282,8 @@ impl<
                         .with_selector(self.selector.clone())
                         .with_python_requirement(&self.python_requirement)
                         .with_index_locations(self.provider.index_locations())
-                        .with_unavailable_packages(&self.unavailable_packages),
+                        .with_unavailable_packages(&self.unavailable_packages)
+                        .with_unavailable_versions(&self.unavailable_versions),
                     )
                 } else {
                     err
@@ -278,7 +317,10 @@ impl<
 
         loop {
             // Run unit propagation.
-            state.unit_propagation(next)?;
+            if let Err(err) = state.unit_propagation(next) {
+                self.record_stats(&state, &priorities);
+                return Err(err.into());
+            }
 
             // Pre-visit all candidate packages, to allow metadata to be fetched in parallel. If
             // the dependency mode is direct, we only need to visit the root package.
@@ -295,6 +337,7 @@ impl<
                         priorities.get(package).unwrap_or_default()
                     })
             else {
+                self.record_stats(&state, &priorities);
                 let selection = state.partial_solution.extract_solution();
                 return ResolutionGraph::from_state(
                     &selection,
@@ -323,6 +366,12 @@ impl<
                 )
                 .await?;
 
+            if let (Some(stats), Some(_), PubGrubPackage::Package(package_name, ..)) =
+                (self.stats, &decision, &next)
+            {
+                stats.record_version(package_name);
+            }
+
             // Pick the next compatible version.
             let version = match decision {
                 None => {
@@ -338,19 +387,26 @@ impl<
                             // Check if the decision was due to the package being unavailable
                             self.unavailable_packages
                                 .get(package_name)
-                                .map(|entry| match *entry {
+                                .map(|entry| match &*entry {
                                     UnavailablePackage::NoIndex => {
                                         "was not found in the provided package locations"
+                                            .to_string()
+                                    }
+                                    UnavailablePackage::Offline => {
+                                        "was not found in the cache".to_string()
                                     }
-                                    UnavailablePackage::Offline => "was not found in the cache",
                                     UnavailablePackage::NotFound => {
-                                        "was not found in the package registry"
+                                        "was not found in the package registry".to_string()
+                                    }
+                                    UnavailablePackage::NotFoundInIndex(index) => {
+                                        format!("was not found in the `{index}` index")
                                     }
                                     UnavailablePackage::InvalidMetadata => {
                                         "was found, but the metadata could not be parsed"
+                                            .to_string()
                                     }
                                     UnavailablePackage::InvalidStructure => {
-                                        "was found, but has an invalid format"
+                                        "was found, but has an invalid format".to_string()
                                     }
                                 })
                         } else {
@@ -361,7 +417,7 @@ impl<
                     let inc = Incompatibility::no_versions(
                         next.clone(),
                         term_intersection.clone(),
-                        reason.map(ToString::to_string),
+                        reason,
                     );
 
                     state.add_incompatibility(inc);
@@ -403,7 +459,14 @@ impl<
                             continue;
                         }
                         UnavailableVersion::IncompatibleDist(incompatibility) => {
-                            incompatibility.to_string()
+                            let reason = incompatibility.to_string();
+                            if let PubGrubPackage::Package(ref package_name, ..) = next {
+                                self.unavailable_versions.insert(
+                                    (package_name.clone(), version.clone()),
+                                    UnavailableVersion::IncompatibleDist(incompatibility),
+                                );
+                            }
+                            reason
                         }
                     };
                     state.add_incompatibility(Incompatibility::unavailable(
@@ -471,6 +534,38 @@ impl<
         }
     }
 
+    /// Record the conflicts and priorities from the given solver state in the [`ResolverStats`],
+    /// if enabled.
+    fn record_stats(&self, state: &State<UvDependencyProvider>, priorities: &PubGrubPriorities) {
+        let Some(stats) = self.stats else {
+            return;
+        };
+
+        // Incompatibilities derived from others are only created during conflict resolution, so
+        // we attribute each one to every package that it involves.
+        for (package, ids) in &state.incompatibilities {
+            let PubGrubPackage::Package(package_name, ..) = package else {
+                continue;
+            };
+            let conflicts = ids
+                .iter()
+                .filter(|id| {
+                    matches!(
+                        state.incompatibility_store[**id].kind,
+                        Kind::DerivedFrom(..)
+                    )
+                })
+                .count();
+            if conflicts > 0 {
+                stats.record_conflicts(package_name, conflicts);
+            }
+        }
+
+        for (package_name, priority) in priorities.iter() {
+            stats.record_priority(package_name, priority);
+        }
+    }
+
     /// Visit a [`PubGrubPackage`] prior to selection. This should be called on a [`PubGrubPackage`]
     /// before it is selected, to allow metadata to be fetched in parallel.
     async fn visit_package(
@@ -670,6 +765,13 @@ impl<
                             .insert(package_name.clone(), UnavailablePackage::NotFound);
                         &[]
                     }
+                    VersionsResponse::NotFoundInIndex(ref index) => {
+                        self.unavailable_packages.insert(
+                            package_name.clone(),
+                            UnavailablePackage::NotFoundInIndex(index.clone()),
+                        );
+                        &[]
+                    }
                 };
 
                 if let Some(extra) = extra {
@@ -681,14 +783,25 @@ impl<
                 }
 
                 // Find a version.
-                let Some(candidate) = self.selector.select(
+                let candidate = self.selector.select(
                     package_name,
                     range,
                     version_maps,
                     &self.preferences,
                     self.installed_packages,
                     &self.exclusions,
-                ) else {
+                );
+
+                // If requested, fail if any of the distributions considered for the package is
+                // missing the upload time required to apply `--exclude-newer`.
+                if let Some((filename, index)) = version_maps
+                    .iter()
+                    .find_map(VersionMap::first_missing_upload_time)
+                {
+                    return Err(ResolveError::MissingUploadTime(filename, index.to_string()));
+                }
+
+                let Some(candidate) = candidate else {
                     // Short circuit: we couldn't find _any_ versions for a package.
                     return Ok(None);
                 };
@@ -762,6 +875,7 @@ impl<
                     &self.requirements,
                     &self.constraints,
                     &self.overrides,
+                    &self.excludes,
                     None,
                     None,
                     &self.urls,
@@ -838,6 +952,7 @@ impl<
                         &metadata.requires_dist,
                         &self.constraints,
                         &self.overrides,
+                        &self.excludes,
                         Some(package_name),
                         extra.as_ref(),
                         &self.urls,
@@ -920,6 +1035,7 @@ impl<
                     &metadata.requires_dist,
                     &self.constraints,
                     &self.overrides,
+                    &self.excludes,
                     Some(package_name),
                     extra.as_ref(),
                     &self.urls,
@@ -955,7 +1071,7 @@ impl<
     ) -> Result<(), ResolveError> {
         let mut response_stream = ReceiverStream::new(request_stream)
             .map(|request| self.process_request(request).boxed())
-            .buffer_unordered(50);
+            .buffer_unordered(self.concurrent_downloads);
 
         while let Some(response) = response_stream.next().await {
             match response? {
@@ -1013,18 +1129,23 @@ impl<
         match request {
             // Fetch package metadata from the registry.
             Request::Package(package_name) => {
+                let start = Instant::now();
                 let package_versions = self
                     .provider
                     .get_package_versions(&package_name)
                     .boxed()
                     .await
                     .map_err(ResolveError::Client)?;
+                if let Some(stats) = self.stats {
+                    stats.record_versions_fetch(&package_name, start.elapsed());
+                }
 
                 Ok(Some(Response::Package(package_name, package_versions)))
             }
 
             // Fetch distribution metadata from the distribution database.
             Request::Dist(dist) => {
+                let start = Instant::now();
                 let metadata = self
                     .provider
                     .get_or_build_wheel_metadata(&dist)
@@ -1042,6 +1163,9 @@ impl<
                             ResolveError::FetchAndBuild(Box::new(source_dist), err)
                         }
                     })?;
+                if let Some(stats) = self.stats {
+                    stats.record_metadata_fetch(&dist, start.elapsed());
+                }
                 Ok(Some(Response::Dist { dist, metadata }))
             }
 
@@ -1081,6 +1205,14 @@ impl<
                         self.unavailable_packages
                             .insert(package_name.clone(), UnavailablePackage::NotFound);
 
+                        return Ok(None);
+                    }
+                    VersionsResponse::NotFoundInIndex(ref index) => {
+                        self.unavailable_packages.insert(
+                            package_name.clone(),
+                            UnavailablePackage::NotFoundInIndex(index.clone()),
+                        );
+
                         return Ok(None);
                     }
                 };
@@ -1109,6 +1241,7 @@ impl<
 
                     let response = match dist {
                         ResolvedDist::Installable(dist) => {
+                            let start = Instant::now();
                             let metadata = self
                                 .provider
                                 .get_or_build_wheel_metadata(&dist)
@@ -1128,6 +1261,9 @@ impl<
                                         ResolveError::FetchAndBuild(Box::new(source_dist), err)
                                     }
                                 })?;
+                            if let Some(stats) = self.stats {
+                                stats.record_metadata_fetch(&dist, start.elapsed());
+                            }
                             Response::Dist { dist, metadata }
                         }
                         ResolvedDist::Installed(dist) => {
@@ -2,6 +2,7 @@ use std::future::Future;
 
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use rustc_hash::FxHashMap;
 
 use distribution_types::{Dist, IndexLocations};
 use platform_tags::Tags;
@@ -14,6 +15,7 @@ use uv_types::{BuildContext, NoBinary, NoBuild};
 use crate::python_requirement::PythonRequirement;
 use crate::version_map::VersionMap;
 use crate::yanks::AllowedYanks;
+use crate::MissingUploadTime;
 
 pub type PackageVersionsResult = Result<VersionsResponse, uv_client::Error>;
 pub type WheelMetadataResult = Result<MetadataResponse, uv_distribution::Error>;
@@ -25,6 +27,8 @@ pub enum VersionsResponse {
     Found(Vec<VersionMap>),
     /// The package was not found in the registry
     NotFound,
+    /// The package was not found in the named index to which it's assigned
+    NotFoundInIndex(String),
     /// The package was not found in the local registry
     NoIndex,
     /// The package was not found in the cache and the network is not available.
@@ -80,6 +84,8 @@ pub struct DefaultResolverProvider<'a, Context: BuildContext + Send + Sync> {
     python_requirement: PythonRequirement,
     allowed_yanks: AllowedYanks,
     exclude_newer: Option<DateTime<Utc>>,
+    exclude_newer_package: FxHashMap<PackageName, Option<DateTime<Utc>>>,
+    missing_upload_time: MissingUploadTime,
     no_binary: NoBinary,
     no_build: NoBuild,
 }
@@ -95,6 +101,8 @@ impl<'a, Context: BuildContext + Send + Sync> DefaultResolverProvider<'a, Contex
         python_requirement: PythonRequirement,
         allowed_yanks: AllowedYanks,
         exclude_newer: Option<DateTime<Utc>>,
+        exclude_newer_package: FxHashMap<PackageName, Option<DateTime<Utc>>>,
+        missing_upload_time: MissingUploadTime,
         no_binary: &'a NoBinary,
         no_build: &'a NoBuild,
     ) -> Self {
@@ -106,6 +114,8 @@ impl<'a, Context: BuildContext + Send + Sync> DefaultResolverProvider<'a, Contex
             python_requirement,
             allowed_yanks,
             exclude_newer,
+            exclude_newer_package,
+            missing_upload_time,
             no_binary: no_binary.clone(),
             no_build: no_build.clone(),
         }
@@ -120,6 +130,13 @@ impl<'a, Context: BuildContext + Send + Sync> ResolverProvider
         &'io self,
         package_name: &'io PackageName,
     ) -> PackageVersionsResult {
+        // Apply any per-package exclusion date in place of the global exclusion date.
+        let exclude_newer = self
+            .exclude_newer_package
+            .get(package_name)
+            .copied()
+            .unwrap_or(self.exclude_newer);
+
         match self.client.simple(package_name).await {
             Ok(results) => Ok(VersionsResponse::Found(
                 results
@@ -132,7 +149,8 @@ impl<'a, Context: BuildContext + Send + Sync> ResolverProvider
                             &self.tags,
                             &self.python_requirement,
                             &self.allowed_yanks,
-                            self.exclude_newer.as_ref(),
+                            exclude_newer.as_ref(),
+                            self.missing_upload_time,
                             self.flat_index.get(package_name).cloned(),
                             &self.no_binary,
                             &self.no_build,
@@ -148,6 +166,11 @@ impl<'a, Context: BuildContext + Send + Sync> ResolverProvider
                         Ok(VersionsResponse::NotFound)
                     }
                 }
+                // A package that's assigned to an index is never fetched from anywhere else,
+                // including `--find-links`.
+                uv_client::ErrorKind::PackageNotFoundInIndex(_, index) => {
+                    Ok(VersionsResponse::NotFoundInIndex(index))
+                }
                 uv_client::ErrorKind::NoIndex(_) => {
                     if let Some(flat_index) = self.flat_index.get(package_name).cloned() {
                         Ok(VersionsResponse::Found(vec![VersionMap::from(flat_index)]))
@@ -0,0 +1,251 @@
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use dashmap::DashMap;
+use serde::Serialize;
+
+use distribution_types::{BuiltDist, Dist, Name, SourceDist};
+use uv_normalize::PackageName;
+
+/// Statistics collected over the course of a single resolution attempt, to help diagnose slow
+/// resolutions (e.g., due to heavy backtracking, or source distributions that must be built to
+/// read their metadata).
+#[derive(Debug, Default)]
+pub struct ResolverStats {
+    /// The statistics for each package, keyed by name.
+    packages: DashMap<PackageName, PackageStats>,
+    /// The statistics for each index from which distribution metadata was fetched.
+    indexes: DashMap<String, IndexStats>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct PackageStats {
+    /// The position of the package in the resolver's priority queue, in order of discovery.
+    priority: Option<usize>,
+    /// The number of versions that the resolver tried for the package.
+    versions_tried: usize,
+    /// The number of incompatibilities involving the package that were derived during conflict
+    /// resolution (i.e., backtracking).
+    conflicts: usize,
+    /// The number of source distributions of the package that were built to read their metadata.
+    source_builds: usize,
+    /// The time spent fetching the available versions of the package.
+    versions_fetch: Duration,
+    /// The time spent fetching (or building) the metadata for distributions of the package.
+    metadata_fetch: Duration,
+}
+
+#[derive(Debug, Default, Clone)]
+struct IndexStats {
+    /// The number of distribution metadata requests made to the index.
+    requests: usize,
+    /// The time spent fetching (or building) distribution metadata from the index.
+    metadata_fetch: Duration,
+}
+
+impl ResolverStats {
+    /// Record that the resolver tried a version of the given package.
+    pub(crate) fn record_version(&self, package: &PackageName) {
+        self.packages
+            .entry(package.clone())
+            .or_default()
+            .versions_tried += 1;
+    }
+
+    /// Record the time spent fetching the available versions of the given package.
+    pub(crate) fn record_versions_fetch(&self, package: &PackageName, elapsed: Duration) {
+        self.packages
+            .entry(package.clone())
+            .or_default()
+            .versions_fetch += elapsed;
+    }
+
+    /// Record the time spent fetching (or building) the metadata for the given distribution.
+    pub(crate) fn record_metadata_fetch(&self, dist: &Dist, elapsed: Duration) {
+        self.packages
+            .entry(dist.name().clone())
+            .or_default()
+            .metadata_fetch += elapsed;
+
+        let index = match dist {
+            Dist::Built(BuiltDist::Registry(wheel)) => &wheel.index,
+            Dist::Source(SourceDist::Registry(sdist)) => &sdist.index,
+            _ => return,
+        };
+        let mut stats = self.indexes.entry(index.to_string()).or_default();
+        stats.requests += 1;
+        stats.metadata_fetch += elapsed;
+    }
+
+    /// Record the number of conflicts involving the given package.
+    pub(crate) fn record_conflicts(&self, package: &PackageName, conflicts: usize) {
+        self.packages.entry(package.clone()).or_default().conflicts += conflicts;
+    }
+
+    /// Record the number of source distributions of the given package that were built, as reported
+    /// by the build context (i.e., excluding any whose metadata was read from the cache or the
+    /// index).
+    pub fn record_source_builds(&self, package: &PackageName, builds: usize) {
+        self.packages
+            .entry(package.clone())
+            .or_default()
+            .source_builds += builds;
+    }
+
+    /// Record the position of the given package in the resolver's priority queue.
+    pub(crate) fn record_priority(&self, package: &PackageName, priority: usize) {
+        self.packages.entry(package.clone()).or_default().priority = Some(priority);
+    }
+
+    /// Return a report of the collected statistics, labelled with the given description of the
+    /// resolution attempt, with the packages that caused the most conflicts first.
+    pub fn report(&self, label: String) -> ResolverStatsReport {
+        let mut packages = self
+            .packages
+            .iter()
+            .map(|entry| {
+                let stats = entry.value();
+                PackageStatsReport {
+                    name: entry.key().to_string(),
+                    priority: stats.priority,
+                    versions_tried: stats.versions_tried,
+                    conflicts: stats.conflicts,
+                    source_builds: stats.source_builds,
+                    versions_fetch_secs: stats.versions_fetch.as_secs_f64(),
+                    metadata_fetch_secs: stats.metadata_fetch.as_secs_f64(),
+                }
+            })
+            .collect::<Vec<_>>();
+        packages.sort_by(|a, b| {
+            b.conflicts
+                .cmp(&a.conflicts)
+                .then_with(|| b.versions_tried.cmp(&a.versions_tried))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        let mut indexes = self
+            .indexes
+            .iter()
+            .map(|entry| IndexStatsReport {
+                url: entry.key().clone(),
+                requests: entry.value().requests,
+                metadata_fetch_secs: entry.value().metadata_fetch.as_secs_f64(),
+            })
+            .collect::<Vec<_>>();
+        indexes.sort_by(|a, b| a.url.cmp(&b.url));
+
+        ResolverStatsReport {
+            label,
+            packages,
+            indexes,
+        }
+    }
+}
+
+/// A machine-readable report of the [`ResolverStats`] collected over a resolution attempt.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolverStatsReport {
+    /// A description of the resolution attempt (e.g., `attempt 2`).
+    pub label: String,
+    /// The statistics for each package, with the packages that caused the most conflicts first.
+    pub packages: Vec<PackageStatsReport>,
+    /// The statistics for each index from which distribution metadata was fetched.
+    pub indexes: Vec<IndexStatsReport>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PackageStatsReport {
+    pub name: String,
+    pub priority: Option<usize>,
+    pub versions_tried: usize,
+    pub conflicts: usize,
+    pub source_builds: usize,
+    pub versions_fetch_secs: f64,
+    pub metadata_fetch_secs: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexStatsReport {
+    pub url: String,
+    pub requests: usize,
+    pub metadata_fetch_secs: f64,
+}
+
+impl Display for ResolverStatsReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Resolver statistics for {}:", self.label)?;
+        writeln!(
+            f,
+            "{:<32} {:>8} {:>8} {:>9} {:>6} {:>10} {:>10}",
+            "package", "priority", "versions", "conflicts", "builds", "versions", "metadata"
+        )?;
+        for package in &self.packages {
+            writeln!(
+                f,
+                "{:<32} {:>8} {:>8} {:>9} {:>6} {:>9.2}s {:>9.2}s",
+                package.name,
+                package
+                    .priority
+                    .map_or_else(|| "-".to_string(), |priority| priority.to_string()),
+                package.versions_tried,
+                package.conflicts,
+                package.source_builds,
+                package.versions_fetch_secs,
+                package.metadata_fetch_secs,
+            )?;
+        }
+        if !self.indexes.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "{:<60} {:>8} {:>10}", "index", "requests", "metadata")?;
+            for index in &self.indexes {
+                writeln!(
+                    f,
+                    "{:<60} {:>8} {:>9.2}s",
+                    index.url, index.requests, index.metadata_fetch_secs,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use uv_normalize::PackageName;
+
+    use super::ResolverStats;
+
+    #[test]
+    fn report_order() {
+        let stats = ResolverStats::default();
+        let black = PackageName::from_str("black").unwrap();
+        let click = PackageName::from_str("click").unwrap();
+
+        stats.record_priority(&black, 0);
+        stats.record_priority(&click, 1);
+        stats.record_version(&black);
+        stats.record_version(&click);
+        stats.record_version(&click);
+        stats.record_conflicts(&click, 3);
+        stats.record_versions_fetch(&black, Duration::from_millis(250));
+
+        stats.record_source_builds(&black, 1);
+
+        let report = stats.report("attempt 1".to_string());
+        assert_eq!(report.label, "attempt 1");
+        let names = report
+            .packages
+            .iter()
+            .map(|package| package.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["click", "black"]);
+        assert_eq!(report.packages[0].versions_tried, 2);
+        assert_eq!(report.packages[0].conflicts, 3);
+        assert_eq!(report.packages[1].priority, Some(0));
+        assert_eq!(report.packages[1].source_builds, 1);
+        assert!((report.packages[1].versions_fetch_secs - 0.25).abs() < f64::EPSILON);
+    }
+}
@@ -3,7 +3,7 @@ use std::sync::OnceLock;
 
 use chrono::{DateTime, Utc};
 use rustc_hash::FxHashSet;
-use tracing::{instrument, warn};
+use tracing::{debug, instrument, warn};
 
 use distribution_filename::{DistFilename, WheelFilename};
 use distribution_types::{
@@ -19,7 +19,7 @@ use uv_normalize::PackageName;
 use uv_types::{NoBinary, NoBuild};
 use uv_warnings::warn_user_once;
 
-use crate::{python_requirement::PythonRequirement, yanks::AllowedYanks};
+use crate::{python_requirement::PythonRequirement, yanks::AllowedYanks, MissingUploadTime};
 
 /// A map from versions to distributions.
 #[derive(Debug)]
@@ -47,6 +47,7 @@ impl VersionMap {
         python_requirement: &PythonRequirement,
         allowed_yanks: &AllowedYanks,
         exclude_newer: Option<&DateTime<Utc>>,
+        missing_upload_time: MissingUploadTime,
         flat_index: Option<FlatDistributions>,
         no_binary: &NoBinary,
         no_build: &NoBuild,
@@ -65,6 +66,7 @@ impl VersionMap {
                 LazyPrioritizedDist::OnlySimple(SimplePrioritizedDist {
                     datum_index,
                     dist: OnceLock::new(),
+                    missing_upload_time: OnceLock::new(),
                 }),
             );
         }
@@ -119,6 +121,7 @@ impl VersionMap {
                 tags: tags.clone(),
                 python_requirement: python_requirement.clone(),
                 exclude_newer: exclude_newer.copied(),
+                missing_upload_time,
                 allowed_yanks,
             }),
         }
@@ -183,6 +186,29 @@ impl VersionMap {
         }
     }
 
+    /// Return the filename of the first file that is missing an upload time, along with the index
+    /// that served it, if the map applies an `--exclude-newer` cutoff and
+    /// [`MissingUploadTime::Error`] was requested.
+    ///
+    /// Only the versions that have been materialized (i.e., considered by the resolver) are
+    /// inspected.
+    pub(crate) fn first_missing_upload_time(&self) -> Option<(String, &IndexUrl)> {
+        let VersionMapInner::Lazy(ref lazy) = self.inner else {
+            return None;
+        };
+        if lazy.exclude_newer.is_none() || lazy.missing_upload_time != MissingUploadTime::Error {
+            return None;
+        }
+        lazy.map
+            .values()
+            .find_map(|dist| match dist {
+                LazyPrioritizedDist::OnlySimple(simple)
+                | LazyPrioritizedDist::Both { simple, .. } => simple.missing_upload_time.get(),
+                LazyPrioritizedDist::OnlyFlat(_) => None,
+            })
+            .map(|filename| (filename.clone(), &lazy.index))
+    }
+
     /// Returns the total number of distinct versions in this map.
     ///
     /// Note that this may include versions of distributions that are not
@@ -300,6 +326,8 @@ struct VersionMapLazy {
     python_requirement: PythonRequirement,
     /// Whether files newer than this timestamp should be excluded or not.
     exclude_newer: Option<DateTime<Utc>>,
+    /// How to treat files without an upload time, when `exclude_newer` is set.
+    missing_upload_time: MissingUploadTime,
     /// Which yanked versions are allowed
     allowed_yanks: FxHashSet<Version>,
 }
@@ -361,13 +389,28 @@ impl VersionMapLazy {
                         Some(&upload_time) if upload_time >= exclude_newer.timestamp_millis() => {
                             (true, Some(upload_time))
                         }
-                        None => {
-                            warn_user_once!(
-                                "{} is missing an upload date, but user provided: {exclude_newer}",
-                                file.filename,
-                            );
-                            (true, None)
-                        }
+                        None => match self.missing_upload_time {
+                            MissingUploadTime::Include => {
+                                debug!(
+                                    "{} is missing an upload date, but user provided: {exclude_newer}; including it",
+                                    file.filename,
+                                );
+                                (false, None)
+                            }
+                            MissingUploadTime::Warn => {
+                                warn_user_once!(
+                                    "{} is missing an upload date, but user provided: {exclude_newer}",
+                                    file.filename,
+                                );
+                                (true, None)
+                            }
+                            // Record the file, such that the resolver can fail once it has
+                            // considered this version; exclude it in the meantime.
+                            MissingUploadTime::Error => {
+                                let _ = simple.missing_upload_time.set(file.filename.clone());
+                                (true, None)
+                            }
+                        },
                         _ => (false, None),
                     }
                 } else {
@@ -538,4 +581,7 @@ struct SimplePrioritizedDist {
     /// construct a distribution. (One easy way to effect this, at the time
     /// of writing, is to use `--exclude-newer 1900-01-01`.)
     dist: OnceLock<Option<PrioritizedDist>>,
+    /// The filename of the first file that was found to be missing an upload time during
+    /// initialization, if any, when [`MissingUploadTime::Error`] was requested.
+    missing_upload_time: OnceLock<String>,
 }
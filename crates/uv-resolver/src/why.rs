@@ -0,0 +1,541 @@
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
+use petgraph::Direction;
+use pubgrub::range::Range;
+use rustc_hash::FxHashMap;
+
+use distribution_types::{IncompatibleDist, Name, ResolvedDist, VersionOrUrl};
+use pep440_rs::Version;
+use pep508_rs::{MarkerEnvironment, MarkerTree, Requirement};
+use uv_normalize::PackageName;
+
+use crate::candidate_selector::CandidateDist;
+use crate::pubgrub::{specifiers, PubGrubSpecifier};
+use crate::resolver::{InMemoryIndex, VersionsResponse};
+use crate::Manifest;
+
+/// The maximum number of dependency paths to report for a single package.
+///
+/// The number of paths can grow exponentially with the depth of the graph (e.g., when many
+/// packages share a set of common dependencies), so enumeration stops at this limit.
+const MAX_PATHS: usize = 100;
+
+/// An explanation of why a package was included in a resolution, and why it was pinned to the
+/// selected version.
+#[derive(Debug, Clone)]
+pub struct Why {
+    /// The name of the package.
+    name: PackageName,
+    /// The selected version of the package, or `None` if it was resolved from a URL.
+    version: Option<Version>,
+    /// The markers under which this version of the package applies, if it was only selected in
+    /// some forks of a forked resolution.
+    marker: Option<MarkerTree>,
+    /// The dependency paths from the root requirements to the package, up to [`MAX_PATHS`].
+    paths: Vec<Vec<WhyEdge>>,
+    /// Whether any paths were omitted, as there were more than [`MAX_PATHS`].
+    truncated: bool,
+    /// The newer versions of the package that were rejected, along with the reason for each.
+    rejected: Vec<(Version, RejectionReason)>,
+}
+
+/// A single dependency edge along a path from the root requirements to a package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WhyEdge {
+    /// The package that declared the dependency, or `None` for the root requirements.
+    pub from: Option<PackageName>,
+    /// The package that was depended upon.
+    pub to: PackageName,
+    /// The version range requested by the dependency.
+    pub range: Range<Version>,
+}
+
+impl Display for WhyEdge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.to, specifiers(&self.range).join(","))
+    }
+}
+
+/// The reason that a version newer than the selected version was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RejectionReason {
+    /// The version is excluded by a dependency specifier, e.g., another package's upper bound.
+    ///
+    /// If `package` is `None`, the specifier was declared by the root requirements.
+    Dependency {
+        package: Option<PackageName>,
+        range: Range<Version>,
+    },
+    /// The version is excluded by a constraint (e.g., via `--constraint`).
+    Constraint(Range<Version>),
+    /// The version is excluded by an override (e.g., via `--override`), which replaces any
+    /// specifiers declared for the package.
+    Override(Range<Version>),
+    /// The version has no usable distributions, e.g., because it requires a different Python
+    /// version, was published after the `--exclude-newer` cutoff, was yanked, or has no compatible
+    /// wheels.
+    Incompatible(IncompatibleDist),
+    /// The version is usable, but the resolver selected an older version, e.g., because the
+    /// dependencies of the newer version conflicted with the rest of the resolution.
+    NotSelected,
+}
+
+impl Why {
+    /// Explain the inclusion of the package at `target` in the given resolution graph.
+    ///
+    /// The constraints and overrides are read from the [`Manifest`], and filtered by the
+    /// [`MarkerEnvironment`], which should be the same values that produced the graph.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn from_graph(
+        petgraph: &petgraph::graph::Graph<ResolvedDist, Range<Version>, petgraph::Directed>,
+        requirements: &FxHashMap<PackageName, Range<Version>>,
+        manifest: &Manifest,
+        markers: &MarkerEnvironment,
+        target: NodeIndex,
+        marker: Option<MarkerTree>,
+        index: &InMemoryIndex,
+    ) -> Self {
+        let dist = &petgraph[target];
+        let name = dist.name().clone();
+
+        // Collect the paths from the root requirements to the target.
+        let (paths, truncated) = collect_paths(petgraph, requirements, target, |dist| dist.name());
+
+        let VersionOrUrl::Version(version) = dist.version_or_url() else {
+            return Self {
+                name,
+                version: None,
+                marker,
+                paths,
+                truncated,
+                rejected: Vec::new(),
+            };
+        };
+        let version = version.clone();
+
+        // The ranges imposed by any overrides and constraints, which are checked first, as they
+        // are also reflected in the ranges of the root requirements and the graph's edges.
+        let override_range = manifest
+            .overrides
+            .get(&name)
+            .and_then(|overrides| applicable_range(overrides, markers));
+        let constraint_range = manifest
+            .constraints
+            .get(&name)
+            .and_then(|constraints| applicable_range(constraints, markers));
+
+        // Determine why each newer version was rejected.
+        let mut rejected = Vec::new();
+        if let Some(response) = index.packages.get(&name) {
+            if let VersionsResponse::Found(ref version_maps) = *response {
+                let newer = version_maps
+                    .iter()
+                    .flat_map(|version_map| version_map.iter().map(|(version, _)| version))
+                    .filter(|candidate| **candidate > version)
+                    // Ignore pre-releases, unless the selected version is itself a pre-release.
+                    .filter(|candidate| version.any_prerelease() || !candidate.any_prerelease())
+                    .collect::<BTreeSet<_>>();
+
+                for candidate in newer {
+                    let reason = if let Some(range) = override_range
+                        .as_ref()
+                        .filter(|range| !range.contains(candidate))
+                    {
+                        RejectionReason::Override(range.clone())
+                    } else if let Some(range) = constraint_range
+                        .as_ref()
+                        .filter(|range| !range.contains(candidate))
+                    {
+                        RejectionReason::Constraint(range.clone())
+                    } else if let Some(range) = requirements
+                        .get(&name)
+                        .filter(|range| !range.contains(candidate))
+                    {
+                        RejectionReason::Dependency {
+                            package: None,
+                            range: range.clone(),
+                        }
+                    } else if let Some(edge) = petgraph
+                        .edges_directed(target, Direction::Incoming)
+                        .find(|edge| !edge.weight().contains(candidate))
+                    {
+                        RejectionReason::Dependency {
+                            package: Some(petgraph[edge.source()].name().clone()),
+                            range: edge.weight().clone(),
+                        }
+                    } else if let Some(CandidateDist::Incompatible(incompatibility)) = version_maps
+                        .iter()
+                        .find_map(|version_map| version_map.get(candidate))
+                        .map(CandidateDist::from)
+                    {
+                        RejectionReason::Incompatible(incompatibility)
+                    } else {
+                        RejectionReason::NotSelected
+                    };
+                    rejected.push((candidate.clone(), reason));
+                }
+            }
+        }
+
+        Self {
+            name,
+            version: Some(version),
+            marker,
+            paths,
+            truncated,
+            rejected,
+        }
+    }
+
+    /// Return the name of the package.
+    pub fn name(&self) -> &PackageName {
+        &self.name
+    }
+
+    /// Return the selected version of the package, if it was resolved from a registry.
+    pub fn version(&self) -> Option<&Version> {
+        self.version.as_ref()
+    }
+
+    /// Return the markers under which this version of the package applies, if it was only
+    /// selected in some forks of a forked resolution.
+    pub fn marker(&self) -> Option<&MarkerTree> {
+        self.marker.as_ref()
+    }
+
+    /// Return the dependency paths from the root requirements to the package.
+    ///
+    /// At most [`MAX_PATHS`] paths are returned; see [`Why::truncated`].
+    pub fn paths(&self) -> &[Vec<WhyEdge>] {
+        &self.paths
+    }
+
+    /// Returns `true` if some dependency paths were omitted, as there were too many to enumerate.
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Return the newer versions of the package that were rejected, along with the reason for
+    /// each.
+    pub fn rejected(&self) -> &[(Version, RejectionReason)] {
+        &self.rejected
+    }
+}
+
+/// Return the version range allowed by the given requirements (e.g., the constraints for a
+/// package), ignoring any requirements whose markers don't apply to the target environment.
+///
+/// Returns `None` if none of the requirements apply.
+fn applicable_range(
+    requirements: &[Requirement],
+    markers: &MarkerEnvironment,
+) -> Option<Range<Version>> {
+    requirements
+        .iter()
+        .filter(|requirement| requirement.evaluate_markers(markers, &[]))
+        .map(|requirement| match &requirement.version_or_url {
+            Some(pep508_rs::VersionOrUrl::VersionSpecifier(specifiers)) => specifiers
+                .iter()
+                .filter_map(|specifier| PubGrubSpecifier::try_from(specifier).ok())
+                .fold(Range::full(), |range, specifier| {
+                    range.intersection(&specifier.into())
+                }),
+            Some(pep508_rs::VersionOrUrl::Url(_)) | None => Range::full(),
+        })
+        .reduce(|left, right| left.intersection(&right))
+}
+
+/// Collect the dependency paths from the root requirements to the node at `target`, walking the
+/// graph upwards.
+///
+/// Returns the paths (sorted, and without duplicates), along with whether enumeration stopped
+/// early after reaching [`MAX_PATHS`].
+fn collect_paths<N>(
+    petgraph: &petgraph::graph::Graph<N, Range<Version>, petgraph::Directed>,
+    requirements: &FxHashMap<PackageName, Range<Version>>,
+    target: NodeIndex,
+    name: impl Fn(&N) -> &PackageName,
+) -> (Vec<Vec<WhyEdge>>, bool) {
+    let target_name = name(&petgraph[target]);
+
+    let mut paths = Vec::new();
+    let mut truncated = false;
+    let mut stack = vec![(target, Vec::<WhyEdge>::new())];
+    while let Some((node, path)) = stack.pop() {
+        let node_name = name(&petgraph[node]);
+
+        // If the package is a direct dependency, the path is complete.
+        if let Some(range) = requirements.get(node_name) {
+            if paths.len() == MAX_PATHS {
+                truncated = true;
+                break;
+            }
+            let mut path = path.clone();
+            path.push(WhyEdge {
+                from: None,
+                to: node_name.clone(),
+                range: range.clone(),
+            });
+            path.reverse();
+            paths.push(path);
+        }
+
+        // Continue walking up the graph, avoiding cycles.
+        for edge in petgraph.edges_directed(node, Direction::Incoming) {
+            let source = name(&petgraph[edge.source()]);
+            if source == target_name || path.iter().any(|step| step.from.as_ref() == Some(source)) {
+                continue;
+            }
+            let mut path = path.clone();
+            path.push(WhyEdge {
+                from: Some(source.clone()),
+                to: node_name.clone(),
+                range: edge.weight().clone(),
+            });
+            stack.push((edge.source(), path));
+        }
+    }
+
+    paths.sort_by(|a, b| {
+        a.iter()
+            .map(|edge| &edge.to)
+            .cmp(b.iter().map(|edge| &edge.to))
+    });
+    paths.dedup();
+
+    (paths, truncated)
+}
+
+impl Display for Why {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.version {
+            Some(version) => write!(f, "{}=={}", self.name, version)?,
+            None => write!(f, "{}", self.name)?,
+        }
+        match &self.marker {
+            Some(marker) => writeln!(f, " ; {marker}")?,
+            None => writeln!(f)?,
+        }
+
+        for path in &self.paths {
+            writeln!(f, "    {}", path.iter().join(" -> "))?;
+        }
+        if self.truncated {
+            writeln!(f, "    ... (showing the first {MAX_PATHS} paths)")?;
+        }
+
+        if !self.rejected.is_empty() {
+            writeln!(f, "Newer versions were rejected:")?;
+            for (reason, group) in &self.rejected.iter().group_by(|(_, reason)| reason) {
+                let versions = group.map(|(version, _)| version).join(", ");
+                match reason {
+                    RejectionReason::Dependency {
+                        package: Some(package),
+                        range,
+                    } => writeln!(
+                        f,
+                        "    {versions}: `{package}` requires `{}{}`",
+                        self.name,
+                        specifiers(range).join(",")
+                    )?,
+                    RejectionReason::Dependency {
+                        package: None,
+                        range,
+                    } => writeln!(
+                        f,
+                        "    {versions}: the requirements specify `{}{}`",
+                        self.name,
+                        specifiers(range).join(",")
+                    )?,
+                    RejectionReason::Constraint(range) => writeln!(
+                        f,
+                        "    {versions}: the constraints specify `{}{}`",
+                        self.name,
+                        specifiers(range).join(",")
+                    )?,
+                    RejectionReason::Override(range) => writeln!(
+                        f,
+                        "    {versions}: the overrides specify `{}{}`",
+                        self.name,
+                        specifiers(range).join(",")
+                    )?,
+                    RejectionReason::Incompatible(incompatibility) => {
+                        writeln!(f, "    {versions}: {incompatibility}")?;
+                    }
+                    RejectionReason::NotSelected => {
+                        writeln!(f, "    {versions}: not selected by the resolver")?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pep440_rs::Version;
+    use pep508_rs::MarkerTree;
+    use pubgrub::range::Range;
+    use rustc_hash::FxHashMap;
+    use uv_normalize::PackageName;
+
+    use super::{collect_paths, RejectionReason, Why, WhyEdge, MAX_PATHS};
+
+    fn name(name: &str) -> PackageName {
+        PackageName::from_str(name).unwrap()
+    }
+
+    #[test]
+    fn display() {
+        let why = Why {
+            name: name("werkzeug"),
+            version: Some(Version::from_str("2.3.8").unwrap()),
+            marker: None,
+            truncated: false,
+            paths: vec![vec![
+                WhyEdge {
+                    from: None,
+                    to: name("flask"),
+                    range: Range::higher_than(Version::from_str("2").unwrap()),
+                },
+                WhyEdge {
+                    from: Some(name("flask")),
+                    to: name("werkzeug"),
+                    range: Range::between(
+                        Version::from_str("2.3.7").unwrap(),
+                        Version::from_str("3").unwrap(),
+                    ),
+                },
+            ]],
+            rejected: vec![
+                (
+                    Version::from_str("3.0.0").unwrap(),
+                    RejectionReason::Dependency {
+                        package: Some(name("flask")),
+                        range: Range::between(
+                            Version::from_str("2.3.7").unwrap(),
+                            Version::from_str("3").unwrap(),
+                        ),
+                    },
+                ),
+                (
+                    Version::from_str("3.0.1").unwrap(),
+                    RejectionReason::Dependency {
+                        package: Some(name("flask")),
+                        range: Range::between(
+                            Version::from_str("2.3.7").unwrap(),
+                            Version::from_str("3").unwrap(),
+                        ),
+                    },
+                ),
+                (
+                    Version::from_str("3.0.2").unwrap(),
+                    RejectionReason::NotSelected,
+                ),
+            ],
+        };
+
+        insta::assert_snapshot!(why, @r###"
+        werkzeug==2.3.8
+            flask>=2 -> werkzeug>=2.3.7,<3
+        Newer versions were rejected:
+            3.0.0, 3.0.1: `flask` requires `werkzeug>=2.3.7,<3`
+            3.0.2: not selected by the resolver
+        "###);
+    }
+
+    #[test]
+    fn display_constraint() {
+        let why = Why {
+            name: name("sniffio"),
+            version: Some(Version::from_str("1.3.0").unwrap()),
+            marker: Some(MarkerTree::from_str("sys_platform == 'linux'").unwrap()),
+            truncated: false,
+            paths: vec![vec![WhyEdge {
+                from: None,
+                to: name("sniffio"),
+                range: Range::full(),
+            }]],
+            rejected: vec![
+                (
+                    Version::from_str("1.3.1").unwrap(),
+                    RejectionReason::Constraint(Range::strictly_lower_than(
+                        Version::from_str("1.3.1").unwrap(),
+                    )),
+                ),
+                (
+                    Version::from_str("1.4.0").unwrap(),
+                    RejectionReason::Override(Range::strictly_lower_than(
+                        Version::from_str("1.4").unwrap(),
+                    )),
+                ),
+            ],
+        };
+
+        insta::assert_snapshot!(why, @r###"
+        sniffio==1.3.0 ; sys_platform == 'linux'
+            sniffio
+        Newer versions were rejected:
+            1.3.1: the constraints specify `sniffio<1.3.1`
+            1.4.0: the overrides specify `sniffio<1.4`
+        "###);
+    }
+
+    #[test]
+    fn paths_truncated() {
+        // Build a graph of layers in which every package depends on every package in the next
+        // layer, such that the number of paths to the final package grows exponentially.
+        let mut petgraph = petgraph::graph::Graph::<PackageName, Range<Version>>::new();
+        let mut requirements = FxHashMap::default();
+        let mut previous = Vec::new();
+        for layer in 0..10 {
+            let current = (0..3)
+                .map(|index| petgraph.add_node(name(&format!("layer{layer}-{index}"))))
+                .collect::<Vec<_>>();
+            if layer == 0 {
+                for node in &current {
+                    requirements.insert(petgraph[*node].clone(), Range::full());
+                }
+            }
+            for source in &previous {
+                for target in &current {
+                    petgraph.add_edge(*source, *target, Range::full());
+                }
+            }
+            previous = current;
+        }
+        let target = petgraph.add_node(name("target"));
+        for source in &previous {
+            petgraph.add_edge(*source, target, Range::full());
+        }
+
+        let (paths, truncated) = collect_paths(&petgraph, &requirements, target, |name| name);
+        assert!(truncated);
+        assert_eq!(paths.len(), MAX_PATHS);
+        assert!(paths.iter().all(|path| path.len() == 11));
+    }
+
+    #[test]
+    fn paths_deduplicated() {
+        // Multiple edges between the same packages (e.g., via different extras) yield a single
+        // path.
+        let mut petgraph = petgraph::graph::Graph::<PackageName, Range<Version>>::new();
+        let root = petgraph.add_node(name("root"));
+        let target = petgraph.add_node(name("target"));
+        petgraph.add_edge(root, target, Range::full());
+        petgraph.add_edge(root, target, Range::full());
+        let requirements = FxHashMap::from_iter([(name("root"), Range::full())]);
+
+        let (paths, truncated) = collect_paths(&petgraph, &requirements, target, |name| name);
+        assert!(!truncated);
+        assert_eq!(paths.len(), 1);
+    }
+}
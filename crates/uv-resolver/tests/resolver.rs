@@ -16,13 +16,14 @@ use platform_tags::{Arch, Os, Platform, Tags};
 use uv_cache::Cache;
 use uv_client::{FlatIndex, RegistryClientBuilder};
 use uv_interpreter::{find_default_python, Interpreter, PythonEnvironment};
+use uv_normalize::PackageName;
 use uv_resolver::{
     DisplayResolutionGraph, Exclusions, InMemoryIndex, Manifest, Options, OptionsBuilder,
     PreReleaseMode, Preference, ResolutionGraph, ResolutionMode, Resolver,
 };
 use uv_types::{
-    BuildContext, BuildIsolation, BuildKind, Constraints, EmptyInstalledPackages, NoBinary,
-    NoBuild, Overrides, SetupPyStrategy, SourceBuildTrait,
+    BuildContext, BuildIsolation, BuildKind, Concurrency, Constraints, EmptyInstalledPackages,
+    NoBinary, NoBuild, Overrides, SetupPyStrategy, SourceBuildTrait,
 };
 
 // Exclude any packages uploaded after this date.
@@ -36,14 +37,16 @@ struct DummyContext {
     cache: Cache,
     interpreter: Interpreter,
     index_locations: IndexLocations,
+    concurrency: Concurrency,
 }
 
 impl DummyContext {
-    fn new(cache: Cache, interpreter: Interpreter) -> Self {
+    fn new(cache: Cache, interpreter: Interpreter, concurrency: Concurrency) -> Self {
         Self {
             cache,
             interpreter,
             index_locations: IndexLocations::default(),
+            concurrency,
         }
     }
 }
@@ -75,6 +78,10 @@ impl BuildContext for DummyContext {
         SetupPyStrategy::default()
     }
 
+    fn concurrency(&self) -> Concurrency {
+        self.concurrency
+    }
+
     fn index_locations(&self) -> &IndexLocations {
         &self.index_locations
     }
@@ -97,6 +104,8 @@ impl BuildContext for DummyContext {
     ) -> Result<Self::SourceDistBuilder> {
         Ok(DummyBuilder)
     }
+
+    fn record_build_requirements(&self, _: &SourceDist, _: Vec<Requirement>) {}
 }
 
 struct DummyBuilder;
@@ -109,6 +118,10 @@ impl SourceBuildTrait for DummyBuilder {
     async fn wheel<'a>(&'a self, _: &'a Path) -> Result<String> {
         panic!("The test should not need to build source distributions")
     }
+
+    fn build_requirements(&self) -> Option<&Resolution> {
+        panic!("The test should not need to build source distributions")
+    }
 }
 
 async fn resolve(
@@ -116,6 +129,16 @@ async fn resolve(
     options: Options,
     markers: &'static MarkerEnvironment,
     tags: &Tags,
+) -> Result<ResolutionGraph> {
+    resolve_with_concurrency(manifest, options, markers, tags, Concurrency::default()).await
+}
+
+async fn resolve_with_concurrency(
+    manifest: Manifest,
+    options: Options,
+    markers: &'static MarkerEnvironment,
+    tags: &Tags,
+    concurrency: Concurrency,
 ) -> Result<ResolutionGraph> {
     let client = RegistryClientBuilder::new(Cache::temp()?).build();
     let flat_index = FlatIndex::default();
@@ -124,7 +147,7 @@ async fn resolve(
     let real_interpreter =
         find_default_python(&Cache::temp().unwrap()).expect("Expected a python to be installed");
     let interpreter = Interpreter::artificial(real_interpreter.platform().clone(), markers.clone());
-    let build_context = DummyContext::new(Cache::temp()?, interpreter.clone());
+    let build_context = DummyContext::new(Cache::temp()?, interpreter.clone(), concurrency);
     let installed_packages = EmptyInstalledPackages;
     let resolver = Resolver::new(
         manifest,
@@ -174,6 +197,38 @@ async fn black() -> Result<()> {
     Ok(())
 }
 
+/// Resolve `black` with a single in-flight metadata request at a time.
+#[tokio::test]
+async fn black_serial() -> Result<()> {
+    let manifest = Manifest::simple(vec![Requirement::from_str("black<=23.9.1").unwrap()]);
+    let options = OptionsBuilder::new()
+        .exclude_newer(Some(*EXCLUDE_NEWER))
+        .build();
+    let concurrency = Concurrency {
+        downloads: 1,
+        ..Concurrency::default()
+    };
+
+    let resolution =
+        resolve_with_concurrency(manifest, options, &MARKERS_311, &TAGS_311, concurrency).await?;
+
+    assert_snapshot!(DisplayResolutionGraph::from(&resolution), @r###"
+    black==23.9.1
+    click==8.1.7
+        # via black
+    mypy-extensions==1.0.0
+        # via black
+    packaging==23.2
+        # via black
+    pathspec==0.11.2
+        # via black
+    platformdirs==4.0.0
+        # via black
+    "###);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn black_colorama() -> Result<()> {
     let manifest = Manifest::simple(vec![
@@ -274,6 +329,7 @@ async fn black_mypy_extensions() -> Result<()> {
         ]),
         Overrides::default(),
         vec![],
+        vec![],
         None,
         vec![],
         Exclusions::default(),
@@ -302,6 +358,42 @@ async fn black_mypy_extensions() -> Result<()> {
     Ok(())
 }
 
+/// Resolve `black` while excluding `platformdirs`, to ensure that the requirement on an excluded
+/// package is dropped entirely.
+#[tokio::test]
+async fn black_exclude() -> Result<()> {
+    let manifest = Manifest::new(
+        vec![Requirement::from_str("black<=23.9.1").unwrap()],
+        Constraints::default(),
+        Overrides::default(),
+        vec![PackageName::from_str("platformdirs").unwrap()],
+        vec![],
+        None,
+        vec![],
+        Exclusions::default(),
+        vec![],
+    );
+    let options = OptionsBuilder::new()
+        .exclude_newer(Some(*EXCLUDE_NEWER))
+        .build();
+
+    let resolution = resolve(manifest, options, &MARKERS_311, &TAGS_311).await?;
+
+    assert_snapshot!(DisplayResolutionGraph::from(&resolution), @r###"
+    black==23.9.1
+    click==8.1.7
+        # via black
+    mypy-extensions==1.0.0
+        # via black
+    packaging==23.2
+        # via black
+    pathspec==0.11.2
+        # via black
+    "###);
+
+    Ok(())
+}
+
 /// Resolve `black` with a constraint on `mypy-extensions[extra]`, to ensure that extras are
 /// ignored when resolving constraints.
 #[tokio::test]
@@ -313,6 +405,7 @@ async fn black_mypy_extensions_extra() -> Result<()> {
         ]),
         Overrides::default(),
         vec![],
+        vec![],
         None,
         vec![],
         Exclusions::default(),
@@ -350,6 +443,7 @@ async fn black_flake8() -> Result<()> {
         Constraints::from_requirements(vec![Requirement::from_str("flake8<1").unwrap()]),
         Overrides::default(),
         vec![],
+        vec![],
         None,
         vec![],
         Exclusions::default(),
@@ -438,6 +532,7 @@ async fn black_respect_preference() -> Result<()> {
         vec![Requirement::from_str("black<=23.9.1")?],
         Constraints::default(),
         Overrides::default(),
+        vec![],
         vec![Preference::from_requirement(Requirement::from_str(
             "black==23.9.0",
         )?)],
@@ -476,6 +571,7 @@ async fn black_ignore_preference() -> Result<()> {
         vec![Requirement::from_str("black<=23.9.1")?],
         Constraints::default(),
         Overrides::default(),
+        vec![],
         vec![Preference::from_requirement(Requirement::from_str(
             "black==23.9.2",
         )?)],
@@ -522,7 +618,7 @@ async fn black_disallow_prerelease() -> Result<()> {
     assert_snapshot!(err, @r###"
     Because only black>20.0 is available and you require black<=20.0, we can conclude that the requirements are unsatisfiable.
 
-    hint: Pre-releases are available for black in the requested range (e.g., 19.10b0), but pre-releases weren't enabled (try: `--prerelease=allow`)
+    hint: Pre-releases are available for black in the requested range (e.g., 19.10b0), but pre-releases weren't enabled (try: `--prerelease-package black` or `--prerelease=allow`)
     "###);
 
     Ok(())
@@ -543,7 +639,41 @@ async fn black_allow_prerelease_if_necessary() -> Result<()> {
     assert_snapshot!(err, @r###"
     Because only black>20.0 is available and you require black<=20.0, we can conclude that the requirements are unsatisfiable.
 
-    hint: Pre-releases are available for black in the requested range (e.g., 19.10b0), but pre-releases weren't enabled (try: `--prerelease=allow`)
+    hint: Pre-releases are available for black in the requested range (e.g., 19.10b0), but pre-releases weren't enabled (try: `--prerelease-package black` or `--prerelease=allow`)
+    "###);
+
+    Ok(())
+}
+
+/// Allow pre-releases for `black` alone, via `--prerelease-package`, despite pre-releases being
+/// disallowed otherwise.
+#[tokio::test]
+async fn black_allow_prerelease_package() -> Result<()> {
+    let manifest = Manifest::simple(vec![Requirement::from_str("black<=20.0").unwrap()]);
+    let options = OptionsBuilder::new()
+        .prerelease_mode(PreReleaseMode::Disallow)
+        .prerelease_packages(vec![PackageName::from_str("black").unwrap()])
+        .exclude_newer(Some(*EXCLUDE_NEWER))
+        .build();
+
+    let resolution = resolve(manifest, options, &MARKERS_311, &TAGS_311).await?;
+
+    assert_snapshot!(DisplayResolutionGraph::from(&resolution), @r###"
+    appdirs==1.4.4
+        # via black
+    attrs==23.1.0
+        # via black
+    black==19.10b0
+    click==8.1.7
+        # via black
+    pathspec==0.11.2
+        # via black
+    regex==2023.10.3
+        # via black
+    toml==0.10.2
+        # via black
+    typed-ast==1.5.5
+        # via black
     "###);
 
     Ok(())
@@ -664,7 +794,7 @@ async fn msgraph_sdk() -> Result<()> {
     Because only msgraph-core<1.0.0a2 is available and msgraph-sdk==1.0.0 depends on msgraph-core>=1.0.0a2, we can conclude that msgraph-sdk==1.0.0 cannot be used.
     And because you require msgraph-sdk==1.0.0, we can conclude that the requirements are unsatisfiable.
 
-    hint: msgraph-core was requested with a pre-release marker (e.g., msgraph-core>=1.0.0a2), but pre-releases weren't enabled (try: `--prerelease=allow`)
+    hint: msgraph-core was requested with a pre-release marker (e.g., msgraph-core>=1.0.0a2), but pre-releases weren't enabled (try: `--prerelease-package msgraph-core` or `--prerelease=allow`)
     "###);
 
     Ok(())
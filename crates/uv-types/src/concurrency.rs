@@ -0,0 +1,48 @@
+use std::num::NonZeroUsize;
+
+/// Concurrency limits for the various stages of an installation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Concurrency {
+    /// The maximum number of in-flight concurrent downloads.
+    pub downloads: usize,
+    /// The maximum number of concurrent source distribution builds.
+    pub builds: usize,
+    /// The number of threads used to install wheels.
+    pub installs: usize,
+}
+
+impl Default for Concurrency {
+    fn default() -> Self {
+        Self {
+            downloads: Self::DEFAULT_DOWNLOADS,
+            builds: Self::threads(),
+            installs: Self::threads(),
+        }
+    }
+}
+
+impl Concurrency {
+    /// The default number of concurrent downloads.
+    pub const DEFAULT_DOWNLOADS: usize = 50;
+
+    /// Create a set of limits, using the defaults for any that aren't provided.
+    pub fn new(
+        downloads: Option<NonZeroUsize>,
+        builds: Option<NonZeroUsize>,
+        installs: Option<NonZeroUsize>,
+    ) -> Self {
+        let default = Self::default();
+        Self {
+            downloads: downloads.map_or(default.downloads, NonZeroUsize::get),
+            builds: builds.map_or(default.builds, NonZeroUsize::get),
+            installs: installs.map_or(default.installs, NonZeroUsize::get),
+        }
+    }
+
+    /// The default number of threads, i.e., the number of available CPUs.
+    pub fn threads() -> usize {
+        std::thread::available_parallelism()
+            .map(NonZeroUsize::get)
+            .unwrap_or(1)
+    }
+}
@@ -1,5 +1,6 @@
 //! Fundamental types shared across `uv` crates.
 pub use build_options::*;
+pub use concurrency::*;
 pub use config_settings::*;
 pub use constraints::*;
 pub use downloads::*;
@@ -10,6 +11,7 @@ pub use requirements::*;
 pub use traits::*;
 
 mod build_options;
+mod concurrency;
 mod config_settings;
 mod constraints;
 mod downloads;
@@ -51,6 +51,21 @@ pub enum Upgrade {
     Packages(FxHashSet<PackageName>),
 }
 
+/// The strategy to use when reconciling package upgrades with the pinned versions in an existing
+/// lockfile.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum UpgradeStrategy {
+    /// Prefer the pinned versions from the existing lockfile, but allow the resolver to move any
+    /// pin as needed.
+    #[default]
+    Prefer,
+
+    /// Treat the pinned versions from the existing lockfile as constraints, relaxing them one at a
+    /// time only when the resolution would otherwise be unsatisfiable.
+    LeastMovement,
+}
+
 impl Upgrade {
     /// Determine the upgrade strategy from the command-line arguments.
     pub fn from_args(upgrade: bool, upgrade_package: Vec<PackageName>) -> Self {
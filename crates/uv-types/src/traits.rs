@@ -9,7 +9,7 @@ use pep508_rs::{PackageName, Requirement};
 use uv_cache::Cache;
 use uv_interpreter::{Interpreter, PythonEnvironment};
 
-use crate::{BuildIsolation, BuildKind, NoBinary, NoBuild, SetupPyStrategy};
+use crate::{BuildIsolation, BuildKind, Concurrency, NoBinary, NoBuild, SetupPyStrategy};
 
 ///  Avoids cyclic crate dependencies between resolver, installer and builder.
 ///
@@ -75,6 +75,9 @@ pub trait BuildContext: Sync {
     /// The strategy to use when building source distributions that lack a `pyproject.toml`.
     fn setup_py_strategy(&self) -> SetupPyStrategy;
 
+    /// The limits on concurrent downloads, builds and installs.
+    fn concurrency(&self) -> Concurrency;
+
     /// Resolve the given requirements into a ready-to-install set of package versions.
     fn resolve<'a>(
         &'a self,
@@ -104,6 +107,11 @@ pub trait BuildContext: Sync {
         dist: Option<&'a SourceDist>,
         build_kind: BuildKind,
     ) -> impl Future<Output = Result<Self::SourceDistBuilder>> + Send + 'a;
+
+    /// Record the requirements that were installed into the isolated build environment of a
+    /// source distribution, whether it was built by this context or its build was read from the
+    /// cache.
+    fn record_build_requirements(&self, dist: &SourceDist, requirements: Vec<Requirement>);
 }
 
 /// A wrapper for `uv_build::SourceBuild` to avoid cyclical crate dependencies.
@@ -126,6 +134,12 @@ pub trait SourceBuildTrait {
     /// Returns the filename of the built wheel inside the given `wheel_dir`.
     fn wheel<'a>(&'a self, wheel_dir: &'a Path)
         -> impl Future<Output = Result<String>> + Send + 'a;
+
+    /// A wrapper for `uv_build::SourceBuild::build_requirements`.
+    ///
+    /// Returns the resolved requirements that were installed into the build environment, or
+    /// `None` if build isolation is disabled.
+    fn build_requirements(&self) -> Option<&Resolution>;
 }
 
 /// A wrapper for [`uv_installer::SitePackages`]
@@ -0,0 +1,177 @@
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+
+use distribution_types::IndexLocations;
+use install_wheel_rs::linker::LinkMode;
+use pep440_rs::{Operator, VersionSpecifier, VersionSpecifiers};
+use pep508_rs::{Requirement, VersionOrUrl};
+use uv_auth::KeyringProvider;
+use uv_cache::Cache;
+use uv_client::{
+    ClientCertificate, Connectivity, HostCaBundle, ProxyConfig, TrustedHost, UserAgentSuffix,
+};
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_requirements::{DependencyType, ExtrasSpecification, PyProjectTomlMut, RequirementsSource};
+use uv_resolver::{DependencyMode, MissingUploadTime, PreReleaseMode, ResolutionMode};
+use uv_types::{
+    Concurrency, ConfigSettings, IndexStrategy, NoBinary, NoBuild, Reinstall, SetupPyStrategy,
+    Upgrade,
+};
+
+use crate::commands::project::{find_project_root, project_environment};
+use crate::commands::{pip_install, ErrorFormat, ExitStatus};
+use crate::printer::Printer;
+
+/// Add a requirement to the project's `pyproject.toml`, and install it into the project
+/// environment.
+///
+/// The project's requirements are installed with `pip install` semantics, i.e., the environment
+/// isn't synced to the project, and any extraneous packages are left in place.
+#[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
+pub(crate) async fn add(
+    requirement: &str,
+    dependency_type: DependencyType,
+    raw: bool,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    keyring_provider: KeyringProvider,
+    connectivity: Connectivity,
+    exclude_newer: Option<DateTime<Utc>>,
+    native_tls: bool,
+    client_cert: Option<ClientCertificate>,
+    ca_bundles: Vec<HostCaBundle>,
+    allow_insecure_host: Vec<TrustedHost>,
+    proxy: Option<ProxyConfig>,
+    linehaul: bool,
+    user_agent_suffixes: Vec<UserAgentSuffix>,
+    stale_if_error: Option<Duration>,
+    concurrency: Concurrency,
+    cache: Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let mut requirement = Requirement::from_str(requirement)
+        .with_context(|| format!("Failed to parse requirement: `{requirement}`"))?;
+
+    // Add the requirement to the `pyproject.toml`, as given.
+    let root = find_project_root()?;
+    let path = root.join("pyproject.toml");
+    let original = fs_err::read_to_string(&path)?;
+    let mut pyproject = PyProjectTomlMut::from_toml(&original)
+        .with_context(|| format!("Failed to read `{}`", path.user_display()))?;
+    pyproject.add_dependency(&requirement, &dependency_type)?;
+    fs_err::write(&path, pyproject.to_string())?;
+
+    // Install the project requirements, including the development dependencies and the extra that
+    // the requirement was added to, if any.
+    let venv = project_environment(&root, &cache, printer)?;
+    let requirements = std::iter::once(RequirementsSource::PyprojectToml(path.clone()))
+        .chain(
+            pyproject
+                .requirements()
+                .into_iter()
+                .filter(|(dependency_type, _)| *dependency_type == DependencyType::Dev)
+                .map(|(_, requirement)| RequirementsSource::Package(requirement.to_string())),
+        )
+        .collect::<Vec<_>>();
+    let extras = match &dependency_type {
+        DependencyType::Optional(extra) => vec![extra.clone()],
+        DependencyType::Production | DependencyType::Dev => vec![],
+    };
+    let extras = if extras.is_empty() {
+        ExtrasSpecification::None
+    } else {
+        ExtrasSpecification::Some(&extras)
+    };
+
+    let status = pip_install(
+        &requirements,
+        &[],
+        &[],
+        &[],
+        vec![],
+        &extras,
+        ResolutionMode::default(),
+        PreReleaseMode::default(),
+        vec![],
+        DependencyMode::Transitive,
+        Upgrade::None,
+        index_locations,
+        index_strategy,
+        keyring_provider,
+        Reinstall::None,
+        LinkMode::default(),
+        false,
+        SetupPyStrategy::default(),
+        connectivity,
+        &ConfigSettings::default(),
+        false,
+        NoBuild::None,
+        NoBinary::None,
+        false,
+        exclude_newer,
+        FxHashMap::default(),
+        MissingUploadTime::default(),
+        Some(venv.python_executable().to_string_lossy().to_string()),
+        false,
+        false,
+        native_tls,
+        client_cert,
+        ca_bundles,
+        allow_insecure_host,
+        proxy,
+        linehaul,
+        user_agent_suffixes,
+        stale_if_error,
+        concurrency,
+        cache.clone(),
+        false,
+        ErrorFormat::default(),
+        printer,
+    )
+    .await;
+
+    // If the requirement couldn't be installed, leave the `pyproject.toml` untouched.
+    match status {
+        Ok(ExitStatus::Success) => {}
+        Ok(status) => {
+            fs_err::write(&path, &original)?;
+            return Ok(status);
+        }
+        Err(err) => {
+            fs_err::write(&path, &original)?;
+            return Err(err);
+        }
+    }
+
+    // Unless the requirement should be added as given, add a lower bound based on the installed
+    // version (e.g., `httpx` becomes `httpx>=0.27.0`).
+    if !raw && requirement.version_or_url.is_none() {
+        let site_packages = SitePackages::from_executable(&venv)?;
+        if let Some(dist) = site_packages.get_packages(&requirement.name).first() {
+            let version = dist.version().clone().without_local();
+            let specifier = VersionSpecifier::from_version(Operator::GreaterThanEqual, version)?;
+            requirement.version_or_url = Some(VersionOrUrl::VersionSpecifier(
+                VersionSpecifiers::from(specifier),
+            ));
+            pyproject.add_dependency(&requirement, &dependency_type)?;
+            fs_err::write(&path, pyproject.to_string())?;
+        }
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Added {} to {} in {}",
+        requirement.to_string().bold(),
+        dependency_type,
+        path.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
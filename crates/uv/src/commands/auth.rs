@@ -0,0 +1,248 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use console::Term;
+use owo_colors::OwoColorize;
+use tracing::debug;
+use url::Url;
+
+use pep508_rs::redact_url;
+use uv_auth::{
+    credentials_file, keyring_delete, keyring_set, CredentialsStore, KeyringProvider, Login,
+    StoredCredentials,
+};
+use uv_client::{
+    BaseClientBuilder, ClientCertificate, HostCaBundle, ProxyConfig, TrustedHost, UserAgentSuffix,
+};
+use uv_fs::Simplified;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Validate and store the credentials for an index.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn auth_login(
+    index_url: Url,
+    name: Option<String>,
+    username: Option<String>,
+    password: Option<String>,
+    password_stdin: bool,
+    token: Option<String>,
+    keyring_provider: KeyringProvider,
+    native_tls: bool,
+    client_cert: Option<ClientCertificate>,
+    ca_bundles: Vec<HostCaBundle>,
+    allow_insecure_host: Vec<TrustedHost>,
+    proxy: Option<ProxyConfig>,
+    linehaul: bool,
+    user_agent_suffixes: Vec<UserAgentSuffix>,
+    stale_if_error: Option<Duration>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let Some(path) = credentials_file() else {
+        bail!("Could not determine the location of the credentials file; set `UV_CREDENTIALS_FILE` to provide one");
+    };
+    let mut store = CredentialsStore::read(path)?;
+
+    // Credentials embedded in the URL are used as defaults, but never stored in the URL itself.
+    let username = username.or_else(|| {
+        Some(index_url.username())
+            .filter(|username| !username.is_empty())
+            .map(ToString::to_string)
+    });
+    let password = password.or_else(|| index_url.password().map(ToString::to_string));
+
+    // Read the password (or, without a username, the token) from stdin, if requested.
+    let (password, token) = if password_stdin {
+        let mut secret = String::new();
+        std::io::stdin()
+            .read_line(&mut secret)
+            .context("Failed to read from stdin")?;
+        let secret = secret.trim_end_matches(['\r', '\n']).to_string();
+        if secret.is_empty() {
+            bail!("Expected a password on stdin, but found nothing");
+        }
+        if username.is_some() {
+            (Some(secret), token)
+        } else {
+            (password, Some(secret))
+        }
+    } else {
+        (password, token)
+    };
+    let mut url = index_url;
+    let _ = url.set_username("");
+    let _ = url.set_password(None);
+
+    let Some(name) = name.or_else(|| url.host_str().map(ToString::to_string)) else {
+        bail!("Index URL must include a host: {url}");
+    };
+
+    // Prompt for any missing credentials.
+    let term = Term::stderr();
+    let login = if let Some(token) = token {
+        Login::Token(token)
+    } else {
+        let username = match username {
+            Some(username) => username,
+            None => prompt(&term, "Username (leave empty to use a token): ", false)?,
+        };
+        if username.is_empty() {
+            Login::Token(prompt(&term, "Token: ", true)?)
+        } else {
+            let password = match password {
+                Some(password) => password,
+                None => prompt(&term, "Password: ", true)?,
+            };
+            Login::Basic { username, password }
+        }
+    };
+
+    // Validate the credentials against the index.
+    let client = BaseClientBuilder::new()
+        .native_tls(native_tls)
+        .client_cert(client_cert)
+        .ca_bundles(ca_bundles)
+        .allow_insecure_hosts(allow_insecure_host)
+        .proxy(proxy.clone())
+        .linehaul(linehaul)
+        .user_agent_suffixes(user_agent_suffixes.clone())
+        .stale_if_error(stale_if_error)
+        .build();
+    let request = client.client().get(url.clone());
+    let request = match &login {
+        Login::Basic { username, password } => request.basic_auth(username, Some(password)),
+        Login::Token(token) => request.bearer_auth(token),
+        Login::Keyring { .. } => unreachable!("keyring logins are only created below"),
+    };
+    let response = request
+        .send()
+        .await
+        .with_context(|| format!("Failed to connect to index: {}", redact_url(&url)))?;
+    let status = response.status();
+    match status.as_u16() {
+        401 | 403 => {
+            bail!(
+                "Failed to authenticate with {}: the index returned `{status}`",
+                url.cyan()
+            );
+        }
+        _ if !status.is_success() => {
+            bail!(
+                "Failed to validate credentials for {}: the index returned `{status}`",
+                url.cyan()
+            );
+        }
+        _ => debug!("Validated credentials for {url} (`{status}`)"),
+    }
+
+    // If a keyring is available, store the password there, rather than in the credentials file.
+    let login = match (keyring_provider, login) {
+        (KeyringProvider::Subprocess, Login::Basic { username, password }) => {
+            keyring_set(url.as_str(), &username, &password)?;
+            Login::Keyring { username }
+        }
+        (_, login) => login,
+    };
+
+    store.insert(&name, &url, login);
+    store.write()?;
+
+    writeln!(
+        printer.stderr(),
+        "Stored credentials for {} as `{name}` in: {}",
+        url.cyan(),
+        store.path().user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Remove the stored credentials for an index, by name or URL.
+pub(crate) fn auth_logout(index: &str, printer: Printer) -> Result<ExitStatus> {
+    let Some(path) = credentials_file() else {
+        bail!("Could not determine the location of the credentials file; set `UV_CREDENTIALS_FILE` to provide one");
+    };
+    let mut store = CredentialsStore::read(path)?;
+
+    let removed = store.remove(index);
+    if removed.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "No credentials found for: {}",
+            index.cyan()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    for stored in &removed {
+        if let (Some(url), Some(StoredCredentials::Keyring { username })) =
+            (stored.url.as_ref(), stored.credentials.as_ref())
+        {
+            keyring_delete(url.as_str(), username)?;
+        }
+    }
+    store.write()?;
+
+    for stored in &removed {
+        writeln!(
+            printer.stderr(),
+            "Removed credentials for `{}`",
+            stored.name.cyan()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// List the indexes in the credentials file, without any secrets.
+pub(crate) fn auth_list(printer: Printer) -> Result<ExitStatus> {
+    let Some(path) = credentials_file() else {
+        bail!("Could not determine the location of the credentials file; set `UV_CREDENTIALS_FILE` to provide one");
+    };
+    let store = CredentialsStore::read(path)?;
+
+    let indexes = store.indexes();
+    if indexes.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "No credentials found in: {}",
+            store.path().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for index in indexes {
+        let url = index
+            .url
+            .as_ref()
+            .map(|url| redact_url(url).to_string())
+            .unwrap_or_else(|| "(named index)".to_string());
+        match index.credentials {
+            Some(credentials) => writeln!(
+                printer.stdout(),
+                "{} {} ({credentials})",
+                index.name.bold(),
+                url.cyan()
+            )?,
+            None => writeln!(printer.stdout(), "{} {}", index.name.bold(), url.cyan())?,
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Prompt the user for a value on the terminal, hiding the input if `secure` is set.
+fn prompt(term: &Term, message: &str, secure: bool) -> Result<String> {
+    if !term.is_term() {
+        bail!("Credentials must be provided via `--username` and `--password-stdin` (or `--token`) when not running in a terminal");
+    }
+    term.write_str(message)?;
+    let value = if secure {
+        term.read_secure_line()?
+    } else {
+        term.read_line()?
+    };
+    Ok(value.trim().to_string())
+}
@@ -1,9 +1,12 @@
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
 
+use anstream::eprint;
 use anyhow::Context;
 use owo_colors::OwoColorize;
 
+pub(crate) use add::add;
+pub(crate) use auth::{auth_list, auth_login, auth_logout};
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
 pub(crate) use cache_prune::cache_prune;
@@ -16,6 +19,8 @@ pub(crate) use pip_list::pip_list;
 pub(crate) use pip_show::pip_show;
 pub(crate) use pip_sync::pip_sync;
 pub(crate) use pip_uninstall::pip_uninstall;
+pub(crate) use pip_why::pip_why;
+pub(crate) use remove::remove;
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
 use uv_cache::Cache;
@@ -23,11 +28,14 @@ use uv_fs::Simplified;
 use uv_installer::compile_tree;
 use uv_interpreter::PythonEnvironment;
 use uv_normalize::PackageName;
+use uv_resolver::NoSolutionError;
 pub(crate) use venv::venv;
 pub(crate) use version::version;
 
 use crate::printer::Printer;
 
+mod add;
+mod auth;
 mod cache_clean;
 mod cache_dir;
 mod cache_prune;
@@ -39,6 +47,9 @@ mod pip_list;
 mod pip_show;
 mod pip_sync;
 mod pip_uninstall;
+mod pip_why;
+mod project;
+mod remove;
 mod reporters;
 #[cfg(feature = "self-update")]
 mod self_update;
@@ -70,6 +81,25 @@ impl From<ExitStatus> for ExitCode {
     }
 }
 
+/// Report a resolution failure in the given [`ErrorFormat`].
+///
+/// Like the text report, the JSON report is written regardless of the verbosity, such that it's
+/// available in CI even with `--quiet`.
+pub(super) fn report_no_solution(err: &NoSolutionError, format: ErrorFormat) -> anyhow::Result<()> {
+    match format {
+        ErrorFormat::Text => {
+            let report = miette::Report::msg(format!("{err}"))
+                .context("No solution found when resolving dependencies:");
+            eprint!("{report:?}");
+        }
+        ErrorFormat::Json => {
+            let output = serde_json::to_string(&err.report())?;
+            anstream::println!("{output}");
+        }
+    }
+    Ok(())
+}
+
 /// Format a duration as a human-readable string, Cargo-style.
 pub(super) fn elapsed(duration: Duration) -> String {
     let secs = duration.as_secs();
@@ -113,6 +143,15 @@ pub(crate) enum VersionFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub(crate) enum ErrorFormat {
+    /// Display resolution failures as a human-readable report.
+    #[default]
+    Text,
+    /// Display resolution failures in a machine-readable JSON format.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub(crate) enum ListFormat {
     /// Display the list of packages in a human-readable table.
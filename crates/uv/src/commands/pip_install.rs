@@ -1,11 +1,12 @@
 use std::fmt::Write;
 use std::path::Path;
+use std::time::Duration;
 
-use anstream::eprint;
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, Utc};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
 use tempfile::tempdir_in;
 use tracing::debug;
 
@@ -21,8 +22,8 @@ use requirements_txt::EditableRequirement;
 use uv_auth::{KeyringProvider, GLOBAL_AUTH_STORE};
 use uv_cache::Cache;
 use uv_client::{
-    BaseClientBuilder, Connectivity, FlatIndex, FlatIndexClient, RegistryClient,
-    RegistryClientBuilder,
+    BaseClientBuilder, ClientCertificate, Connectivity, FlatIndex, FlatIndexClient, HostCaBundle,
+    ProxyConfig, RegistryClient, RegistryClientBuilder, TrustedHost, UserAgentSuffix,
 };
 use uv_dispatch::BuildDispatch;
 use uv_fs::Simplified;
@@ -34,17 +35,20 @@ use uv_requirements::{
     RequirementsSpecification, SourceTreeResolver,
 };
 use uv_resolver::{
-    DependencyMode, Exclusions, InMemoryIndex, Manifest, Options, OptionsBuilder, PreReleaseMode,
-    Preference, ResolutionGraph, ResolutionMode, Resolver,
+    DependencyMode, Exclusions, InMemoryIndex, Manifest, MissingUploadTime, Options,
+    OptionsBuilder, PreReleaseMode, Preference, ResolutionGraph, ResolutionMode, Resolver,
 };
 use uv_types::{
-    BuildIsolation, ConfigSettings, Constraints, InFlight, IndexStrategy, NoBinary, NoBuild,
-    Overrides, Reinstall, SetupPyStrategy, Upgrade,
+    BuildContext, BuildIsolation, Concurrency, ConfigSettings, Constraints, InFlight,
+    IndexStrategy, NoBinary, NoBuild, Overrides, Reinstall, SetupPyStrategy, Upgrade,
 };
 use uv_warnings::warn_user;
 
 use crate::commands::reporters::{DownloadReporter, InstallReporter, ResolverReporter};
-use crate::commands::{compile_bytecode, elapsed, ChangeEvent, ChangeEventKind, ExitStatus};
+use crate::commands::{
+    compile_bytecode, elapsed, report_no_solution, ChangeEvent, ChangeEventKind, ErrorFormat,
+    ExitStatus,
+};
 use crate::printer::Printer;
 
 use super::DryRunEvent;
@@ -55,9 +59,12 @@ pub(crate) async fn pip_install(
     requirements: &[RequirementsSource],
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
+    build_constraints: &[RequirementsSource],
+    excludes: Vec<PackageName>,
     extras: &ExtrasSpecification<'_>,
     resolution_mode: ResolutionMode,
     prerelease_mode: PreReleaseMode,
+    prerelease_packages: Vec<PackageName>,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     index_locations: IndexLocations,
@@ -74,18 +81,36 @@ pub(crate) async fn pip_install(
     no_binary: NoBinary,
     strict: bool,
     exclude_newer: Option<DateTime<Utc>>,
+    exclude_newer_package: FxHashMap<PackageName, Option<DateTime<Utc>>>,
+    missing_upload_time: MissingUploadTime,
     python: Option<String>,
     system: bool,
     break_system_packages: bool,
     native_tls: bool,
+    client_cert: Option<ClientCertificate>,
+    ca_bundles: Vec<HostCaBundle>,
+    allow_insecure_host: Vec<TrustedHost>,
+    proxy: Option<ProxyConfig>,
+    linehaul: bool,
+    user_agent_suffixes: Vec<UserAgentSuffix>,
+    stale_if_error: Option<Duration>,
+    concurrency: Concurrency,
     cache: Cache,
     dry_run: bool,
+    error_format: ErrorFormat,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .client_cert(client_cert.clone())
+        .ca_bundles(ca_bundles.clone())
+        .allow_insecure_hosts(allow_insecure_host.clone())
+        .proxy(proxy.clone())
+        .linehaul(linehaul)
+        .user_agent_suffixes(user_agent_suffixes.clone())
+        .stale_if_error(stale_if_error)
         .keyring_provider(keyring_provider);
 
     // Read all requirements from the provided sources.
@@ -102,6 +127,7 @@ pub(crate) async fn pip_install(
         find_links,
         no_binary: specified_no_binary,
         no_build: specified_no_build,
+        trusted_hosts,
         extras: _,
     } = read_requirements(
         requirements,
@@ -112,6 +138,19 @@ pub(crate) async fn pip_install(
     )
     .await?;
 
+    // Read the build constraints from the provided sources.
+    let build_constraints = Constraints::from_requirements(
+        RequirementsSpecification::from_sources(
+            &[],
+            build_constraints,
+            &[],
+            &ExtrasSpecification::None,
+            &client_builder,
+        )
+        .await?
+        .constraints,
+    );
+
     // Detect the current Python interpreter.
     let venv = if let Some(python) = python.as_ref() {
         PythonEnvironment::from_requested_python(python, &cache)?
@@ -191,9 +230,28 @@ pub(crate) async fn pip_install(
         GLOBAL_AUTH_STORE.save_from_url(url);
     }
 
+    // Add any credentials configured for the indexes (e.g., via `UV_INDEX_<NAME>_TOKEN`).
+    GLOBAL_AUTH_STORE.configure_indexes(
+        index_locations
+            .named_index()
+            .map(|index| (index.name.as_str(), index.url.url())),
+    )?;
+
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
+        .client_cert(client_cert)
+        .ca_bundles(ca_bundles)
+        .allow_insecure_hosts(
+            allow_insecure_host
+                .into_iter()
+                .chain(trusted_hosts)
+                .collect(),
+        )
+        .proxy(proxy.clone())
+        .linehaul(linehaul)
+        .user_agent_suffixes(user_agent_suffixes.clone())
+        .stale_if_error(stale_if_error)
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
@@ -241,7 +299,15 @@ pub(crate) async fn pip_install(
         &no_build,
         &no_binary,
     )
-    .with_options(OptionsBuilder::new().exclude_newer(exclude_newer).build());
+    .with_concurrency(concurrency)
+    .with_options(
+        OptionsBuilder::new()
+            .exclude_newer(exclude_newer)
+            .exclude_newer_package(exclude_newer_package.clone())
+            .missing_upload_time(missing_upload_time)
+            .build(),
+    )
+    .with_build_constraints(build_constraints.clone());
 
     // Resolve the requirements from the provided sources.
     let requirements = {
@@ -289,8 +355,11 @@ pub(crate) async fn pip_install(
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
         .prerelease_mode(prerelease_mode)
+        .prerelease_packages(prerelease_packages)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
+        .exclude_newer_package(exclude_newer_package.clone())
+        .missing_upload_time(missing_upload_time)
         .build();
 
     // Resolve the requirements.
@@ -298,6 +367,7 @@ pub(crate) async fn pip_install(
         requirements,
         constraints,
         overrides,
+        excludes,
         project,
         &editables,
         &site_packages,
@@ -317,9 +387,7 @@ pub(crate) async fn pip_install(
     {
         Ok(resolution) => Resolution::from(resolution),
         Err(Error::Resolve(uv_resolver::ResolveError::NoSolution(err))) => {
-            let report = miette::Report::msg(format!("{err}"))
-                .context("No solution found when resolving dependencies:");
-            eprint!("{report:?}");
+            report_no_solution(&err, error_format)?;
             return Ok(ExitStatus::Failure);
         }
         Err(err) => return Err(err.into()),
@@ -347,7 +415,15 @@ pub(crate) async fn pip_install(
             &no_build,
             &no_binary,
         )
-        .with_options(OptionsBuilder::new().exclude_newer(exclude_newer).build())
+        .with_concurrency(concurrency)
+        .with_options(
+            OptionsBuilder::new()
+                .exclude_newer(exclude_newer)
+                .exclude_newer_package(exclude_newer_package.clone())
+                .missing_upload_time(missing_upload_time)
+                .build(),
+        )
+        .with_build_constraints(build_constraints)
     };
 
     // Sync the environment.
@@ -445,6 +521,7 @@ async fn build_editables(
     let start = std::time::Instant::now();
 
     let downloader = Downloader::new(cache, tags, client, build_dispatch)
+        .with_concurrent_downloads(build_dispatch.concurrency().downloads)
         .with_reporter(DownloadReporter::from(printer).with_length(editables.len() as u64));
 
     let editables = LocalEditables::from_editables(editables.iter().map(|editable| {
@@ -499,6 +576,7 @@ async fn resolve(
     requirements: Vec<Requirement>,
     constraints: Vec<Requirement>,
     overrides: Vec<Requirement>,
+    excludes: Vec<PackageName>,
     project: Option<PackageName>,
     editables: &[BuiltEditable],
     site_packages: &SitePackages<'_>,
@@ -560,6 +638,7 @@ async fn resolve(
         requirements,
         constraints,
         overrides,
+        excludes,
         preferences,
         project,
         editables,
@@ -700,6 +779,7 @@ async fn install(
         let start = std::time::Instant::now();
 
         let downloader = Downloader::new(cache, tags, client, build_dispatch)
+            .with_concurrent_downloads(build_dispatch.concurrency().downloads)
             .with_reporter(DownloadReporter::from(printer).with_length(remote.len() as u64));
 
         let wheels = downloader
@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::time::Duration;
 
 use anstream::eprint;
 use anyhow::{anyhow, Context, Result};
@@ -16,21 +17,22 @@ use requirements_txt::EditableRequirement;
 use uv_auth::{KeyringProvider, GLOBAL_AUTH_STORE};
 use uv_cache::{ArchiveTarget, ArchiveTimestamp, Cache};
 use uv_client::{
-    BaseClientBuilder, Connectivity, FlatIndex, FlatIndexClient, RegistryClient,
-    RegistryClientBuilder,
+    BaseClientBuilder, ClientCertificate, Connectivity, FlatIndex, FlatIndexClient, HostCaBundle,
+    ProxyConfig, RegistryClient, RegistryClientBuilder, TrustedHost, UserAgentSuffix,
 };
 use uv_dispatch::BuildDispatch;
 use uv_fs::Simplified;
 use uv_installer::{is_dynamic, Downloader, Plan, Planner, ResolvedEditable, SitePackages};
 use uv_interpreter::{Interpreter, PythonEnvironment};
+use uv_normalize::ExtraName;
 use uv_requirements::{
     ExtrasSpecification, NamedRequirementsResolver, RequirementsSource, RequirementsSpecification,
     SourceTreeResolver,
 };
 use uv_resolver::{DependencyMode, InMemoryIndex, Manifest, OptionsBuilder, Resolver};
 use uv_types::{
-    BuildIsolation, ConfigSettings, EmptyInstalledPackages, InFlight, IndexStrategy, NoBinary,
-    NoBuild, Reinstall, SetupPyStrategy,
+    BuildContext, BuildIsolation, Concurrency, ConfigSettings, Constraints, EmptyInstalledPackages,
+    InFlight, IndexStrategy, NoBinary, NoBuild, Reinstall, SetupPyStrategy,
 };
 use uv_warnings::warn_user;
 
@@ -42,6 +44,8 @@ use crate::printer::Printer;
 #[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
 pub(crate) async fn pip_sync(
     sources: &[RequirementsSource],
+    extras: &[ExtraName],
+    build_constraints: &[RequirementsSource],
     reinstall: &Reinstall,
     link_mode: LinkMode,
     compile: bool,
@@ -59,6 +63,14 @@ pub(crate) async fn pip_sync(
     system: bool,
     break_system_packages: bool,
     native_tls: bool,
+    client_cert: Option<ClientCertificate>,
+    ca_bundles: Vec<HostCaBundle>,
+    allow_insecure_host: Vec<TrustedHost>,
+    proxy: Option<ProxyConfig>,
+    linehaul: bool,
+    user_agent_suffixes: Vec<UserAgentSuffix>,
+    stale_if_error: Option<Duration>,
+    concurrency: Concurrency,
     cache: Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -67,6 +79,13 @@ pub(crate) async fn pip_sync(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .client_cert(client_cert.clone())
+        .ca_bundles(ca_bundles.clone())
+        .allow_insecure_hosts(allow_insecure_host.clone())
+        .proxy(proxy.clone())
+        .linehaul(linehaul)
+        .user_agent_suffixes(user_agent_suffixes.clone())
+        .stale_if_error(stale_if_error)
         .keyring_provider(keyring_provider);
 
     // Read all requirements from the provided sources.
@@ -84,8 +103,22 @@ pub(crate) async fn pip_sync(
         find_links,
         no_binary: specified_no_binary,
         no_build: specified_no_build,
+        trusted_hosts,
     } = RequirementsSpecification::from_simple_sources(sources, &client_builder).await?;
 
+    // Read the build constraints from the provided sources.
+    let build_constraints = Constraints::from_requirements(
+        RequirementsSpecification::from_sources(
+            &[],
+            build_constraints,
+            &[],
+            &ExtrasSpecification::None,
+            &client_builder,
+        )
+        .await?
+        .constraints,
+    );
+
     // Validate that the requirements are non-empty.
     let num_requirements = requirements.len() + source_trees.len() + editables.len();
     if num_requirements == 0 {
@@ -141,9 +174,28 @@ pub(crate) async fn pip_sync(
         GLOBAL_AUTH_STORE.save_from_url(url);
     }
 
+    // Add any credentials configured for the indexes (e.g., via `UV_INDEX_<NAME>_TOKEN`).
+    GLOBAL_AUTH_STORE.configure_indexes(
+        index_locations
+            .named_index()
+            .map(|index| (index.name.as_str(), index.url.url())),
+    )?;
+
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
+        .client_cert(client_cert)
+        .ca_bundles(ca_bundles)
+        .allow_insecure_hosts(
+            allow_insecure_host
+                .into_iter()
+                .chain(trusted_hosts)
+                .collect(),
+        )
+        .proxy(proxy.clone())
+        .linehaul(linehaul)
+        .user_agent_suffixes(user_agent_suffixes.clone())
+        .stale_if_error(stale_if_error)
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
@@ -193,7 +245,9 @@ pub(crate) async fn pip_sync(
         build_isolation,
         &no_build,
         &no_binary,
-    );
+    )
+    .with_concurrency(concurrency)
+    .with_build_constraints(build_constraints);
 
     // Convert from unnamed to named requirements.
     let requirements = {
@@ -247,6 +301,7 @@ pub(crate) async fn pip_sync(
         extraneous,
     } = Planner::with_requirements(&requirements)
         .with_editable_requirements(&resolved_editables.editables)
+        .with_extras(extras)
         .build(
             site_packages,
             reinstall,
@@ -348,6 +403,7 @@ pub(crate) async fn pip_sync(
         let start = std::time::Instant::now();
 
         let downloader = Downloader::new(&cache, tags, &client, &build_dispatch)
+            .with_concurrent_downloads(concurrency.downloads)
             .with_reporter(DownloadReporter::from(printer).with_length(remote.len() as u64));
 
         let wheels = downloader
@@ -610,6 +666,7 @@ async fn resolve_editables(
         let start = std::time::Instant::now();
 
         let downloader = Downloader::new(cache, tags, client, build_dispatch)
+            .with_concurrent_downloads(build_dispatch.concurrency().downloads)
             .with_reporter(DownloadReporter::from(printer).with_length(uninstalled.len() as u64));
 
         let editables = LocalEditables::from_editables(uninstalled.iter().map(|editable| {
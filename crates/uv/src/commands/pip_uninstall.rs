@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::time::Duration;
 
 use anyhow::Result;
 use itertools::{Either, Itertools};
@@ -9,7 +10,10 @@ use distribution_types::{InstalledMetadata, Name};
 use pep508_rs::{Requirement, RequirementsTxtRequirement, UnnamedRequirement};
 use uv_auth::KeyringProvider;
 use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, Connectivity};
+use uv_client::{
+    BaseClientBuilder, ClientCertificate, Connectivity, HostCaBundle, ProxyConfig, TrustedHost,
+    UserAgentSuffix,
+};
 use uv_fs::Simplified;
 use uv_interpreter::PythonEnvironment;
 
@@ -18,7 +22,7 @@ use crate::printer::Printer;
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 
 /// Uninstall packages from the current environment.
-#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
 pub(crate) async fn pip_uninstall(
     sources: &[RequirementsSource],
     python: Option<String>,
@@ -27,6 +31,13 @@ pub(crate) async fn pip_uninstall(
     cache: Cache,
     connectivity: Connectivity,
     native_tls: bool,
+    client_cert: Option<ClientCertificate>,
+    ca_bundles: Vec<HostCaBundle>,
+    allow_insecure_host: Vec<TrustedHost>,
+    proxy: Option<ProxyConfig>,
+    linehaul: bool,
+    user_agent_suffixes: Vec<UserAgentSuffix>,
+    stale_if_error: Option<Duration>,
     keyring_provider: KeyringProvider,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -34,6 +45,13 @@ pub(crate) async fn pip_uninstall(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .client_cert(client_cert)
+        .ca_bundles(ca_bundles)
+        .allow_insecure_hosts(allow_insecure_host)
+        .proxy(proxy.clone())
+        .linehaul(linehaul)
+        .user_agent_suffixes(user_agent_suffixes.clone())
+        .stale_if_error(stale_if_error)
         .keyring_provider(keyring_provider);
 
     // Read all requirements from the provided sources.
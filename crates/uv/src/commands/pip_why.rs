@@ -0,0 +1,319 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+
+use distribution_types::IndexLocations;
+use uv_auth::KeyringProvider;
+use uv_cache::Cache;
+use uv_client::{
+    BaseClientBuilder, ClientCertificate, Connectivity, HostCaBundle, ProxyConfig, TrustedHost,
+    UserAgentSuffix,
+};
+use uv_interpreter::PythonVersion;
+use uv_normalize::PackageName;
+use uv_requirements::{
+    upgrade::read_lockfile, ExtrasSpecification, LookaheadResolver, RequirementsSource,
+    RequirementsSpecification,
+};
+use uv_resolver::{
+    DependencyMode, Exclusions, InMemoryIndex, Manifest, MissingUploadTime, OptionsBuilder,
+    PreReleaseMode, ResolutionMode,
+};
+use uv_types::{
+    Concurrency, ConfigSettings, Constraints, InFlight, IndexStrategy, NoBuild, Overrides,
+    SetupPyStrategy, Upgrade,
+};
+
+use crate::commands::pip_compile::{build_editables, resolve_requirements, ResolutionContext};
+use crate::commands::reporters::ResolverReporter;
+use crate::commands::{elapsed, report_no_solution, ErrorFormat, ExitStatus};
+use crate::printer::Printer;
+
+/// Resolve a set of requirements, and explain why the given package was included in the
+/// resolution at its selected version.
+#[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
+pub(crate) async fn pip_why(
+    package: &PackageName,
+    requirements: &[RequirementsSource],
+    constraints: &[RequirementsSource],
+    overrides: &[RequirementsSource],
+    excludes: Vec<PackageName>,
+    extras: ExtrasSpecification<'_>,
+    output_file: Option<&Path>,
+    resolution_mode: ResolutionMode,
+    prerelease_mode: PreReleaseMode,
+    prerelease_packages: Vec<PackageName>,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    keyring_provider: KeyringProvider,
+    setup_py: SetupPyStrategy,
+    config_settings: ConfigSettings,
+    connectivity: Connectivity,
+    no_build_isolation: bool,
+    no_build: NoBuild,
+    python_version: Option<PythonVersion>,
+    exclude_newer: Option<DateTime<Utc>>,
+    exclude_newer_package: FxHashMap<PackageName, Option<DateTime<Utc>>>,
+    missing_upload_time: MissingUploadTime,
+    native_tls: bool,
+    client_cert: Option<ClientCertificate>,
+    ca_bundles: Vec<HostCaBundle>,
+    allow_insecure_host: Vec<TrustedHost>,
+    proxy: Option<ProxyConfig>,
+    linehaul: bool,
+    user_agent_suffixes: Vec<UserAgentSuffix>,
+    stale_if_error: Option<Duration>,
+    concurrency: Concurrency,
+    cache: Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    // If the user requests `extras` but does not provide a valid source (e.g., a `pyproject.toml`),
+    // return an error.
+    if !extras.is_empty() && !requirements.iter().any(RequirementsSource::allows_extras) {
+        return Err(anyhow!(
+            "Requesting extras requires a `pyproject.toml`, `setup.cfg`, or `setup.py` file."
+        ));
+    }
+
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .client_cert(client_cert.clone())
+        .ca_bundles(ca_bundles.clone())
+        .allow_insecure_hosts(allow_insecure_host.clone())
+        .proxy(proxy.clone())
+        .linehaul(linehaul)
+        .user_agent_suffixes(user_agent_suffixes.clone())
+        .stale_if_error(stale_if_error)
+        .keyring_provider(keyring_provider);
+
+    // Read all requirements from the provided sources.
+    let RequirementsSpecification {
+        project,
+        requirements,
+        constraints,
+        overrides,
+        editables,
+        source_trees,
+        extras: used_extras,
+        index_url,
+        extra_index_urls,
+        no_index,
+        find_links,
+        no_binary: _,
+        no_build: specified_no_build,
+        trusted_hosts,
+    } = RequirementsSpecification::from_sources(
+        requirements,
+        constraints,
+        overrides,
+        &extras,
+        &client_builder,
+    )
+    .await?;
+
+    // If all the metadata could be statically resolved, validate that every extra was used.
+    if source_trees.is_empty() {
+        if let ExtrasSpecification::Some(extras) = extras {
+            let mut unused_extras = extras
+                .iter()
+                .filter(|extra| !used_extras.contains(extra))
+                .collect::<Vec<_>>();
+            if !unused_extras.is_empty() {
+                unused_extras.sort_unstable();
+                unused_extras.dedup();
+                let s = if unused_extras.len() == 1 { "" } else { "s" };
+                return Err(anyhow!(
+                    "Requested extra{s} not found: {}",
+                    unused_extras.iter().join(", ")
+                ));
+            }
+        }
+    }
+
+    // Incorporate any index locations from the provided sources.
+    let index_locations =
+        index_locations.combine(index_url, extra_index_urls, find_links, no_index);
+
+    // Combine the `--no-build` flags.
+    let no_build = no_build.combine(specified_no_build);
+
+    let context = ResolutionContext::new(
+        python_version.as_ref(),
+        index_locations,
+        index_strategy,
+        keyring_provider,
+        connectivity,
+        &no_build,
+        no_build_isolation,
+        native_tls,
+        client_cert,
+        ca_bundles,
+        allow_insecure_host
+            .into_iter()
+            .chain(trusted_hosts)
+            .collect(),
+        proxy,
+        linehaul,
+        user_agent_suffixes,
+        stale_if_error,
+        &cache,
+    )
+    .await?;
+    let ResolutionContext {
+        interpreter,
+        tags,
+        markers,
+        client,
+        ..
+    } = &context;
+
+    // Create an in-memory index for building source distributions.
+    let source_index = InMemoryIndex::default();
+
+    // Use a separate index for the top-level resolution, such that we can inspect the versions
+    // that were considered for the target package without interference from any builds.
+    let top_level_index = InMemoryIndex::default();
+
+    // Read the pinned versions from the output file, if present, to replay its resolution.
+    let preferences = read_lockfile(output_file, Upgrade::None).await?;
+
+    // Collect constraints and overrides.
+    let constraints = Constraints::from_requirements(constraints);
+    let overrides = Overrides::from_requirements(overrides);
+
+    // Track in-flight downloads, builds, etc., across resolutions.
+    let in_flight = InFlight::default();
+
+    let build_dispatch = context.build_dispatch(
+        &cache,
+        &source_index,
+        &in_flight,
+        setup_py,
+        &config_settings,
+        &no_build,
+        concurrency,
+        OptionsBuilder::new()
+            .exclude_newer(exclude_newer)
+            .exclude_newer_package(exclude_newer_package.clone())
+            .missing_upload_time(missing_upload_time)
+            .build(),
+    );
+
+    // Build the editables and add their requirements
+    let editables = build_editables(
+        editables,
+        &cache,
+        tags,
+        client,
+        &build_dispatch,
+        interpreter,
+        markers,
+        printer,
+    )
+    .await?;
+
+    // Resolve the requirements from the provided sources.
+    let requirements = resolve_requirements(
+        requirements,
+        source_trees,
+        &extras,
+        &build_dispatch,
+        client,
+        &top_level_index,
+        printer,
+    )
+    .await?;
+
+    // Determine any lookahead requirements.
+    let lookaheads = LookaheadResolver::new(
+        &requirements,
+        &constraints,
+        &overrides,
+        &editables,
+        &build_dispatch,
+        client,
+        &top_level_index,
+    )
+    .with_reporter(ResolverReporter::from(printer))
+    .resolve(markers)
+    .await?;
+
+    // Create a manifest of the requirements.
+    let manifest = Manifest::new(
+        requirements,
+        constraints,
+        overrides,
+        excludes,
+        preferences,
+        project,
+        editables,
+        // Do not consider any installed packages during resolution
+        Exclusions::All,
+        lookaheads,
+    );
+
+    let options = OptionsBuilder::new()
+        .resolution_mode(resolution_mode)
+        .prerelease_mode(prerelease_mode)
+        .prerelease_packages(prerelease_packages)
+        .dependency_mode(DependencyMode::Transitive)
+        .exclude_newer(exclude_newer)
+        .exclude_newer_package(exclude_newer_package.clone())
+        .missing_upload_time(missing_upload_time)
+        .build();
+
+    // Resolve the dependencies.
+    let resolver = context.resolver(
+        manifest.clone(),
+        options,
+        &top_level_index,
+        &build_dispatch,
+        printer,
+    )?;
+
+    let resolution = match resolver.resolve().await {
+        Err(uv_resolver::ResolveError::NoSolution(err)) => {
+            report_no_solution(&err, ErrorFormat::Text)?;
+            return Ok(ExitStatus::Failure);
+        }
+        result => result,
+    }?;
+
+    let s = if resolution.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Resolved {} in {}",
+            format!("{} package{}", resolution.len(), s).bold(),
+            elapsed(start.elapsed())
+        )
+        .dimmed()
+    )?;
+
+    let whys = resolution.why(package, &manifest, markers, &top_level_index);
+    if whys.is_empty() {
+        return Err(anyhow!(
+            "Package `{package}` is not included in the resolution"
+        ));
+    }
+
+    // Explain each version of the package, e.g., if a forked resolution selected several.
+    for (index, why) in whys.iter().enumerate() {
+        if index > 0 {
+            writeln!(printer.stdout())?;
+        }
+        write!(printer.stdout(), "{why}")?;
+    }
+
+    Ok(ExitStatus::Success)
+}
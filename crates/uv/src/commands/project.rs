@@ -0,0 +1,80 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_interpreter::{find_default_python, PythonEnvironment};
+use uv_requirements::Workspace;
+
+use crate::printer::Printer;
+
+/// Find the root of the current project, i.e., the nearest directory (starting from the current
+/// working directory) that contains a `pyproject.toml`.
+pub(crate) fn find_project_root() -> Result<PathBuf> {
+    let cwd = std::env::current_dir()?;
+    cwd.ancestors()
+        .find(|directory| directory.join("pyproject.toml").is_file())
+        .map(Path::to_path_buf)
+        .ok_or_else(|| {
+            anyhow!(
+                "No `pyproject.toml` found in `{}` or any parent directory",
+                cwd.user_display()
+            )
+        })
+}
+
+/// Find the virtual environment for the project, creating one at `.venv` in the project root if
+/// none exists.
+///
+/// If the project is part of a workspace, the environment is shared by all workspace members, and
+/// created in the workspace root instead.
+pub(crate) fn project_environment(
+    root: &Path,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<PythonEnvironment> {
+    match PythonEnvironment::from_virtualenv(cache) {
+        Ok(venv) => {
+            debug!(
+                "Using Python {} environment at {}",
+                venv.interpreter().python_version(),
+                venv.python_executable().user_display().cyan()
+            );
+            return Ok(venv);
+        }
+        Err(uv_interpreter::Error::VenvNotFound) => {}
+        Err(err) => return Err(err.into()),
+    }
+
+    let path = match Workspace::discover(root)? {
+        Some(workspace) => workspace.root().join(".venv"),
+        None => root.join(".venv"),
+    };
+    let interpreter = find_default_python(cache)?;
+    writeln!(
+        printer.stderr(),
+        "Using Python {} interpreter at: {}",
+        interpreter.python_version(),
+        interpreter.sys_executable().user_display().cyan()
+    )?;
+    writeln!(
+        printer.stderr(),
+        "Creating virtualenv at: {}",
+        path.user_display().cyan()
+    )?;
+
+    // Extra cfg for pyvenv.cfg to specify uv version
+    let extra_cfg = vec![("uv".to_string(), env!("CARGO_PKG_VERSION").to_string())];
+
+    Ok(uv_virtualenv::create_venv(
+        &path,
+        interpreter,
+        uv_virtualenv::Prompt::None,
+        false,
+        extra_cfg,
+    )?)
+}
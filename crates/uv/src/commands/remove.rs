@@ -0,0 +1,167 @@
+use std::collections::hash_map::Entry;
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use anyhow::{anyhow, Context, Result};
+use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::debug;
+
+use distribution_types::{InstalledMetadata, Name};
+use pep508_rs::{MarkerEnvironment, Requirement};
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_interpreter::PythonEnvironment;
+use uv_normalize::{ExtraName, PackageName};
+use uv_requirements::{DependencyType, PyProjectTomlMut};
+
+use crate::commands::project::find_project_root;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Remove a requirement from the project's `pyproject.toml`, and uninstall any packages that are
+/// no longer required by the project.
+pub(crate) async fn remove(
+    package: &PackageName,
+    dependency_type: DependencyType,
+    cache: Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let root = find_project_root()?;
+    let path = root.join("pyproject.toml");
+    let contents = fs_err::read_to_string(&path)?;
+    let mut pyproject = PyProjectTomlMut::from_toml(&contents)
+        .with_context(|| format!("Failed to read `{}`", path.user_display()))?;
+
+    let before = pyproject.requirements();
+    let removed = pyproject.remove_dependency(package, &dependency_type)?;
+    if removed.is_empty() {
+        return Err(anyhow!(
+            "`{package}` is not a dependency in {dependency_type} in `{}`",
+            path.user_display()
+        ));
+    }
+    let after = pyproject.requirements();
+    fs_err::write(&path, pyproject.to_string())?;
+
+    for requirement in &removed {
+        writeln!(
+            printer.stderr(),
+            "Removed {} from {} in {}",
+            requirement.to_string().bold(),
+            dependency_type,
+            path.user_display().cyan()
+        )?;
+    }
+
+    // If the project doesn't have an environment yet, there's nothing to uninstall.
+    let venv = match PythonEnvironment::from_virtualenv(&cache) {
+        Ok(venv) => venv,
+        Err(uv_interpreter::Error::VenvNotFound) => return Ok(ExitStatus::Success),
+        Err(err) => return Err(err.into()),
+    };
+    let _lock = venv.lock()?;
+    let site_packages = SitePackages::from_executable(&venv)?;
+
+    // Uninstall any packages that were only reachable through the removed requirement. Any
+    // installed package outside of the project's dependency graph (e.g., one installed with
+    // `uv pip install`) is a root in its own right, such that its dependencies are retained.
+    let markers = venv.interpreter().markers();
+    let project = pyproject.name();
+    let previous = reachable(&site_packages, markers, roots(markers, &before))?;
+    let installed = site_packages
+        .iter()
+        .map(|dist| dist.name().clone())
+        .filter(|name| !previous.contains(name) && project.as_ref() != Some(name))
+        .map(|name| (name, Vec::new()));
+    let required = reachable(
+        &site_packages,
+        markers,
+        roots(markers, &after).chain(installed),
+    )?;
+    let mut orphans = previous
+        .into_iter()
+        .filter(|name| !required.contains(name) && project.as_ref() != Some(name))
+        .flat_map(|name| site_packages.get_packages(&name))
+        .collect::<Vec<_>>();
+    orphans.sort_unstable_by_key(|dist| dist.name());
+
+    for dist in &orphans {
+        let summary = uv_installer::uninstall(dist).await?;
+        debug!(
+            "Uninstalled {} ({} file{}, {} director{})",
+            dist.name(),
+            summary.file_count,
+            if summary.file_count == 1 { "" } else { "s" },
+            summary.dir_count,
+            if summary.dir_count == 1 { "y" } else { "ies" },
+        );
+    }
+
+    for dist in orphans {
+        writeln!(
+            printer.stderr(),
+            " {} {}{}",
+            "-".red(),
+            dist.name().as_ref().bold(),
+            dist.installed_version().to_string().dimmed()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return the names and extras of the given requirements that apply to the current environment.
+fn roots<'a>(
+    markers: &'a MarkerEnvironment,
+    requirements: &'a [(DependencyType, Requirement)],
+) -> impl Iterator<Item = (PackageName, Vec<ExtraName>)> + 'a {
+    requirements
+        .iter()
+        .map(|(_, requirement)| requirement)
+        .filter(|requirement| requirement.evaluate_markers(markers, &[]))
+        .map(|requirement| (requirement.name.clone(), requirement.extras.clone()))
+}
+
+/// Return the names of all installed packages that are reachable from the given roots.
+fn reachable(
+    site_packages: &SitePackages<'_>,
+    markers: &MarkerEnvironment,
+    roots: impl Iterator<Item = (PackageName, Vec<ExtraName>)>,
+) -> Result<FxHashSet<PackageName>> {
+    let mut seen = FxHashMap::<PackageName, FxHashSet<ExtraName>>::default();
+    let mut queue = roots.collect::<VecDeque<_>>();
+
+    while let Some((name, extras)) = queue.pop_front() {
+        // Revisit a package only if it's been requested with extras that we haven't seen yet.
+        match seen.entry(name.clone()) {
+            Entry::Vacant(entry) => {
+                entry.insert(extras.into_iter().collect());
+            }
+            Entry::Occupied(mut entry) => {
+                let mut changed = false;
+                for extra in extras {
+                    changed |= entry.get_mut().insert(extra);
+                }
+                if !changed {
+                    continue;
+                }
+            }
+        }
+        let extras = seen[&name].iter().cloned().collect::<Vec<_>>();
+
+        for dist in site_packages.get_packages(&name) {
+            let metadata = dist.metadata().with_context(|| {
+                format!("Failed to read metadata for: {}", dist.name().as_ref())
+            })?;
+            for requirement in metadata.requires_dist {
+                if requirement.evaluate_markers(markers, &extras) {
+                    queue.push_back((requirement.name, requirement.extras));
+                }
+            }
+        }
+    }
+
+    Ok(seen.into_keys().collect())
+}
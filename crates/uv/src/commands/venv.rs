@@ -1,6 +1,7 @@
 use std::fmt::Write;
 use std::path::Path;
 use std::str::FromStr;
+use std::time::Duration;
 use std::vec;
 
 use anstream::eprint;
@@ -15,14 +16,17 @@ use distribution_types::{DistributionMetadata, IndexLocations, Name, ResolvedDis
 use pep508_rs::Requirement;
 use uv_auth::{KeyringProvider, GLOBAL_AUTH_STORE};
 use uv_cache::Cache;
-use uv_client::{Connectivity, FlatIndex, FlatIndexClient, RegistryClientBuilder};
+use uv_client::{
+    ClientCertificate, Connectivity, FlatIndex, FlatIndexClient, HostCaBundle, ProxyConfig,
+    RegistryClientBuilder, TrustedHost, UserAgentSuffix,
+};
 use uv_dispatch::BuildDispatch;
 use uv_fs::Simplified;
 use uv_interpreter::{find_default_python, find_requested_python, Error};
 use uv_resolver::{InMemoryIndex, OptionsBuilder};
 use uv_types::{
-    BuildContext, BuildIsolation, ConfigSettings, InFlight, IndexStrategy, NoBinary, NoBuild,
-    SetupPyStrategy,
+    BuildContext, BuildIsolation, Concurrency, ConfigSettings, InFlight, IndexStrategy, NoBinary,
+    NoBuild, SetupPyStrategy,
 };
 
 use crate::commands::ExitStatus;
@@ -30,7 +34,11 @@ use crate::printer::Printer;
 use crate::shell::Shell;
 
 /// Create a virtual environment.
-#[allow(clippy::unnecessary_wraps, clippy::too_many_arguments)]
+#[allow(
+    clippy::unnecessary_wraps,
+    clippy::too_many_arguments,
+    clippy::fn_params_excessive_bools
+)]
 pub(crate) async fn venv(
     path: &Path,
     python_request: Option<&str>,
@@ -43,6 +51,14 @@ pub(crate) async fn venv(
     seed: bool,
     exclude_newer: Option<DateTime<Utc>>,
     native_tls: bool,
+    client_cert: Option<ClientCertificate>,
+    ca_bundles: Vec<HostCaBundle>,
+    allow_insecure_host: Vec<TrustedHost>,
+    proxy: Option<ProxyConfig>,
+    linehaul: bool,
+    user_agent_suffixes: Vec<UserAgentSuffix>,
+    stale_if_error: Option<Duration>,
+    concurrency: Concurrency,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -58,6 +74,14 @@ pub(crate) async fn venv(
         seed,
         exclude_newer,
         native_tls,
+        client_cert,
+        ca_bundles,
+        allow_insecure_host,
+        proxy,
+        linehaul,
+        user_agent_suffixes,
+        stale_if_error,
+        concurrency,
         cache,
         printer,
     )
@@ -91,7 +115,7 @@ enum VenvError {
 }
 
 /// Create a virtual environment.
-#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
 async fn venv_impl(
     path: &Path,
     python_request: Option<&str>,
@@ -104,6 +128,14 @@ async fn venv_impl(
     seed: bool,
     exclude_newer: Option<DateTime<Utc>>,
     native_tls: bool,
+    client_cert: Option<ClientCertificate>,
+    ca_bundles: Vec<HostCaBundle>,
+    allow_insecure_host: Vec<TrustedHost>,
+    proxy: Option<ProxyConfig>,
+    linehaul: bool,
+    user_agent_suffixes: Vec<UserAgentSuffix>,
+    stale_if_error: Option<Duration>,
+    concurrency: Concurrency,
     cache: &Cache,
     printer: Printer,
 ) -> miette::Result<ExitStatus> {
@@ -150,9 +182,25 @@ async fn venv_impl(
             GLOBAL_AUTH_STORE.save_from_url(url);
         }
 
+        // Add any credentials configured for the indexes (e.g., via `UV_INDEX_<NAME>_TOKEN`).
+        GLOBAL_AUTH_STORE
+            .configure_indexes(
+                index_locations
+                    .named_index()
+                    .map(|index| (index.name.as_str(), index.url.url())),
+            )
+            .into_diagnostic()?;
+
         // Instantiate a client.
         let client = RegistryClientBuilder::new(cache.clone())
             .native_tls(native_tls)
+            .client_cert(client_cert)
+            .ca_bundles(ca_bundles)
+            .allow_insecure_hosts(allow_insecure_host)
+            .proxy(proxy.clone())
+            .linehaul(linehaul)
+            .user_agent_suffixes(user_agent_suffixes.clone())
+            .stale_if_error(stale_if_error)
             .index_urls(index_locations.index_urls())
             .index_strategy(index_strategy)
             .keyring_provider(keyring_provider)
@@ -196,6 +244,7 @@ async fn venv_impl(
             &NoBuild::All,
             &NoBinary::None,
         )
+        .with_concurrency(concurrency)
         .with_options(OptionsBuilder::new().exclude_newer(exclude_newer).build());
 
         // Resolve the seed packages.
@@ -39,12 +39,6 @@ pub(crate) struct PipCompileCompatArgs {
     #[clap(long, hide = true)]
     cert: Option<String>,
 
-    #[clap(long, hide = true)]
-    client_cert: Option<String>,
-
-    #[clap(long, hide = true)]
-    trusted_host: Option<String>,
-
     #[clap(long, hide = true)]
     emit_trusted_host: bool,
 
@@ -132,21 +126,9 @@ impl CompatArgs for PipCompileCompatArgs {
             ));
         }
 
-        if self.client_cert.is_some() {
-            return Err(anyhow!(
-                "pip-compile's `--client-cert` is unsupported (uv doesn't support dedicated client certificates)."
-            ));
-        }
-
-        if self.trusted_host.is_some() {
-            return Err(anyhow!(
-                "pip-compile's `--trusted-host` is unsupported (uv always requires HTTPS)."
-            ));
-        }
-
         if self.emit_trusted_host {
             return Err(anyhow!(
-                "pip-compile's `--emit-trusted-host` is unsupported (uv always requires HTTPS)."
+                "pip-compile's `--emit-trusted-host` is unsupported (uv never emits trusted hosts)."
             ));
         }
 
@@ -215,9 +197,6 @@ pub(crate) struct PipSyncCompatArgs {
     #[clap(short, long, hide = true)]
     ask: bool,
 
-    #[clap(long, hide = true)]
-    trusted_host: Option<String>,
-
     #[clap(long, hide = true)]
     python_executable: Option<String>,
 
@@ -227,9 +206,6 @@ pub(crate) struct PipSyncCompatArgs {
     #[clap(long, hide = true)]
     cert: Option<String>,
 
-    #[clap(long, hide = true)]
-    client_cert: Option<String>,
-
     #[clap(long, hide = true)]
     config: Option<String>,
 
@@ -263,18 +239,6 @@ impl CompatArgs for PipSyncCompatArgs {
             return Err(anyhow!("pip-sync's `--user` is unsupported."));
         }
 
-        if self.client_cert.is_some() {
-            return Err(anyhow!(
-                "pip-sync's `--client-cert` is unsupported (uv doesn't support dedicated client certificates)."
-            ));
-        }
-
-        if self.trusted_host.is_some() {
-            return Err(anyhow!(
-                "pip-sync's `--trusted-host` is unsupported (uv always requires HTTPS)."
-            ));
-        }
-
         if self.config.is_some() {
             return Err(anyhow!(
                 "pip-sync's `--config` is unsupported (uv does not use a configuration file)."
@@ -1,32 +1,45 @@
 use std::env;
 use std::io::stdout;
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::process::ExitCode;
 use std::str::FromStr;
+use std::time::Duration;
 
 use anstream::eprintln;
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use chrono::{DateTime, Days, NaiveDate, NaiveTime, Utc};
 use clap::error::{ContextKind, ContextValue};
 use clap::{Args, CommandFactory, Parser, Subcommand};
 use owo_colors::OwoColorize;
 use tracing::instrument;
+use url::Url;
 
-use distribution_types::{FlatIndexLocation, IndexLocations, IndexUrl};
+use distribution_types::{
+    FlatIndexLocation, IndexAssignment, IndexLocations, IndexUrl, NamedIndex,
+};
 use uv_auth::KeyringProvider;
 use uv_cache::{Cache, CacheArgs, Refresh};
-use uv_client::Connectivity;
+use uv_client::{
+    ClientCertificate, Connectivity, HostCaBundle, IndexProxy, ProxyBypass, ProxyConfig,
+    TrustedHost, UserAgentSuffix,
+};
 use uv_interpreter::PythonVersion;
 use uv_normalize::{ExtraName, PackageName};
-use uv_requirements::{ExtrasSpecification, RequirementsSource};
-use uv_resolver::{AnnotationStyle, DependencyMode, PreReleaseMode, ResolutionMode};
+use uv_requirements::{ConflictingExtras, DependencyType, ExtrasSpecification, RequirementsSource};
+use uv_resolver::{
+    AnnotationStyle, DependencyMode, MissingUploadTime, PreReleaseMode, ResolutionMode,
+};
 use uv_types::{
-    ConfigSettingEntry, ConfigSettings, NoBuild, PackageNameSpecifier, Reinstall, SetupPyStrategy,
-    Upgrade,
+    Concurrency, ConfigSettingEntry, ConfigSettings, NoBuild, PackageNameSpecifier, Reinstall,
+    SetupPyStrategy, Upgrade, UpgradeStrategy,
 };
 use uv_types::{IndexStrategy, NoBinary};
+use uv_warnings::warn_user;
 
-use crate::commands::{extra_name_with_clap_error, ExitStatus, ListFormat, VersionFormat};
+use crate::commands::{
+    extra_name_with_clap_error, ErrorFormat, ExitStatus, ListFormat, VersionFormat,
+};
 use crate::compat::CompatArgs;
 
 #[cfg(target_os = "windows")]
@@ -99,6 +112,159 @@ struct Cli {
     #[arg(global = true, long, env = "UV_NATIVE_TLS")]
     native_tls: bool,
 
+    /// Path to a PEM-encoded client certificate to present for mutual TLS (mTLS) authentication.
+    ///
+    /// The file may also contain the private key; otherwise, provide it with `--client-key`. An
+    /// encrypted (PKCS #8) private key is decrypted with the passphrase in the
+    /// `UV_CLIENT_KEY_PASSWORD` environment variable.
+    ///
+    /// The certificate is only presented to the hosts given by `--client-cert-host`.
+    #[arg(
+        global = true,
+        long,
+        env = "UV_CLIENT_CERT",
+        requires = "client_cert_host"
+    )]
+    client_cert: Option<PathBuf>,
+
+    /// Path to the PEM-encoded private key for the `--client-cert` certificate.
+    #[arg(global = true, long, env = "UV_CLIENT_KEY", requires = "client_cert")]
+    client_key: Option<PathBuf>,
+
+    /// A host to present the `--client-cert` certificate to (e.g., `pypi.internal.example.com`);
+    /// may be provided more than once.
+    ///
+    /// Requests to any other host (e.g., PyPI) are made without the certificate. When provided via
+    /// the `UV_CLIENT_CERT_HOST` environment variable, multiple hosts are separated by spaces.
+    #[arg(
+        global = true,
+        long,
+        env = "UV_CLIENT_CERT_HOST",
+        value_delimiter = ' ',
+        requires = "client_cert"
+    )]
+    client_cert_host: Vec<String>,
+
+    /// Allow insecure connections to a host; may be provided more than once.
+    ///
+    /// Expects a host name, optionally followed by a port (e.g., `localhost:8080`). TLS
+    /// certificate verification is skipped for the host, such that it may be served with a
+    /// self-signed or otherwise untrusted certificate.
+    ///
+    /// WARNING: Hosts included in this list will not be verified against the system's certificate
+    /// store. Only use `--allow-insecure-host` in a secure network with verified sources, as it
+    /// bypasses SSL verification and could expose you to MITM attacks.
+    ///
+    /// Hosts may also be provided via `--trusted-host` in a `requirements.txt` file. When provided
+    /// via the `UV_INSECURE_HOST` environment variable, multiple hosts are separated by spaces.
+    #[arg(
+        global = true,
+        long,
+        alias = "trusted-host",
+        env = "UV_INSECURE_HOST",
+        value_delimiter = ' ',
+        value_parser = parse_trusted_host
+    )]
+    allow_insecure_host: Vec<TrustedHost>,
+
+    /// A PEM-encoded CA bundle to trust for a single host, in addition to the default roots, as
+    /// `HOST=PATH` (e.g., `pypi.internal.example.com=/etc/ssl/internal-ca.pem`); may be provided
+    /// more than once.
+    ///
+    /// Allows connecting to an index served with a certificate signed by a private CA, without
+    /// trusting that CA for any other host (e.g., PyPI). The host may include a port, or be given
+    /// as the index URL itself.
+    #[arg(global = true, long, value_parser = parse_index_ca_cert)]
+    index_ca_cert: Vec<(TrustedHost, PathBuf)>,
+
+    /// The proxy through which to send HTTP requests (e.g., `http://proxy.example.com:3128`).
+    ///
+    /// Takes precedence over any proxy set in the environment (e.g., via `HTTPS_PROXY`), along with
+    /// the environment's `NO_PROXY` rules. Credentials for the proxy may be included in the URL,
+    /// provided via the `UV_PROXY_USERNAME` and `UV_PROXY_PASSWORD` environment variables, or stored
+    /// in the credentials file under a `[proxy.<name>]` entry with the proxy's `url`.
+    #[arg(global = true, long, env = "UV_PROXY", value_parser = parse_proxy)]
+    proxy: Option<ProxyConfig>,
+
+    /// The proxy through which to send requests to a named index, as `NAME=URL` (e.g.,
+    /// `internal=http://proxy.example.com:3128`); may be provided more than once.
+    ///
+    /// Applies to all requests to the host of the index provided via `--index NAME=<url>`, and
+    /// takes precedence over `--proxy` and `--no-proxy`. When provided via the `UV_INDEX_PROXY`
+    /// environment variable, multiple proxies are separated by spaces.
+    #[arg(
+        global = true,
+        long,
+        env = "UV_INDEX_PROXY",
+        value_delimiter = ' ',
+        value_parser = parse_index_proxy
+    )]
+    index_proxy: Vec<IndexProxy>,
+
+    /// A host for which requests bypass the proxy set via `--proxy`; may be provided more than
+    /// once.
+    ///
+    /// Matches the host and any of its subdomains, optionally limited to a single port (e.g.,
+    /// `internal.example.com:8080`); `*` matches all hosts. When provided via the `UV_NO_PROXY`
+    /// environment variable, multiple hosts are separated by commas.
+    #[arg(
+        global = true,
+        long,
+        env = "UV_NO_PROXY",
+        value_delimiter = ',',
+        value_parser = parse_proxy_bypass
+    )]
+    no_proxy: Vec<ProxyBypass>,
+
+    /// Send a bare `uv/<version>` user agent, omitting the linehaul metadata that otherwise
+    /// describes the platform (e.g., the distribution, libc, CPU, and CI environment) and the
+    /// Python interpreter.
+    #[arg(global = true, long, env = "UV_NO_TELEMETRY")]
+    no_linehaul: bool,
+
+    /// A suffix to append to the user agent of requests to a given host, as `HOST=SUFFIX` (e.g.,
+    /// `pypi.internal.example.com=team-ml/1.0`); may be provided more than once.
+    ///
+    /// Allows a proxy or an internal index to attribute traffic (e.g., to a team), without changing
+    /// the user agent sent to any other host. When provided via the `UV_USER_AGENT_SUFFIX`
+    /// environment variable, multiple suffixes are separated by spaces.
+    #[arg(
+        global = true,
+        long,
+        env = "UV_USER_AGENT_SUFFIX",
+        value_delimiter = ' ',
+        value_parser = parse_user_agent_suffix
+    )]
+    user_agent_suffix: Vec<UserAgentSuffix>,
+
+    /// If the index can't be reached (or responds with a server error) when revalidating a cached
+    /// response, use the cached response instead, as long as it has been stale for at most this
+    /// many seconds (i.e., `stale-if-error`).
+    ///
+    /// A warning is shown for each host whose stale responses are used. By default, stale
+    /// responses are never used, and `--refresh` always requires a successful revalidation.
+    #[arg(global = true, long, env = "UV_STALE_IF_ERROR", value_name = "SECONDS")]
+    stale_if_error: Option<u64>,
+
+    /// The maximum number of in-flight concurrent downloads.
+    ///
+    /// Defaults to 50.
+    #[arg(global = true, long, env = "UV_CONCURRENT_DOWNLOADS")]
+    concurrent_downloads: Option<NonZeroUsize>,
+
+    /// The maximum number of source distributions that uv will build concurrently at any given
+    /// time.
+    ///
+    /// Defaults to the number of CPU cores.
+    #[arg(global = true, long, env = "UV_CONCURRENT_BUILDS")]
+    concurrent_builds: Option<NonZeroUsize>,
+
+    /// The number of threads used when installing and unzipping packages.
+    ///
+    /// Defaults to the number of CPU cores.
+    #[arg(global = true, long, env = "UV_CONCURRENT_INSTALLS")]
+    concurrent_installs: Option<NonZeroUsize>,
+
     #[command(flatten)]
     cache_args: CacheArgs,
 }
@@ -133,8 +299,18 @@ enum Commands {
     /// Create a virtual environment.
     #[clap(alias = "virtualenv", alias = "v")]
     Venv(VenvArgs),
+    /// Add a dependency to the project's `pyproject.toml` and install it.
+    ///
+    /// As with `uv pip install`, the project's requirements are only installed (or upgraded), and
+    /// the environment isn't synced to the project: any other installed packages are left in
+    /// place.
+    Add(AddArgs),
+    /// Remove a dependency from the project's `pyproject.toml` and uninstall it.
+    Remove(RemoveArgs),
     /// Manage the cache.
     Cache(CacheNamespace),
+    /// Manage credentials for package indexes.
+    Auth(AuthNamespace),
     /// Manage the `uv` executable.
     #[clap(name = "self")]
     #[cfg(feature = "self-update")]
@@ -182,6 +358,71 @@ enum CacheCommand {
     Dir,
 }
 
+#[derive(Args)]
+struct AuthNamespace {
+    #[clap(subcommand)]
+    command: AuthCommand,
+}
+
+#[derive(Subcommand)]
+enum AuthCommand {
+    /// Validate and store credentials for an index.
+    Login(AuthLoginArgs),
+    /// Remove the stored credentials for an index.
+    Logout(AuthLogoutArgs),
+    /// List the indexes with stored credentials, without any secrets.
+    List,
+}
+
+#[derive(Args)]
+struct AuthLoginArgs {
+    /// The URL of the index (e.g., `https://pypi.internal.example.com/simple`).
+    index_url: Url,
+
+    /// The name under which to store the credentials.
+    ///
+    /// Credentials stored under the name of a named index (i.e., `--index <name>=<url>`) apply to
+    /// that index. Defaults to the host of the index URL.
+    #[clap(long)]
+    name: Option<String>,
+
+    /// The username with which to authenticate. If omitted, uv will prompt for it.
+    #[clap(long, conflicts_with = "token")]
+    username: Option<String>,
+
+    /// The password with which to authenticate. If omitted, uv will prompt for it.
+    ///
+    /// Command-line arguments are visible to other users on the same machine (e.g., via `ps`), so
+    /// prefer `--password-stdin` in scripts and CI.
+    #[clap(long, requires = "username", conflicts_with = "token")]
+    password: Option<String>,
+
+    /// Read the password from stdin, or, if no username is provided, the token.
+    ///
+    /// The first line of stdin is used, without its trailing newline (e.g.,
+    /// `echo "$TOKEN" | uv auth login <url> --password-stdin`).
+    #[clap(long, conflicts_with_all = ["password", "token"])]
+    password_stdin: bool,
+
+    /// A token with which to authenticate, sent as a bearer token.
+    ///
+    /// Command-line arguments are visible to other users on the same machine (e.g., via `ps`), so
+    /// prefer `--password-stdin` in scripts and CI.
+    #[clap(long)]
+    token: Option<String>,
+
+    /// Store the password in the system keyring, via the `keyring` CLI, rather than in the
+    /// credentials file.
+    #[clap(long, default_value_t, value_enum, env = "UV_KEYRING_PROVIDER")]
+    keyring_provider: KeyringProvider,
+}
+
+#[derive(Args)]
+struct AuthLogoutArgs {
+    /// The name or URL of the index.
+    index: String,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 struct CleanArgs {
@@ -213,6 +454,8 @@ enum PipCommand {
     Show(PipShowArgs),
     /// Verify installed packages have compatible dependencies.
     Check(PipCheckArgs),
+    /// Explain why a package is included in a resolution, and why it was pinned to its version.
+    Why(PipWhyArgs),
 }
 
 /// Clap parser for the union of date and datetime
@@ -233,6 +476,81 @@ fn date_or_datetime(input: &str) -> Result<DateTime<Utc>, String> {
     ))
 }
 
+/// Clap parser for a per-package exclusion date, e.g., `boto3=2024-01-01` or `boto3=false`.
+fn exclude_newer_package(input: &str) -> Result<(PackageName, Option<DateTime<Utc>>), String> {
+    let Some((package, date)) = input.split_once('=') else {
+        return Err(format!(
+            "Expected `PACKAGE=DATE` or `PACKAGE=false`, but found: `{input}`"
+        ));
+    };
+    let package = PackageName::from_str(package.trim()).map_err(|err| err.to_string())?;
+    let date = match date.trim() {
+        "false" => None,
+        date => Some(date_or_datetime(date)?),
+    };
+    Ok((package, date))
+}
+
+/// Parse a host (and optional port) to which insecure connections are allowed.
+fn parse_trusted_host(input: &str) -> Result<TrustedHost, String> {
+    TrustedHost::from_str(input).map_err(|err| err.to_string())
+}
+
+/// Parse a `HOST=PATH` pair for `--index-ca-cert`.
+fn parse_index_ca_cert(input: &str) -> Result<(TrustedHost, PathBuf), String> {
+    let Some((host, path)) = input.split_once('=') else {
+        return Err(format!("Expected `HOST=PATH`, but found: `{input}`"));
+    };
+    let host = parse_trusted_host(host)?;
+    Ok((host, PathBuf::from(path.trim())))
+}
+
+/// Parse a proxy URL for `--proxy`.
+fn parse_proxy(input: &str) -> Result<ProxyConfig, String> {
+    ProxyConfig::from_str(input).map_err(|err| err.to_string())
+}
+
+/// Parse a proxy for a named index, for `--index-proxy`.
+fn parse_index_proxy(input: &str) -> Result<IndexProxy, String> {
+    IndexProxy::from_str(input).map_err(|err| err.to_string())
+}
+
+/// Route the requests to each named index with an `--index-proxy` through its proxy.
+fn with_index_proxies(
+    proxy: Option<&ProxyConfig>,
+    index_proxies: &[IndexProxy],
+    index_locations: &IndexLocations,
+) -> Result<Option<ProxyConfig>> {
+    if index_proxies.is_empty() {
+        return Ok(proxy.cloned());
+    }
+    let mut proxy = proxy.cloned().unwrap_or_default();
+    for index_proxy in index_proxies {
+        let Some(index) = index_locations
+            .named_index()
+            .find(|index| index.name == index_proxy.name())
+        else {
+            bail!(
+                "`--index-proxy` refers to the index `{}`, but no index with that name was provided (e.g., `--index {}=<url>`)",
+                index_proxy.name(),
+                index_proxy.name()
+            );
+        };
+        proxy = proxy.with_index_proxy(&index.url, index_proxy.proxy().clone());
+    }
+    Ok(Some(proxy))
+}
+
+/// Parse a host (and optional port) that bypasses the proxy.
+fn parse_proxy_bypass(input: &str) -> Result<ProxyBypass, String> {
+    ProxyBypass::from_str(input).map_err(|err| err.to_string())
+}
+
+/// Parse a `HOST=SUFFIX` pair for `--user-agent-suffix`.
+fn parse_user_agent_suffix(input: &str) -> Result<UserAgentSuffix, String> {
+    UserAgentSuffix::from_str(input).map_err(|err| err.to_string())
+}
+
 /// A re-implementation of `Option`, used to avoid Clap's automatic `Option` flattening in
 /// [`parse_index_url`].
 #[derive(Debug, Clone)]
@@ -293,6 +611,22 @@ struct PipCompileArgs {
     #[clap(long)]
     r#override: Vec<PathBuf>,
 
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed into an isolated build environment (e.g., to pin
+    /// `setuptools`). Build constraints have no effect on the runtime requirements.
+    #[clap(long)]
+    build_constraint: Vec<PathBuf>,
+
+    /// Exclude the given package from the resolution, dropping any requirement on it.
+    ///
+    /// Unlike `--override`, which replaces a requirement, `--exclude` removes it entirely, whether
+    /// it's declared directly or by any transitive dependency. May be provided multiple times.
+    #[clap(long)]
+    exclude: Vec<PackageName>,
+
     /// Include optional dependencies in the given extra group name; may be provided more than once.
     #[clap(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
     extra: Vec<ExtraName>,
@@ -301,6 +635,15 @@ struct PipCompileArgs {
     #[clap(long, conflicts_with = "extra")]
     all_extras: bool,
 
+    /// Declare a set of mutually exclusive extras, as a comma-separated list (e.g., `cpu,cu121`);
+    /// may be provided more than once.
+    ///
+    /// When more than one extra from a set is enabled (e.g., via `--all-extras`), the resolution is
+    /// forked, with one fork per conflicting extra. Packages that differ between the forks are
+    /// emitted with `extra == "..."` markers, to be selected with `uv pip sync --extra`.
+    #[clap(long)]
+    conflicting_extras: Vec<ConflictingExtras>,
+
     /// Ignore package dependencies, instead only add those packages explicitly listed
     /// on the command line to the resulting the requirements file.
     #[clap(long)]
@@ -315,6 +658,12 @@ struct PipCompileArgs {
     #[clap(long, hide = true, conflicts_with = "prerelease")]
     pre: bool,
 
+    /// Allow pre-release versions of the given package, regardless of the `--prerelease` strategy.
+    ///
+    /// May be provided multiple times to allow pre-releases for several packages.
+    #[clap(long, env = "UV_PRERELEASE_PACKAGE", value_delimiter = ' ')]
+    prerelease_package: Vec<PackageName>,
+
     /// Write the compiled requirements to the given `requirements.txt` file.
     #[clap(long, short)]
     output_file: Option<PathBuf>,
@@ -384,6 +733,22 @@ struct PipCompileArgs {
     #[clap(long, conflicts_with = "index_url", conflicts_with = "extra_index_url")]
     no_index: bool,
 
+    /// A named package index, given as `<name>=<url>` (e.g., `internal=https://example.com/simple`).
+    ///
+    /// Named indexes are only consulted for the packages that are assigned to them via
+    /// `--index-for`.
+    #[clap(long = "index", env = "UV_INDEX", value_delimiter = ' ')]
+    named_index: Vec<NamedIndex>,
+
+    /// Fetch the given packages exclusively from a named index, given as `<package>=<name>` (e.g.,
+    /// `acme-core=internal`).
+    ///
+    /// The package may be a glob pattern, as in `acme-*=internal`. Packages that are assigned to an
+    /// index are never fetched from any other index, which prevents "dependency confusion" attacks
+    /// whereby an attacker uploads a package with the same name to a public index.
+    #[clap(long, env = "UV_INDEX_FOR", value_delimiter = ' ')]
+    index_for: Vec<IndexAssignment>,
+
     /// The strategy to use when resolving against multiple index URLs.
     ///
     /// By default, `uv` will stop at the first index on which a given package is available, and
@@ -418,6 +783,15 @@ struct PipCompileArgs {
     #[clap(long, short = 'P')]
     upgrade_package: Vec<PackageName>,
 
+    /// The strategy to use when reconciling upgrades with the pinned versions in the existing
+    /// output file.
+    ///
+    /// With `least-movement`, the existing pins are treated as constraints, and are only relaxed,
+    /// one at a time, when the resolution would otherwise be unsatisfiable. Any pins that had to
+    /// move are reported, along with the packages they conflicted with.
+    #[clap(long, value_enum, default_value_t = UpgradeStrategy::default())]
+    upgrade_strategy: UpgradeStrategy,
+
     /// Include distribution hashes in the output file.
     #[clap(long)]
     generate_hashes: bool,
@@ -472,6 +846,18 @@ struct PipCompileArgs {
     /// format (e.g., `2006-12-02`).
     #[arg(long, value_parser = date_or_datetime)]
     exclude_newer: Option<DateTime<Utc>>,
+    /// Limit candidate versions of a specific package to those that were uploaded prior to the
+    /// given date, in place of `--exclude-newer`; may be provided more than once.
+    ///
+    /// Accepts `PACKAGE=DATE`, with the date in the same format as `--exclude-newer`, or
+    /// `PACKAGE=false` to consider every version of the package regardless of its upload date.
+    #[arg(long, value_parser = exclude_newer_package)]
+    exclude_newer_package: Vec<(PackageName, Option<DateTime<Utc>>)>,
+
+    /// The behavior when a distribution is missing an upload time (e.g., because the index
+    /// doesn't provide upload times), but an exclusion date applies to its package.
+    #[arg(long, value_enum, default_value_t = MissingUploadTime::default())]
+    missing_upload_time: MissingUploadTime,
 
     /// Specify a package to omit from the output resolution. Its dependencies will still be
     /// included in the resolution. Equivalent to pip-compile's `--unsafe-package` option.
@@ -495,10 +881,48 @@ struct PipCompileArgs {
     #[clap(long, hide = true)]
     emit_marker_expression: bool,
 
+    /// Include the resolved build dependencies of each source distribution that was built during
+    /// the resolution (or whose build was read from the cache) in the generated output file, as
+    /// comments.
+    ///
+    /// Source distributions whose metadata is available without a build (e.g., from a static
+    /// `PKG-INFO`) are not included.
+    #[clap(long)]
+    emit_build_dependencies: bool,
+
+    /// Write the resolved build dependencies of each source distribution to the given file, in a
+    /// format that can be passed to `--build-constraint` (e.g., to reproduce the build environments
+    /// in `uv pip sync`).
+    #[clap(long)]
+    build_constraints_output_file: Option<PathBuf>,
+
     /// Choose the style of the annotation comments, which indicate the source of each package.
     #[clap(long, default_value_t=AnnotationStyle::Split, value_enum)]
     annotation_style: AnnotationStyle,
 
+    /// The format in which to report resolution failures.
+    ///
+    /// With `json`, the derivation tree explaining the failure, along with any hints, is written
+    /// to stdout as a JSON object.
+    #[clap(long, value_enum, default_value_t = ErrorFormat::default())]
+    error_format: ErrorFormat,
+
+    /// Report statistics about the resolution to stderr, to help diagnose slow resolutions.
+    ///
+    /// Includes the number of versions tried for each package, the time spent fetching metadata
+    /// for each package and index, the number of source distributions that were built to read
+    /// their metadata, and the packages that caused the most conflicts.
+    ///
+    /// If the resolution is retried (e.g., to relax a pin with `--upgrade-strategy
+    /// least-movement`), the statistics are reported separately for each attempt.
+    #[clap(long)]
+    resolver_stats: bool,
+
+    /// Write statistics about the resolution to the given file, as a JSON array with an entry for
+    /// each resolution attempt.
+    #[clap(long)]
+    resolver_stats_json: Option<PathBuf>,
+
     #[command(flatten)]
     compat_args: compat::PipCompileCompatArgs,
 }
@@ -510,6 +934,20 @@ struct PipSyncArgs {
     #[clap(required(true))]
     src_file: Vec<PathBuf>,
 
+    /// Include the requirements gated by the given extra (i.e., `extra == "..."` markers), as in a
+    /// requirements file compiled with `--conflicting-extras`; may be provided more than once.
+    #[clap(long, value_parser = extra_name_with_clap_error)]
+    extra: Vec<ExtraName>,
+
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed into an isolated build environment (e.g., to pin
+    /// `setuptools`). Build constraints have no effect on the runtime requirements.
+    #[clap(long)]
+    build_constraint: Vec<PathBuf>,
+
     /// Reinstall all packages, regardless of whether they're already installed.
     #[clap(long, alias = "force-reinstall")]
     reinstall: bool,
@@ -579,6 +1017,22 @@ struct PipSyncArgs {
     #[clap(long, conflicts_with = "index_url", conflicts_with = "extra_index_url")]
     no_index: bool,
 
+    /// A named package index, given as `<name>=<url>` (e.g., `internal=https://example.com/simple`).
+    ///
+    /// Named indexes are only consulted for the packages that are assigned to them via
+    /// `--index-for`.
+    #[clap(long = "index", env = "UV_INDEX", value_delimiter = ' ')]
+    named_index: Vec<NamedIndex>,
+
+    /// Fetch the given packages exclusively from a named index, given as `<package>=<name>` (e.g.,
+    /// `acme-core=internal`).
+    ///
+    /// The package may be a glob pattern, as in `acme-*=internal`. Packages that are assigned to an
+    /// index are never fetched from any other index, which prevents "dependency confusion" attacks
+    /// whereby an attacker uploads a package with the same name to a public index.
+    #[clap(long, env = "UV_INDEX_FOR", value_delimiter = ' ')]
+    index_for: Vec<IndexAssignment>,
+
     /// The strategy to use when resolving against multiple index URLs.
     ///
     /// By default, `uv` will stop at the first index on which a given package is available, and
@@ -603,8 +1057,11 @@ struct PipSyncArgs {
     /// workflows.
     ///
     /// Supported formats:
-    /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
-    ///   `python3.10` on Linux and macOS.
+    /// - `3.10` or `3.10.4` selects the newest Python in `PATH` matching `==3.10.*` or `==3.10.4`
+    ///   respectively.
+    /// - `>=3.10,<3.12` selects the newest Python in `PATH` that satisfies the version specifiers.
+    /// - `cpython@3.11`, `pypy3.10` or `graalpy` selects the newest Python of the given
+    ///   implementation in `PATH`, optionally restricted to a version or version specifiers.
     /// - `python3.10` or `python.exe` looks for a binary with the given name in `PATH`.
     /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
     #[clap(
@@ -750,6 +1207,22 @@ struct PipInstallArgs {
     #[clap(long)]
     r#override: Vec<PathBuf>,
 
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed into an isolated build environment (e.g., to pin
+    /// `setuptools`). Build constraints have no effect on the runtime requirements.
+    #[clap(long)]
+    build_constraint: Vec<PathBuf>,
+
+    /// Exclude the given package from the resolution, dropping any requirement on it.
+    ///
+    /// Unlike `--override`, which replaces a requirement, `--exclude` removes it entirely, whether
+    /// it's declared directly or by any transitive dependency. May be provided multiple times.
+    #[clap(long)]
+    exclude: Vec<PackageName>,
+
     /// Include optional dependencies in the given extra group name; may be provided more than once.
     #[clap(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
     extra: Vec<ExtraName>,
@@ -812,6 +1285,12 @@ struct PipInstallArgs {
     #[clap(long, hide = true, conflicts_with = "prerelease")]
     pre: bool,
 
+    /// Allow pre-release versions of the given package, regardless of the `--prerelease` strategy.
+    ///
+    /// May be provided multiple times to allow pre-releases for several packages.
+    #[clap(long, env = "UV_PRERELEASE_PACKAGE", value_delimiter = ' ')]
+    prerelease_package: Vec<PackageName>,
+
     /// Write the compiled requirements to the given `requirements.txt` file.
     #[clap(long, short)]
     output_file: Option<PathBuf>,
@@ -853,6 +1332,22 @@ struct PipInstallArgs {
     #[clap(long, conflicts_with = "index_url", conflicts_with = "extra_index_url")]
     no_index: bool,
 
+    /// A named package index, given as `<name>=<url>` (e.g., `internal=https://example.com/simple`).
+    ///
+    /// Named indexes are only consulted for the packages that are assigned to them via
+    /// `--index-for`.
+    #[clap(long = "index", env = "UV_INDEX", value_delimiter = ' ')]
+    named_index: Vec<NamedIndex>,
+
+    /// Fetch the given packages exclusively from a named index, given as `<package>=<name>` (e.g.,
+    /// `acme-core=internal`).
+    ///
+    /// The package may be a glob pattern, as in `acme-*=internal`. Packages that are assigned to an
+    /// index are never fetched from any other index, which prevents "dependency confusion" attacks
+    /// whereby an attacker uploads a package with the same name to a public index.
+    #[clap(long, env = "UV_INDEX_FOR", value_delimiter = ' ')]
+    index_for: Vec<IndexAssignment>,
+
     /// The strategy to use when resolving against multiple index URLs.
     ///
     /// By default, `uv` will stop at the first index on which a given package is available, and
@@ -877,8 +1372,11 @@ struct PipInstallArgs {
     /// workflows.
     ///
     /// Supported formats:
-    /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
-    ///   `python3.10` on Linux and macOS.
+    /// - `3.10` or `3.10.4` selects the newest Python in `PATH` matching `==3.10.*` or `==3.10.4`
+    ///   respectively.
+    /// - `>=3.10,<3.12` selects the newest Python in `PATH` that satisfies the version specifiers.
+    /// - `cpython@3.11`, `pypy3.10` or `graalpy` selects the newest Python of the given
+    ///   implementation in `PATH`, optionally restricted to a version or version specifiers.
     /// - `python3.10` or `python.exe` looks for a binary with the given name in `PATH`.
     /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
     #[clap(
@@ -988,11 +1486,30 @@ struct PipInstallArgs {
     /// format (e.g., `2006-12-02`).
     #[arg(long, value_parser = date_or_datetime)]
     exclude_newer: Option<DateTime<Utc>>,
+    /// Limit candidate versions of a specific package to those that were uploaded prior to the
+    /// given date, in place of `--exclude-newer`; may be provided more than once.
+    ///
+    /// Accepts `PACKAGE=DATE`, with the date in the same format as `--exclude-newer`, or
+    /// `PACKAGE=false` to consider every version of the package regardless of its upload date.
+    #[arg(long, value_parser = exclude_newer_package)]
+    exclude_newer_package: Vec<(PackageName, Option<DateTime<Utc>>)>,
+
+    /// The behavior when a distribution is missing an upload time (e.g., because the index
+    /// doesn't provide upload times), but an exclusion date applies to its package.
+    #[arg(long, value_enum, default_value_t = MissingUploadTime::default())]
+    missing_upload_time: MissingUploadTime,
 
     /// Perform a dry run, i.e., don't actually install anything but resolve the dependencies and
     /// print the resulting plan.
     #[clap(long)]
     dry_run: bool,
+
+    /// The format in which to report resolution failures.
+    ///
+    /// With `json`, the derivation tree explaining the failure, along with any hints, is written
+    /// to stdout as a JSON object.
+    #[clap(long, value_enum, default_value_t = ErrorFormat::default())]
+    error_format: ErrorFormat,
 }
 
 #[derive(Args)]
@@ -1015,8 +1532,11 @@ struct PipUninstallArgs {
     /// workflows.
     ///
     /// Supported formats:
-    /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
-    ///   `python3.10` on Linux and macOS.
+    /// - `3.10` or `3.10.4` selects the newest Python in `PATH` matching `==3.10.*` or `==3.10.4`
+    ///   respectively.
+    /// - `>=3.10,<3.12` selects the newest Python in `PATH` that satisfies the version specifiers.
+    /// - `cpython@3.11`, `pypy3.10` or `graalpy` selects the newest Python of the given
+    ///   implementation in `PATH`, optionally restricted to a version or version specifiers.
     /// - `python3.10` or `python.exe` looks for a binary with the given name in `PATH`.
     /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
     #[clap(
@@ -1084,8 +1604,11 @@ struct PipFreezeArgs {
     /// falling back to the system Python if no virtual environment is found.
     ///
     /// Supported formats:
-    /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
-    ///   `python3.10` on Linux and macOS.
+    /// - `3.10` or `3.10.4` selects the newest Python in `PATH` matching `==3.10.*` or `==3.10.4`
+    ///   respectively.
+    /// - `>=3.10,<3.12` selects the newest Python in `PATH` that satisfies the version specifiers.
+    /// - `cpython@3.11`, `pypy3.10` or `graalpy` selects the newest Python of the given
+    ///   implementation in `PATH`, optionally restricted to a version or version specifiers.
     /// - `python3.10` or `python.exe` looks for a binary with the given name in `PATH`.
     /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
     #[clap(
@@ -1146,8 +1669,11 @@ struct PipListArgs {
     /// falling back to the system Python if no virtual environment is found.
     ///
     /// Supported formats:
-    /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
-    ///   `python3.10` on Linux and macOS.
+    /// - `3.10` or `3.10.4` selects the newest Python in `PATH` matching `==3.10.*` or `==3.10.4`
+    ///   respectively.
+    /// - `>=3.10,<3.12` selects the newest Python in `PATH` that satisfies the version specifiers.
+    /// - `cpython@3.11`, `pypy3.10` or `graalpy` selects the newest Python of the given
+    ///   implementation in `PATH`, optionally restricted to a version or version specifiers.
     /// - `python3.10` or `python.exe` looks for a binary with the given name in `PATH`.
     /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
     #[clap(
@@ -1187,8 +1713,11 @@ struct PipCheckArgs {
     /// falling back to the system Python if no virtual environment is found.
     ///
     /// Supported formats:
-    /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
-    ///   `python3.10` on Linux and macOS.
+    /// - `3.10` or `3.10.4` selects the newest Python in `PATH` matching `==3.10.*` or `==3.10.4`
+    ///   respectively.
+    /// - `>=3.10,<3.12` selects the newest Python in `PATH` that satisfies the version specifiers.
+    /// - `cpython@3.11`, `pypy3.10` or `graalpy` selects the newest Python of the given
+    ///   implementation in `PATH`, optionally restricted to a version or version specifiers.
     /// - `python3.10` or `python.exe` looks for a binary with the given name in `PATH`.
     /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
     #[clap(
@@ -1218,6 +1747,155 @@ struct PipCheckArgs {
     system: bool,
 }
 
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+struct PipWhyArgs {
+    /// The package to explain.
+    package: PackageName,
+
+    /// Include all packages listed in the given `requirements.in` files.
+    ///
+    /// When the path is `-`, then requirements are read from stdin.
+    #[clap(required(true))]
+    src_file: Vec<PathBuf>,
+
+    /// Constrain versions using the given requirements files.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. However, including a package in a constraints file will _not_
+    /// trigger the installation of that package.
+    ///
+    /// This is equivalent to pip's `--constraint` option.
+    #[clap(long, short)]
+    constraint: Vec<PathBuf>,
+
+    /// Override versions using the given requirements files.
+    ///
+    /// Overrides files are `requirements.txt`-like files that force a specific version of a
+    /// requirement to be installed, regardless of the requirements declared by any constituent
+    /// package, and regardless of whether this would be considered an invalid resolution.
+    #[clap(long)]
+    r#override: Vec<PathBuf>,
+
+    /// Exclude the given package from the resolution, dropping any requirement on it.
+    ///
+    /// Unlike `--override`, which replaces a requirement, `--exclude` removes it entirely, whether
+    /// it's declared directly or by any transitive dependency. May be provided multiple times.
+    #[clap(long)]
+    exclude: Vec<PackageName>,
+
+    /// Include optional dependencies in the given extra group name; may be provided more than once.
+    #[clap(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    extra: Vec<ExtraName>,
+
+    /// Include all optional dependencies.
+    #[clap(long, conflicts_with = "extra")]
+    all_extras: bool,
+
+    #[clap(long, value_enum, default_value_t = ResolutionMode::default(), env = "UV_RESOLUTION")]
+    resolution: ResolutionMode,
+
+    #[clap(long, value_enum, default_value_t = PreReleaseMode::default(), conflicts_with = "pre", env = "UV_PRERELEASE")]
+    prerelease: PreReleaseMode,
+
+    #[clap(long, hide = true, conflicts_with = "prerelease")]
+    pre: bool,
+
+    /// Allow pre-release versions of the given package, regardless of the `--prerelease` strategy.
+    #[clap(long, env = "UV_PRERELEASE_PACKAGE", value_delimiter = ' ')]
+    prerelease_package: Vec<PackageName>,
+
+    /// Replay the resolution in the given `requirements.txt` file (e.g., as written by
+    /// `uv pip compile --output-file`), preferring the versions pinned therein.
+    #[clap(long, short)]
+    output_file: Option<PathBuf>,
+
+    /// Run offline, i.e., without accessing the network.
+    #[arg(global = true, long)]
+    offline: bool,
+
+    /// The URL of the Python package index (by default: <https://pypi.org/simple>).
+    #[clap(long, short, env = "UV_INDEX_URL", value_parser = parse_index_url)]
+    index_url: Option<Maybe<IndexUrl>>,
+
+    /// Extra URLs of package indexes to use, in addition to `--index-url`.
+    #[clap(long, env = "UV_EXTRA_INDEX_URL", value_delimiter = ' ', value_parser = parse_index_url)]
+    extra_index_url: Vec<Maybe<IndexUrl>>,
+
+    /// Ignore the registry index (e.g., PyPI), instead relying on direct URL dependencies and those
+    /// discovered via `--find-links`.
+    #[clap(long, conflicts_with = "index_url", conflicts_with = "extra_index_url")]
+    no_index: bool,
+
+    /// A named package index, given as `<name>=<url>` (e.g., `internal=https://example.com/simple`).
+    #[clap(long = "index", env = "UV_INDEX", value_delimiter = ' ')]
+    named_index: Vec<NamedIndex>,
+
+    /// Fetch the given packages exclusively from a named index, given as `<package>=<name>` (e.g.,
+    /// `acme-core=internal`).
+    #[clap(long, env = "UV_INDEX_FOR", value_delimiter = ' ')]
+    index_for: Vec<IndexAssignment>,
+
+    /// The strategy to use when resolving against multiple index URLs.
+    #[clap(long, default_value_t, value_enum, env = "UV_INDEX_STRATEGY")]
+    index_strategy: IndexStrategy,
+
+    /// Attempt to use `keyring` for authentication for index urls
+    #[clap(long, default_value_t, value_enum, env = "UV_KEYRING_PROVIDER")]
+    keyring_provider: KeyringProvider,
+
+    /// Locations to search for candidate distributions, beyond those found in the indexes.
+    #[clap(long, short)]
+    find_links: Vec<FlatIndexLocation>,
+
+    /// Use legacy `setuptools` behavior when building source distributions without a
+    /// `pyproject.toml`.
+    #[clap(long)]
+    legacy_setup_py: bool,
+
+    /// Disable isolation when building source distributions.
+    #[clap(long)]
+    no_build_isolation: bool,
+
+    /// Don't build source distributions.
+    ///
+    /// Alias for `--only-binary :all:`.
+    #[clap(long, conflicts_with = "only_binary")]
+    no_build: bool,
+
+    /// Only use pre-built wheels; don't build source distributions.
+    #[clap(long, conflicts_with = "no_build")]
+    only_binary: Vec<PackageNameSpecifier>,
+
+    /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
+    #[clap(long, short = 'C', alias = "config-settings")]
+    config_setting: Vec<ConfigSettingEntry>,
+
+    /// The minimum Python version that should be supported by the resolution (e.g., `3.7` or
+    /// `3.7.9`).
+    #[arg(long, short)]
+    python_version: Option<PythonVersion>,
+
+    /// Limit candidate packages to those that were uploaded prior to the given date.
+    ///
+    /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and UTC dates in the same
+    /// format (e.g., `2006-12-02`).
+    #[arg(long, value_parser = date_or_datetime)]
+    exclude_newer: Option<DateTime<Utc>>,
+    /// Limit candidate versions of a specific package to those that were uploaded prior to the
+    /// given date, in place of `--exclude-newer`; may be provided more than once.
+    ///
+    /// Accepts `PACKAGE=DATE`, with the date in the same format as `--exclude-newer`, or
+    /// `PACKAGE=false` to consider every version of the package regardless of its upload date.
+    #[arg(long, value_parser = exclude_newer_package)]
+    exclude_newer_package: Vec<(PackageName, Option<DateTime<Utc>>)>,
+
+    /// The behavior when a distribution is missing an upload time (e.g., because the index
+    /// doesn't provide upload times), but an exclusion date applies to its package.
+    #[arg(long, value_enum, default_value_t = MissingUploadTime::default())]
+    missing_upload_time: MissingUploadTime,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 struct PipShowArgs {
@@ -1236,8 +1914,11 @@ struct PipShowArgs {
     /// falling back to the system Python if no virtual environment is found.
     ///
     /// Supported formats:
-    /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
-    ///   `python3.10` on Linux and macOS.
+    /// - `3.10` or `3.10.4` selects the newest Python in `PATH` matching `==3.10.*` or `==3.10.4`
+    ///   respectively.
+    /// - `>=3.10,<3.12` selects the newest Python in `PATH` that satisfies the version specifiers.
+    /// - `cpython@3.11`, `pypy3.10` or `graalpy` selects the newest Python of the given
+    ///   implementation in `PATH`, optionally restricted to a version or version specifiers.
     /// - `python3.10` or `python.exe` looks for a binary with the given name in `PATH`.
     /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
     #[clap(
@@ -1273,8 +1954,11 @@ struct VenvArgs {
     /// The Python interpreter to use for the virtual environment.
     ///
     /// Supported formats:
-    /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
-    ///   `python3.10` on Linux and macOS.
+    /// - `3.10` or `3.10.4` selects the newest Python in `PATH` matching `==3.10.*` or `==3.10.4`
+    ///   respectively.
+    /// - `>=3.10,<3.12` selects the newest Python in `PATH` that satisfies the version specifiers.
+    /// - `cpython@3.11`, `pypy3.10` or `graalpy` selects the newest Python of the given
+    ///   implementation in `PATH`, optionally restricted to a version or version specifiers.
     /// - `python3.10` or `python.exe` looks for a binary with the given name in `PATH`.
     /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
     ///
@@ -1397,8 +2081,105 @@ struct VenvArgs {
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 struct AddArgs {
-    /// The name of the package to add (e.g., `Django==4.2.6`).
-    name: String,
+    /// The requirement to add (e.g., `Django==4.2.6` or `httpx[http2]`).
+    requirement: String,
+
+    /// Add the requirement to the given optional dependency group (extra), rather than to
+    /// `project.dependencies`.
+    #[clap(long, conflicts_with = "dev", value_parser = extra_name_with_clap_error)]
+    optional: Option<ExtraName>,
+
+    /// Add the requirement to the development dependencies, in `tool.uv.dev-dependencies`.
+    #[clap(long)]
+    dev: bool,
+
+    /// Add the requirement exactly as given, without a lower bound on the installed version.
+    ///
+    /// By default, a requirement without a version specifier (e.g., `httpx`) is written to the
+    /// `pyproject.toml` with a lower bound on the version that was installed (e.g.,
+    /// `httpx>=0.27.0`).
+    #[clap(long)]
+    raw: bool,
+
+    /// The URL of the Python package index (by default: <https://pypi.org/simple>).
+    ///
+    /// The index given by this flag is given lower priority than all other
+    /// indexes specified via the `--extra-index-url` flag.
+    ///
+    /// Unlike `pip`, `uv` will stop looking for versions of a package as soon
+    /// as it finds it in an index. That is, it isn't possible for `uv` to
+    /// consider versions of the same package across multiple indexes.
+    #[clap(long, short, env = "UV_INDEX_URL", value_parser = parse_index_url)]
+    index_url: Option<Maybe<IndexUrl>>,
+
+    /// Extra URLs of package indexes to use, in addition to `--index-url`.
+    ///
+    /// All indexes given via this flag take priority over the index
+    /// in `--index-url` (which defaults to PyPI). And when multiple
+    /// `--extra-index-url` flags are given, earlier values take priority.
+    ///
+    /// Unlike `pip`, `uv` will stop looking for versions of a package as soon
+    /// as it finds it in an index. That is, it isn't possible for `uv` to
+    /// consider versions of the same package across multiple indexes.
+    #[clap(long, env = "UV_EXTRA_INDEX_URL", value_delimiter = ' ', value_parser = parse_index_url)]
+    extra_index_url: Vec<Maybe<IndexUrl>>,
+
+    /// Locations to search for candidate distributions, beyond those found in the indexes.
+    ///
+    /// If a path, the target must be a directory that contains package as wheel files (`.whl`) or
+    /// source distributions (`.tar.gz` or `.zip`) at the top level.
+    ///
+    /// If a URL, the page must contain a flat list of links to package files.
+    #[clap(long, short)]
+    find_links: Vec<FlatIndexLocation>,
+
+    /// Ignore the registry index (e.g., PyPI), instead relying on direct URL dependencies and those
+    /// discovered via `--find-links`.
+    #[clap(long, conflicts_with = "index_url", conflicts_with = "extra_index_url")]
+    no_index: bool,
+
+    /// A named package index, given as `<name>=<url>` (e.g., `internal=https://example.com/simple`).
+    ///
+    /// Named indexes are only consulted for the packages that are assigned to them via
+    /// `--index-for`.
+    #[clap(long = "index", env = "UV_INDEX", value_delimiter = ' ')]
+    named_index: Vec<NamedIndex>,
+
+    /// Fetch the given packages exclusively from a named index, given as `<package>=<name>` (e.g.,
+    /// `acme-core=internal`).
+    ///
+    /// The package may be a glob pattern, as in `acme-*=internal`. Packages that are assigned to an
+    /// index are never fetched from any other index, which prevents "dependency confusion" attacks
+    /// whereby an attacker uploads a package with the same name to a public index.
+    #[clap(long, env = "UV_INDEX_FOR", value_delimiter = ' ')]
+    index_for: Vec<IndexAssignment>,
+
+    /// The strategy to use when resolving against multiple index URLs.
+    ///
+    /// By default, `uv` will stop at the first index on which a given package is available, and
+    /// limit resolutions to those present on that first index. This prevents "dependency confusion"
+    /// attacks, whereby an attack can upload a malicious package under the same name to a secondary
+    /// index.
+    #[clap(long, default_value_t, value_enum, env = "UV_INDEX_STRATEGY")]
+    index_strategy: IndexStrategy,
+
+    /// Attempt to use `keyring` for authentication for index urls
+    ///
+    /// Due to not having Python imports, only `--keyring-provider subprocess` argument is currently
+    /// implemented `uv` will try to use `keyring` via CLI when this flag is used.
+    #[clap(long, default_value_t, value_enum, env = "UV_KEYRING_PROVIDER")]
+    keyring_provider: KeyringProvider,
+
+    /// Run offline, i.e., without accessing the network.
+    #[arg(global = true, long)]
+    offline: bool,
+
+    /// Limit candidate packages to those that were uploaded prior to the given date.
+    ///
+    /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and UTC dates in the same
+    /// format (e.g., `2006-12-02`).
+    #[arg(long, value_parser = date_or_datetime)]
+    exclude_newer: Option<DateTime<Utc>>,
 }
 
 #[derive(Args)]
@@ -1406,6 +2187,15 @@ struct AddArgs {
 struct RemoveArgs {
     /// The name of the package to remove (e.g., `Django`).
     name: PackageName,
+
+    /// Remove the requirement from the given optional dependency group (extra), rather than from
+    /// `project.dependencies`.
+    #[clap(long, conflicts_with = "dev", value_parser = extra_name_with_clap_error)]
+    optional: Option<ExtraName>,
+
+    /// Remove the requirement from the development dependencies, in `tool.uv.dev-dependencies`.
+    #[clap(long)]
+    dev: bool,
 }
 
 #[instrument] // Anchor span to check for overhead
@@ -1428,13 +2218,13 @@ async fn run() -> Result<ExitStatus> {
                             ContextValue::String("uv pip sync".to_string()),
                         );
                     }
-                    "install" | "add" => {
+                    "install" => {
                         err.insert(
                             ContextKind::SuggestedSubcommand,
                             ContextValue::String("uv pip install".to_string()),
                         );
                     }
-                    "uninstall" | "remove" => {
+                    "uninstall" => {
                         err.insert(
                             ContextKind::SuggestedSubcommand,
                             ContextValue::String("uv pip uninstall".to_string()),
@@ -1512,6 +2302,55 @@ async fn run() -> Result<ExitStatus> {
 
     let cache = Cache::try_from(cli.cache_args)?;
 
+    // Read the client certificate, if any.
+    let client_cert = cli
+        .client_cert
+        .as_deref()
+        .map(|cert| {
+            let passphrase = env::var("UV_CLIENT_KEY_PASSWORD").ok();
+            ClientCertificate::from_pem(
+                cert,
+                cli.client_key.as_deref(),
+                passphrase.as_deref(),
+                cli.client_cert_host,
+            )
+            .with_context(|| format!("Failed to read client certificate: `{}`", cert.display()))
+        })
+        .transpose()?;
+
+    // Read the CA bundles for individual hosts, if any.
+    let ca_bundles = cli
+        .index_ca_cert
+        .into_iter()
+        .map(|(host, path)| {
+            HostCaBundle::from_pem(host, &path)
+                .with_context(|| format!("Failed to read CA bundle: `{}`", path.display()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let concurrency = Concurrency::new(
+        cli.concurrent_downloads,
+        cli.concurrent_builds,
+        cli.concurrent_installs,
+    );
+
+    // Apply any bypass rules to the proxy, or read the proxy from the environment.
+    let proxy = match cli.proxy {
+        Some(proxy) => Some(proxy.with_bypass(cli.no_proxy)),
+        None => {
+            if !cli.no_proxy.is_empty() {
+                warn_user!("`--no-proxy` has no effect without `--proxy`");
+            }
+            ProxyConfig::from_env()
+        }
+    };
+
+    // Limit the threads used to install (and unzip) wheels.
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(concurrency.installs)
+        .build_global()
+        .context("Failed to initialize the installer thread pool")?;
+
     match cli.command {
         Commands::Pip(PipNamespace {
             command: PipCommand::Compile(args),
@@ -1534,6 +2373,11 @@ async fn run() -> Result<ExitStatus> {
                 .into_iter()
                 .map(RequirementsSource::from_overrides_txt)
                 .collect::<Vec<_>>();
+            let build_constraints = args
+                .build_constraint
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
             let index_urls = IndexLocations::new(
                 args.index_url.and_then(Maybe::into_option),
                 args.extra_index_url
@@ -1542,7 +2386,9 @@ async fn run() -> Result<ExitStatus> {
                     .collect(),
                 args.find_links,
                 args.no_index,
-            );
+            )
+            .with_index_assignments(args.named_index, args.index_for)?;
+            let proxy = with_index_proxies(proxy.as_ref(), &cli.index_proxy, &index_urls)?;
             let extras = if args.all_extras {
                 ExtrasSpecification::All
             } else if args.extra.is_empty() {
@@ -1572,10 +2418,14 @@ async fn run() -> Result<ExitStatus> {
                 &requirements,
                 &constraints,
                 &overrides,
+                &build_constraints,
+                args.exclude,
                 extras,
+                &args.conflicting_extras,
                 args.output_file.as_deref(),
                 args.resolution,
                 prerelease,
+                args.prerelease_package,
                 dependency_mode,
                 upgrade,
                 args.generate_hashes,
@@ -1587,6 +2437,8 @@ async fn run() -> Result<ExitStatus> {
                 args.emit_index_url,
                 args.emit_find_links,
                 args.emit_marker_expression,
+                args.emit_build_dependencies,
+                args.build_constraints_output_file.as_deref(),
                 index_urls,
                 args.index_strategy,
                 args.keyring_provider,
@@ -1601,8 +2453,22 @@ async fn run() -> Result<ExitStatus> {
                 no_build,
                 args.python_version,
                 args.exclude_newer,
+                args.exclude_newer_package.into_iter().collect(),
+                args.missing_upload_time,
                 args.annotation_style,
+                args.upgrade_strategy,
+                args.error_format,
+                args.resolver_stats,
+                args.resolver_stats_json,
                 cli.native_tls,
+                client_cert.clone(),
+                ca_bundles.clone(),
+                cli.allow_insecure_host.clone(),
+                proxy,
+                !cli.no_linehaul,
+                cli.user_agent_suffix.clone(),
+                cli.stale_if_error.map(Duration::from_secs),
+                concurrency,
                 cli.quiet,
                 cache,
                 printer,
@@ -1623,12 +2489,19 @@ async fn run() -> Result<ExitStatus> {
                     .collect(),
                 args.find_links,
                 args.no_index,
-            );
+            )
+            .with_index_assignments(args.named_index, args.index_for)?;
+            let proxy = with_index_proxies(proxy.as_ref(), &cli.index_proxy, &index_urls)?;
             let sources = args
                 .src_file
                 .into_iter()
                 .map(RequirementsSource::from_requirements_file)
                 .collect::<Vec<_>>();
+            let build_constraints = args
+                .build_constraint
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
             let reinstall = Reinstall::from_args(args.reinstall, args.reinstall_package);
             let no_binary = NoBinary::from_args(args.no_binary);
             let no_build = NoBuild::from_args(args.only_binary, args.no_build);
@@ -1641,6 +2514,8 @@ async fn run() -> Result<ExitStatus> {
 
             commands::pip_sync(
                 &sources,
+                &args.extra,
+                &build_constraints,
                 &reinstall,
                 args.link_mode,
                 args.compile,
@@ -1662,6 +2537,14 @@ async fn run() -> Result<ExitStatus> {
                 args.system,
                 args.break_system_packages,
                 cli.native_tls,
+                client_cert.clone(),
+                ca_bundles.clone(),
+                cli.allow_insecure_host.clone(),
+                proxy,
+                !cli.no_linehaul,
+                cli.user_agent_suffix.clone(),
+                cli.stale_if_error.map(Duration::from_secs),
+                concurrency,
                 cache,
                 printer,
             )
@@ -1692,6 +2575,11 @@ async fn run() -> Result<ExitStatus> {
                 .into_iter()
                 .map(RequirementsSource::from_overrides_txt)
                 .collect::<Vec<_>>();
+            let build_constraints = args
+                .build_constraint
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
             let index_urls = IndexLocations::new(
                 args.index_url.and_then(Maybe::into_option),
                 args.extra_index_url
@@ -1700,7 +2588,9 @@ async fn run() -> Result<ExitStatus> {
                     .collect(),
                 args.find_links,
                 args.no_index,
-            );
+            )
+            .with_index_assignments(args.named_index, args.index_for)?;
+            let proxy = with_index_proxies(proxy.as_ref(), &cli.index_proxy, &index_urls)?;
             let extras = if args.all_extras {
                 ExtrasSpecification::All
             } else if args.extra.is_empty() {
@@ -1733,9 +2623,12 @@ async fn run() -> Result<ExitStatus> {
                 &requirements,
                 &constraints,
                 &overrides,
+                &build_constraints,
+                args.exclude,
                 &extras,
                 args.resolution,
                 prerelease,
+                args.prerelease_package,
                 dependency_mode,
                 upgrade,
                 index_urls,
@@ -1756,12 +2649,23 @@ async fn run() -> Result<ExitStatus> {
                 no_binary,
                 args.strict,
                 args.exclude_newer,
+                args.exclude_newer_package.into_iter().collect(),
+                args.missing_upload_time,
                 args.python,
                 args.system,
                 args.break_system_packages,
                 cli.native_tls,
+                client_cert.clone(),
+                ca_bundles.clone(),
+                cli.allow_insecure_host.clone(),
+                proxy,
+                !cli.no_linehaul,
+                cli.user_agent_suffix.clone(),
+                cli.stale_if_error.map(Duration::from_secs),
+                concurrency,
                 cache,
                 args.dry_run,
+                args.error_format,
                 printer,
             )
             .await
@@ -1791,6 +2695,13 @@ async fn run() -> Result<ExitStatus> {
                     Connectivity::Online
                 },
                 cli.native_tls,
+                client_cert.clone(),
+                ca_bundles.clone(),
+                cli.allow_insecure_host.clone(),
+                proxy.clone(),
+                !cli.no_linehaul,
+                cli.user_agent_suffix.clone(),
+                cli.stale_if_error.map(Duration::from_secs),
                 args.keyring_provider,
                 printer,
             )
@@ -1832,6 +2743,95 @@ async fn run() -> Result<ExitStatus> {
         Commands::Pip(PipNamespace {
             command: PipCommand::Check(args),
         }) => commands::pip_check(args.python.as_deref(), args.system, &cache, printer),
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Why(args),
+        }) => {
+            let requirements = args
+                .src_file
+                .into_iter()
+                .map(RequirementsSource::from_requirements_file)
+                .collect::<Vec<_>>();
+            let constraints = args
+                .constraint
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
+            let overrides = args
+                .r#override
+                .into_iter()
+                .map(RequirementsSource::from_overrides_txt)
+                .collect::<Vec<_>>();
+            let index_urls = IndexLocations::new(
+                args.index_url.and_then(Maybe::into_option),
+                args.extra_index_url
+                    .into_iter()
+                    .filter_map(Maybe::into_option)
+                    .collect(),
+                args.find_links,
+                args.no_index,
+            )
+            .with_index_assignments(args.named_index, args.index_for)?;
+            let proxy = with_index_proxies(proxy.as_ref(), &cli.index_proxy, &index_urls)?;
+            let extras = if args.all_extras {
+                ExtrasSpecification::All
+            } else if args.extra.is_empty() {
+                ExtrasSpecification::None
+            } else {
+                ExtrasSpecification::Some(&args.extra)
+            };
+            let no_build = NoBuild::from_args(args.only_binary, args.no_build);
+            let prerelease = if args.pre {
+                PreReleaseMode::Allow
+            } else {
+                args.prerelease
+            };
+            let setup_py = if args.legacy_setup_py {
+                SetupPyStrategy::Setuptools
+            } else {
+                SetupPyStrategy::Pep517
+            };
+            let config_settings = args.config_setting.into_iter().collect::<ConfigSettings>();
+            commands::pip_why(
+                &args.package,
+                &requirements,
+                &constraints,
+                &overrides,
+                args.exclude,
+                extras,
+                args.output_file.as_deref(),
+                args.resolution,
+                prerelease,
+                args.prerelease_package,
+                index_urls,
+                args.index_strategy,
+                args.keyring_provider,
+                setup_py,
+                config_settings,
+                if args.offline {
+                    Connectivity::Offline
+                } else {
+                    Connectivity::Online
+                },
+                args.no_build_isolation,
+                no_build,
+                args.python_version,
+                args.exclude_newer,
+                args.exclude_newer_package.into_iter().collect(),
+                args.missing_upload_time,
+                cli.native_tls,
+                client_cert.clone(),
+                ca_bundles.clone(),
+                cli.allow_insecure_host.clone(),
+                proxy,
+                !cli.no_linehaul,
+                cli.user_agent_suffix.clone(),
+                cli.stale_if_error.map(Duration::from_secs),
+                concurrency,
+                cache,
+                printer,
+            )
+            .await
+        }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Clean(args),
         })
@@ -1845,6 +2845,35 @@ async fn run() -> Result<ExitStatus> {
             commands::cache_dir(&cache);
             Ok(ExitStatus::Success)
         }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Login(args),
+        }) => {
+            commands::auth_login(
+                args.index_url,
+                args.name,
+                args.username,
+                args.password,
+                args.password_stdin,
+                args.token,
+                args.keyring_provider,
+                cli.native_tls,
+                client_cert,
+                ca_bundles,
+                cli.allow_insecure_host,
+                proxy.clone(),
+                !cli.no_linehaul,
+                cli.user_agent_suffix.clone(),
+                cli.stale_if_error.map(Duration::from_secs),
+                printer,
+            )
+            .await
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Logout(args),
+        }) => commands::auth_logout(&args.index, printer),
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::List,
+        }) => commands::auth_list(printer),
         Commands::Venv(args) => {
             args.compat_args.validate()?;
 
@@ -1884,11 +2913,73 @@ async fn run() -> Result<ExitStatus> {
                 args.seed,
                 args.exclude_newer,
                 cli.native_tls,
+                client_cert.clone(),
+                ca_bundles.clone(),
+                cli.allow_insecure_host.clone(),
+                proxy.clone(),
+                !cli.no_linehaul,
+                cli.user_agent_suffix.clone(),
+                cli.stale_if_error.map(Duration::from_secs),
+                concurrency,
                 &cache,
                 printer,
             )
             .await
         }
+        Commands::Add(args) => {
+            let index_locations = IndexLocations::new(
+                args.index_url.and_then(Maybe::into_option),
+                args.extra_index_url
+                    .into_iter()
+                    .filter_map(Maybe::into_option)
+                    .collect(),
+                args.find_links,
+                args.no_index,
+            )
+            .with_index_assignments(args.named_index, args.index_for)?;
+            let proxy = with_index_proxies(proxy.as_ref(), &cli.index_proxy, &index_locations)?;
+            let dependency_type = match (args.optional, args.dev) {
+                (Some(extra), _) => DependencyType::Optional(extra),
+                (None, true) => DependencyType::Dev,
+                (None, false) => DependencyType::Production,
+            };
+
+            commands::add(
+                &args.requirement,
+                dependency_type,
+                args.raw,
+                index_locations,
+                args.index_strategy,
+                args.keyring_provider,
+                if args.offline {
+                    Connectivity::Offline
+                } else {
+                    Connectivity::Online
+                },
+                args.exclude_newer,
+                cli.native_tls,
+                client_cert.clone(),
+                ca_bundles.clone(),
+                cli.allow_insecure_host.clone(),
+                proxy,
+                !cli.no_linehaul,
+                cli.user_agent_suffix.clone(),
+                cli.stale_if_error.map(Duration::from_secs),
+                concurrency,
+                cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Remove(args) => {
+            let dependency_type = match (args.optional, args.dev) {
+                (Some(extra), _) => DependencyType::Optional(extra),
+                (None, true) => DependencyType::Dev,
+                (None, false) => DependencyType::Production,
+            };
+
+            commands::remove(&args.name, dependency_type, cache, printer).await
+        }
         #[cfg(feature = "self-update")]
         Commands::Self_(SelfNamespace {
             command: SelfCommand::Update,
@@ -0,0 +1,140 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use common::uv_snapshot;
+
+use crate::common::{get_bin, TestContext, EXCLUDE_NEWER};
+
+mod common;
+
+/// Create a `uv add` command with options shared across scenarios.
+fn add_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("add")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .arg("--exclude-newer")
+        .arg(EXCLUDE_NEWER)
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+
+    if cfg!(all(windows, debug_assertions)) {
+        // TODO(konstin): Reduce stack usage in debug mode enough that the tests pass with the
+        // default windows stack of 1MB
+        command.env("UV_STACK_SIZE", (4 * 1024 * 1024).to_string());
+    }
+
+    command
+}
+
+/// Add a registry requirement, which is written with a lower bound on the installed version.
+#[test]
+fn add_registry() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(add_command(&context).arg("iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Added iniconfig>=2.0.0 to `project.dependencies` in pyproject.toml
+    "###
+    );
+
+    insta::assert_snapshot!(fs_err::read_to_string(pyproject_toml.path())?, @r###"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    dependencies = ["iniconfig>=2.0.0"]
+    "###);
+
+    context.assert_command("import iniconfig").success();
+
+    Ok(())
+}
+
+/// Add a requirement to an extra, as given.
+#[test]
+fn add_optional_raw() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(add_command(&context)
+        .arg("iniconfig")
+        .arg("--optional")
+        .arg("test")
+        .arg("--raw"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Added iniconfig to `project.optional-dependencies.test` in pyproject.toml
+    "###
+    );
+
+    insta::assert_snapshot!(fs_err::read_to_string(pyproject_toml.path())?, @r###"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    dependencies = []
+
+    [project.optional-dependencies]
+    test = ["iniconfig"]
+    "###);
+
+    Ok(())
+}
+
+/// If the requirement can't be installed, the `pyproject.toml` is left untouched.
+#[test]
+fn add_unsatisfiable() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    let contents = indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = []
+    "#};
+    pyproject_toml.write_str(contents)?;
+
+    add_command(&context)
+        .arg("iniconfig>100")
+        .assert()
+        .failure();
+
+    assert_eq!(fs_err::read_to_string(pyproject_toml.path())?, contents);
+
+    Ok(())
+}
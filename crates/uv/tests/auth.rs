@@ -0,0 +1,294 @@
+#![cfg(feature = "python")]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::process::Command;
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use common::uv_snapshot;
+
+use crate::common::{get_bin, TestContext};
+
+mod common;
+
+/// Create a `uv auth` command with options shared across scenarios.
+fn auth_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("auth")
+        .env(
+            "UV_CREDENTIALS_FILE",
+            context.temp_dir.child("credentials.toml").as_os_str(),
+        )
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+    command
+}
+
+/// Start a local index that accepts requests with the given `Authorization` header, and rejects
+/// all others with `401 Unauthorized`.
+///
+/// Returns the URL of the index.
+fn start_index(authorization: &'static str) -> Result<String> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else {
+                continue;
+            };
+            let mut authorized = false;
+            let mut reader = BufReader::new(&stream);
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|read| read > 0) {
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("authorization") && value.trim() == authorization {
+                        authorized = true;
+                    }
+                }
+                line.clear();
+            }
+            let status = if authorized {
+                "200 OK"
+            } else {
+                "401 Unauthorized"
+            };
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+            );
+        }
+    });
+    Ok(format!("http://{addr}/simple"))
+}
+
+/// List the stored credentials, without any secrets.
+#[test]
+fn list() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), auth_command(&context).arg("list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No credentials found in: credentials.toml
+    "###
+    );
+
+    context
+        .temp_dir
+        .child("credentials.toml")
+        .write_str(indoc! {r#"
+            [index.internal]
+            url = "https://pypi.internal.example.com/simple"
+            username = "ci"
+            password = "secret"
+
+            [index.gitlab]
+            token = "secret"
+        "#})?;
+
+    uv_snapshot!(context.filters(), auth_command(&context).arg("list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    gitlab (named index) (token)
+    internal https://pypi.internal.example.com/simple (username `ci`)
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// Remove stored credentials by name, or by URL.
+#[test]
+fn logout() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let credentials_toml = context.temp_dir.child("credentials.toml");
+    credentials_toml.write_str(indoc! {r#"
+        [index.internal]
+        url = "https://pypi.internal.example.com/simple"
+        username = "ci"
+        password = "secret"
+
+        [index.gitlab]
+        token = "secret"
+    "#})?;
+
+    uv_snapshot!(context.filters(), auth_command(&context)
+        .arg("logout")
+        .arg("https://pypi.internal.example.com/simple/"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed credentials for `internal`
+    "###
+    );
+
+    uv_snapshot!(context.filters(), auth_command(&context)
+        .arg("logout")
+        .arg("internal"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    No credentials found for: internal
+    "###
+    );
+
+    // The remaining entries are left untouched.
+    let contents = fs_err::read_to_string(credentials_toml.path())?;
+    assert!(!contents.contains("internal"));
+    assert!(contents.contains("[index.gitlab]"));
+
+    Ok(())
+}
+
+/// Validate and store a username and password, read from stdin.
+#[test]
+fn login_password_stdin() -> Result<()> {
+    let context = TestContext::new("3.12");
+    // `ci:secret`, base64-encoded.
+    let index_url = start_index("Basic Y2k6c2VjcmV0")?;
+
+    let filters = [(r"127\.0\.0\.1:\d+", "[SERVER]")]
+        .into_iter()
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+
+    let stdin = context.temp_dir.child("stdin.txt");
+    stdin.write_str("secret\n")?;
+
+    uv_snapshot!(filters, auth_command(&context)
+        .arg("login")
+        .arg(&index_url)
+        .arg("--name")
+        .arg("internal")
+        .arg("--username")
+        .arg("ci")
+        .arg("--password-stdin")
+        .stdin(fs_err::File::open(stdin.path())?), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for http://[SERVER]/simple as `internal` in: credentials.toml
+    "###
+    );
+
+    let contents = fs_err::read_to_string(context.temp_dir.child("credentials.toml").path())?;
+    assert!(contents.contains("[index.internal]"));
+    assert!(contents.contains(r#"username = "ci""#));
+    assert!(contents.contains(r#"password = "secret""#));
+
+    Ok(())
+}
+
+/// Without a username, the secret read from stdin is sent as a bearer token.
+#[test]
+fn login_token_stdin() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let index_url = start_index("Bearer secret")?;
+
+    let filters = [(r"127\.0\.0\.1:\d+", "[SERVER]")]
+        .into_iter()
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+
+    let stdin = context.temp_dir.child("stdin.txt");
+    stdin.write_str("secret\n")?;
+
+    uv_snapshot!(filters, auth_command(&context)
+        .arg("login")
+        .arg(&index_url)
+        .arg("--name")
+        .arg("internal")
+        .arg("--password-stdin")
+        .stdin(fs_err::File::open(stdin.path())?), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for http://[SERVER]/simple as `internal` in: credentials.toml
+    "###
+    );
+
+    let contents = fs_err::read_to_string(context.temp_dir.child("credentials.toml").path())?;
+    assert!(contents.contains(r#"token = "secret""#));
+
+    Ok(())
+}
+
+/// Credentials that the index rejects are not stored.
+#[test]
+fn login_invalid() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let index_url = start_index("Basic Y2k6c2VjcmV0")?;
+
+    let filters = [(r"127\.0\.0\.1:\d+", "[SERVER]")]
+        .into_iter()
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+
+    let stdin = context.temp_dir.child("stdin.txt");
+    stdin.write_str("wrong\n")?;
+
+    uv_snapshot!(filters, auth_command(&context)
+        .arg("login")
+        .arg(&index_url)
+        .arg("--username")
+        .arg("ci")
+        .arg("--password-stdin")
+        .stdin(fs_err::File::open(stdin.path())?), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to authenticate with http://[SERVER]/simple: the index returned `401 Unauthorized`
+    "###
+    );
+
+    context
+        .temp_dir
+        .child("credentials.toml")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
+
+/// Without a terminal, credentials must be provided upfront.
+#[test]
+fn login_no_terminal() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), auth_command(&context)
+        .arg("login")
+        .arg("https://pypi.internal.example.com/simple"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Credentials must be provided via `--username` and `--password-stdin` (or `--token`) when not running in a terminal
+    "###
+    );
+
+    Ok(())
+}
@@ -3756,3 +3756,156 @@ fn find_links_no_binary() -> Result<()> {
 
     Ok(())
 }
+
+/// Install from a local PEP 503 index, laid out as a directory tree and passed as a `file://` URL.
+#[test]
+fn local_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Serve the wheel from outside the project directory, via a relative link.
+    let wheel = "tqdm-1000.0.0-py3-none-any.whl";
+    fs_err::create_dir_all(context.temp_dir.child("packages"))?;
+    fs_err::copy(
+        context.workspace_root.join("scripts/links").join(wheel),
+        context.temp_dir.child("packages").join(wheel),
+    )?;
+    context
+        .temp_dir
+        .child("simple")
+        .child("tqdm")
+        .child("index.html")
+        .write_str(&format!(
+            r#"<html><body><a href="../../packages/{wheel}">{wheel}</a></body></html>"#
+        ))?;
+
+    let index_url = url::Url::from_directory_path(context.temp_dir.child("simple").path())
+        .unwrap()
+        .to_string();
+
+    uv_snapshot!(context.filters(), context.install_without_exclude_newer()
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg(index_url), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Install from a local PEP 691 index, laid out as a directory tree of `index.json` files.
+#[test]
+fn local_index_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheel = "tqdm-1000.0.0-py3-none-any.whl";
+    fs_err::create_dir_all(context.temp_dir.child("packages"))?;
+    fs_err::copy(
+        context.workspace_root.join("scripts/links").join(wheel),
+        context.temp_dir.child("packages").join(wheel),
+    )?;
+    context
+        .temp_dir
+        .child("simple")
+        .child("tqdm")
+        .child("index.json")
+        .write_str(&format!(
+            r#"{{"meta": {{"api-version": "1.0"}}, "name": "tqdm", "files": [{{"filename": "{wheel}", "url": "../../packages/{wheel}", "hashes": {{}}}}]}}"#
+        ))?;
+
+    let index_url = url::Url::from_directory_path(context.temp_dir.child("simple").path())
+        .unwrap()
+        .to_string();
+
+    uv_snapshot!(context.filters(), context.install_without_exclude_newer()
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg(index_url), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Install from a local PEP 503 index that advertises `data-dist-info-metadata`, in which case
+/// the metadata is read from the `.metadata` file alongside the wheel rather than the wheel
+/// itself.
+#[test]
+fn local_index_dist_info_metadata() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let packages = context.temp_dir.child("packages");
+    fs_err::create_dir_all(&packages)?;
+    for wheel in [
+        "tqdm-1000.0.0-py3-none-any.whl",
+        "validation-1.0.0-py3-none-any.whl",
+    ] {
+        fs_err::copy(
+            context.workspace_root.join("scripts/links").join(wheel),
+            packages.join(wheel),
+        )?;
+    }
+
+    // The `.metadata` file declares a dependency that the wheel's own metadata doesn't.
+    packages
+        .child("tqdm-1000.0.0-py3-none-any.whl.metadata")
+        .write_str(indoc! {r"
+            Metadata-Version: 2.1
+            Name: tqdm
+            Version: 1000.0.0
+            Requires-Python: >=3.10,<4.0
+            Requires-Dist: validation==1.0.0
+        "})?;
+
+    let simple = context.temp_dir.child("simple");
+    simple.child("tqdm").child("index.html").write_str(indoc! {r#"
+        <html><body>
+        <a href="../../packages/tqdm-1000.0.0-py3-none-any.whl" data-dist-info-metadata="true">tqdm-1000.0.0-py3-none-any.whl</a>
+        </body></html>
+    "#})?;
+    simple.child("validation").child("index.html").write_str(indoc! {r#"
+        <html><body>
+        <a href="../../packages/validation-1.0.0-py3-none-any.whl">validation-1.0.0-py3-none-any.whl</a>
+        </body></html>
+    "#})?;
+
+    let index_url = url::Url::from_directory_path(simple.path())
+        .unwrap()
+        .to_string();
+
+    uv_snapshot!(context.filters(), context.install_without_exclude_newer()
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg(index_url), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + tqdm==1000.0.0
+     + validation==1.0.0
+    "###
+    );
+
+    Ok(())
+}
@@ -2596,7 +2596,7 @@ fn package_only_prereleases_in_range() {
       × No solution found when resolving dependencies:
       ╰─▶ Because only package-a<=0.1.0 is available and you require package-a>0.1.0, we can conclude that the requirements are unsatisfiable.
 
-          hint: Pre-releases are available for package-a in the requested range (e.g., 1.0.0a1), but pre-releases weren't enabled (try: `--prerelease=allow`)
+          hint: Pre-releases are available for package-a in the requested range (e.g., 1.0.0a1), but pre-releases weren't enabled (try: `--prerelease-package package-a` or `--prerelease=allow`)
     "###);
 
     // Since there are stable versions of `a` available, prerelease versions should not
@@ -3045,7 +3045,7 @@ fn transitive_package_only_prereleases_in_range() {
       ╰─▶ Because only package-b<=0.1 is available and package-a==0.1.0 depends on package-b>0.1, we can conclude that package-a==0.1.0 cannot be used.
           And because only package-a==0.1.0 is available and you require package-a, we can conclude that the requirements are unsatisfiable.
 
-          hint: Pre-releases are available for package-b in the requested range (e.g., 1.0.0a1), but pre-releases weren't enabled (try: `--prerelease=allow`)
+          hint: Pre-releases are available for package-b in the requested range (e.g., 1.0.0a1), but pre-releases weren't enabled (try: `--prerelease-package package-b` or `--prerelease=allow`)
     "###);
 
     // Since there are stable versions of `b` available, the prerelease version should
@@ -3167,7 +3167,7 @@ fn transitive_prerelease_and_stable_dependency() {
       ╰─▶ Because there is no version of package-c==2.0.0b1 and package-a==1.0.0 depends on package-c==2.0.0b1, we can conclude that package-a==1.0.0 cannot be used.
           And because only package-a==1.0.0 is available and you require package-a, we can conclude that the requirements are unsatisfiable.
 
-          hint: package-c was requested with a pre-release marker (e.g., package-c==2.0.0b1), but pre-releases weren't enabled (try: `--prerelease=allow`)
+          hint: package-c was requested with a pre-release marker (e.g., package-c==2.0.0b1), but pre-releases weren't enabled (try: `--prerelease-package package-c` or `--prerelease=allow`)
     "###);
 
     // Since the user did not explicitly opt-in to a prerelease, it cannot be selected.
@@ -3336,7 +3336,7 @@ fn transitive_prerelease_and_stable_dependency_many_versions() {
           And because package-b==1.0.0 depends on package-c and only package-b==1.0.0 is available, we can conclude that all versions of package-b and all versions of package-a are incompatible.
           And because you require package-a and you require package-b, we can conclude that the requirements are unsatisfiable.
 
-          hint: package-c was requested with a pre-release marker (e.g., package-c>=2.0.0b1), but pre-releases weren't enabled (try: `--prerelease=allow`)
+          hint: package-c was requested with a pre-release marker (e.g., package-c>=2.0.0b1), but pre-releases weren't enabled (try: `--prerelease-package package-c` or `--prerelease=allow`)
     "###);
 
     // Since the user did not explicitly opt-in to a prerelease, it cannot be selected.
@@ -3431,7 +3431,7 @@ fn transitive_prerelease_and_stable_dependency_many_versions_holes() {
               package-c>1.0.0,<2.0.0a5
               package-c>2.0.0a7,<2.0.0b1
               package-c>2.0.0b1,<2.0.0b5
-          ), but pre-releases weren't enabled (try: `--prerelease=allow`)
+          ), but pre-releases weren't enabled (try: `--prerelease-package package-c` or `--prerelease=allow`)
     "###);
 
     // Since the user did not explicitly opt-in to a prerelease, it cannot be selected.
@@ -178,6 +178,43 @@ fn install() -> Result<()> {
     Ok(())
 }
 
+/// Install the requirements gated by the given extra, as emitted for conflicting extras.
+#[test]
+fn install_conflicting_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.0.0 ; extra == 'bar'
+        anyio==3.7.0 ; extra == 'foo'
+        idna==3.6
+        sniffio==1.3.1
+    "})?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--extra")
+        .arg("foo")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Downloaded 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    context.assert_command("import anyio").success();
+
+    Ok(())
+}
+
 /// Install a package into a virtual environment using copy semantics.
 #[test]
 fn install_copy() -> Result<()> {
@@ -0,0 +1,175 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use std::process::Command;
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use common::uv_snapshot;
+
+use crate::common::{get_bin, TestContext, EXCLUDE_NEWER};
+
+mod common;
+
+/// Create a `pip why` command with options shared across tests.
+fn why_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("pip")
+        .arg("why")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .arg("--exclude-newer")
+        .arg(EXCLUDE_NEWER)
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+
+    if cfg!(all(windows, debug_assertions)) {
+        // TODO(konstin): Reduce stack usage in debug mode enough that the tests pass with the
+        // default windows stack of 1MB
+        command.env("UV_STACK_SIZE", (8 * 1024 * 1024).to_string());
+    }
+
+    command
+}
+
+/// Explain a transitive dependency, which is reachable through a single path.
+#[test]
+fn why_transitive() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    uv_snapshot!(why_command(&context)
+        .arg("sniffio")
+        .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    sniffio==1.3.1
+        anyio==3.7.0 -> sniffio>=1.1
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Request an explanation for a package that isn't part of the resolution.
+#[test]
+fn why_missing() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    uv_snapshot!(why_command(&context)
+        .arg("flask")
+        .arg("requirements.in"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    error: Package `flask` is not included in the resolution
+    "###);
+
+    Ok(())
+}
+
+/// Replay the resolution in an output file, which pins an older version of the package.
+#[test]
+fn why_output_file() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio<4")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==3.7.0
+        idna==3.6
+        sniffio==1.3.0
+    "})?;
+
+    uv_snapshot!(why_command(&context)
+        .arg("sniffio")
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    sniffio==1.3.0
+        anyio<4 -> sniffio>=1.1
+    Newer versions were rejected:
+        1.3.1: not selected by the resolver
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Explain a package whose newer versions are excluded by a constraint.
+#[test]
+fn why_constraint() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("anyio<4")?;
+
+    uv_snapshot!(why_command(&context)
+        .arg("anyio")
+        .arg("requirements.in")
+        .arg("--constraint")
+        .arg("constraints.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio==3.7.1
+        anyio<4
+    Newer versions were rejected:
+        4.0.0, 4.1.0, 4.2.0, 4.3.0: the constraints specify `anyio<4`
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Explain a package whose newer versions are excluded by an override.
+#[test]
+fn why_override() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    let overrides_txt = context.temp_dir.child("overrides.txt");
+    overrides_txt.write_str("sniffio<1.3.1")?;
+
+    uv_snapshot!(why_command(&context)
+        .arg("sniffio")
+        .arg("requirements.in")
+        .arg("--override")
+        .arg("overrides.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    sniffio==1.3.0
+        anyio==3.7.0 -> sniffio<1.3.1
+    Newer versions were rejected:
+        1.3.1: the overrides specify `sniffio<1.3.1`
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use common::uv_snapshot;
+
+use crate::common::{get_bin, TestContext};
+
+mod common;
+
+/// Create a `uv remove` command with options shared across scenarios.
+fn remove_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("remove")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+    command
+}
+
+/// Remove a requirement, uninstalling it along with any dependencies that are no longer required.
+#[test]
+fn remove_transitive() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = [
+            "anyio==4.3.0",
+            "idna",
+        ]
+    "#})?;
+
+    context
+        .install()
+        .arg("-r")
+        .arg("pyproject.toml")
+        .assert()
+        .success();
+
+    // `idna` is still required by the project, but `sniffio` is only required by `anyio`.
+    uv_snapshot!(remove_command(&context).arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed anyio==4.3.0 from `project.dependencies` in pyproject.toml
+     - anyio==4.3.0
+     - sniffio==1.3.1
+    "###
+    );
+
+    insta::assert_snapshot!(fs_err::read_to_string(pyproject_toml.path())?, @r###"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    dependencies = [
+        "idna",
+    ]
+    "###);
+
+    context.assert_command("import idna").success();
+    context.assert_command("import sniffio").failure();
+
+    Ok(())
+}
+
+/// Dependencies of packages that were installed outside of the project (e.g., with
+/// `uv pip install`) are retained.
+#[test]
+fn remove_shared_with_installed_root() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = ["anyio==4.3.0"]
+    "#})?;
+
+    context
+        .install()
+        .arg("-r")
+        .arg("pyproject.toml")
+        .assert()
+        .success();
+
+    // `requests` depends on `idna`, as does `anyio`.
+    context.install().arg("requests==2.31.0").assert().success();
+
+    uv_snapshot!(remove_command(&context).arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed anyio==4.3.0 from `project.dependencies` in pyproject.toml
+     - anyio==4.3.0
+     - sniffio==1.3.1
+    "###
+    );
+
+    context.assert_command("import idna").success();
+    context.assert_command("import requests").success();
+
+    Ok(())
+}
+
+/// Removing a requirement that isn't declared is an error.
+#[test]
+fn remove_missing() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = ["anyio"]
+    "#})?;
+
+    uv_snapshot!(remove_command(&context).arg("anyio").arg("--dev"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `anyio` is not a dependency in `tool.uv.dev-dependencies` in `pyproject.toml`
+    "###
+    );
+
+    Ok(())
+}
@@ -276,6 +276,69 @@ fn create_venv_python_patch() {
     context.venv.assert(predicates::path::is_dir());
 }
 
+/// Find an interpreter managed by pyenv by version, even though it isn't in `PATH`.
+#[test]
+#[cfg(unix)]
+fn create_venv_pyenv_python() -> Result<()> {
+    let context = VenvTestContext::new(&["3.12"]);
+
+    // Install the Python 3.12 from the test bin into a fake pyenv root.
+    let python = std::env::split_paths(&context.bin)
+        .next()
+        .and_then(|bin| {
+            fs_err::read_dir(bin)
+                .ok()?
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .find(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.starts_with("python3"))
+                })
+        })
+        .expect("create_bin_with_executables to set up Python 3.12");
+    let pyenv_root = context.temp_dir.child("pyenv");
+    let pyenv_bin = pyenv_root.child("versions").child("3.12").child("bin");
+    pyenv_bin.create_dir_all()?;
+    fs_err::os::unix::fs::symlink(python, pyenv_bin.child("python3"))?;
+
+    // Hide every other source of Python installations.
+    let empty = context.temp_dir.child("empty");
+    empty.create_dir_all()?;
+
+    let mut filters = context.filters();
+    filters.push((
+        r"Using Python 3\.12\.\d+".to_string(),
+        "Using Python 3.12.[X]".to_string(),
+    ));
+    uv_snapshot!(filters, context.venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .env_remove("UV_TEST_PYTHON_PATH")
+        .env_remove("CONDA_PREFIX")
+        .env_remove("CONDA_EXE")
+        .env("PATH", empty.path())
+        .env("HOME", empty.path())
+        .env("PYENV_ROOT", pyenv_root.path())
+        .env("ASDF_DATA_DIR", empty.path())
+        .env("HOMEBREW_CELLAR", empty.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PATH]
+    Creating virtualenv at: .venv
+    Activate with: source .venv/bin/activate
+    "###
+    );
+
+    context.venv.assert(predicates::path::is_dir());
+
+    Ok(())
+}
+
 #[test]
 fn file_exists() -> Result<()> {
     let context = VenvTestContext::new(&["3.12"]);